@@ -0,0 +1,127 @@
+#!/usr/bin/env python3
+"""Generate src/unicode/tables.rs from the Unicode emoji data files.
+
+Usage:
+    scripts/gen_unicode_tables.py [DATA_DIR]
+
+Reads emoji-data.txt, emoji-sequences.txt and emoji-zwj-sequences.txt from
+DATA_DIR, or downloads them from unicode.org when no directory is given.
+Bump VERSION and rerun when a new Unicode version is released.
+"""
+import os
+import sys
+import urllib.request
+
+VERSION = (17, 0, 0)
+BASE_URL = "https://www.unicode.org/Public/%d.%d.%d" % VERSION
+SOURCES = {
+    "emoji-data.txt": BASE_URL + "/ucd/emoji/emoji-data.txt",
+    "emoji-sequences.txt": BASE_URL + "/emoji/emoji-sequences.txt",
+    "emoji-zwj-sequences.txt": BASE_URL + "/emoji/emoji-zwj-sequences.txt",
+}
+PROPERTIES = [
+    "Emoji",
+    "Emoji_Presentation",
+    "Emoji_Modifier",
+    "Emoji_Modifier_Base",
+    "Emoji_Component",
+    "Extended_Pictographic",
+]
+OUTPUT = os.path.join(os.path.dirname(__file__), "..", "src", "unicode", "tables.rs")
+
+
+def read_source(data_dir, name):
+    if data_dir:
+        with open(os.path.join(data_dir, name), encoding="utf-8") as f:
+            return f.read()
+    with urllib.request.urlopen(SOURCES[name]) as response:
+        return response.read().decode("utf-8")
+
+
+def records(text):
+    """Yield the `;` separated fields of each data line, comments stripped"""
+    for line in text.splitlines():
+        line = line.split("#", 1)[0].strip()
+        if line:
+            yield [field.strip() for field in line.split(";")]
+
+
+def code_points(field):
+    """`1F600`, `231A..231B` or `0023 FE0F 20E3` as a list of sequences"""
+    if ".." in field:
+        lo, hi = (int(cp, 16) for cp in field.split(".."))
+        return [[cp] for cp in range(lo, hi + 1)]
+    return [[int(cp, 16) for cp in field.split()]]
+
+
+def merge(ranges):
+    merged = []
+    for lo, hi in sorted(ranges):
+        if merged and lo <= merged[-1][1] + 1:
+            merged[-1] = (merged[-1][0], max(hi, merged[-1][1]))
+        else:
+            merged.append((lo, hi))
+    return merged
+
+
+def parse_properties(text):
+    properties = {name: [] for name in PROPERTIES}
+    for fields in records(text):
+        if fields[1] not in properties:
+            continue
+        if ".." in fields[0]:
+            lo, hi = (int(cp, 16) for cp in fields[0].split(".."))
+        else:
+            lo = hi = int(fields[0], 16)
+        properties[fields[1]].append((lo, hi))
+    return {name: merge(ranges) for name, ranges in properties.items()}
+
+
+def parse_sequences(*texts):
+    sequences = set()
+    for text in texts:
+        for fields in records(text):
+            for cps in code_points(fields[0]):
+                sequences.add("".join(chr(cp) for cp in cps))
+    # Rust compares &str by bytes, so sort the same way for binary search
+    return sorted(sequences, key=lambda s: s.encode("utf-8"))
+
+
+def rust_string(s):
+    return '"' + "".join("\\u{%x}" % ord(ch) for ch in s) + '"'
+
+
+def main():
+    data_dir = sys.argv[1] if len(sys.argv) > 1 else None
+    properties = parse_properties(read_source(data_dir, "emoji-data.txt"))
+    sequences = parse_sequences(
+        read_source(data_dir, "emoji-sequences.txt"),
+        read_source(data_dir, "emoji-zwj-sequences.txt"),
+    )
+
+    out = [
+        "// Generated by scripts/gen_unicode_tables.py from the Unicode emoji data",
+        "// files. Do not edit by hand; rerun the script to update.",
+        "",
+        "pub const UNICODE_VERSION: (u8, u8, u8) = (%d, %d, %d);" % VERSION,
+    ]
+    for name in PROPERTIES:
+        out.append("")
+        out.append("pub const %s: &[(u32, u32)] = &[" % name.upper())
+        for lo, hi in properties[name]:
+            out.append("    (0x%x, 0x%x)," % (lo, hi))
+        out.append("];")
+
+    out.append("")
+    out.append("/// Every RGI emoji sequence, sorted by bytes")
+    out.append("pub const RGI_EMOJI: &[&str] = &[")
+    for sequence in sequences:
+        out.append("    %s," % rust_string(sequence))
+    out.append("];")
+
+    with open(OUTPUT, "w", encoding="utf-8") as f:
+        f.write("\n".join(out) + "\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -1,21 +1,33 @@
+use crate::backend::{self, GenerationParams, TextBackend};
 use crate::error::{EmoError, Result};
-use anyhow::Result as AnyhowResult;
-use hf_hub::api::sync::ApiBuilder;
-use llama_cpp_2::context::params::LlamaContextParams;
-use llama_cpp_2::llama_backend::LlamaBackend;
-use llama_cpp_2::llama_batch::LlamaBatch;
-use llama_cpp_2::model::params::LlamaModelParams;
-use llama_cpp_2::model::{AddBos, LlamaModel, Special};
-use llama_cpp_2::sampling::LlamaSampler;
-use indicatif::{ProgressBar, ProgressStyle};
-use std::io::Write;
-use std::num::NonZeroU32;
+use crate::feedback::FeedbackLog;
+use crate::fewshot;
+use crate::gitmoji::Gitmoji;
+use crate::models::ModelRegistry;
+use crate::prompts::{self, ChatPrompt, PromptKind, PromptVars};
+use crate::{unicode, EmojiDatabase};
 use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
 
 pub struct AiEmojiSelector {
     model_path: PathBuf,
+    /// The config with the model, server, prompts and memos; feedback lives
+    /// next to it
+    config_path: PathBuf,
+    /// Config files merged for settings, lowest precedence first; only
+    /// `config_path` when empty
+    layers: Vec<PathBuf>,
+    /// Memo profile whose memos are few-shot examples; the base one if None
+    profile: Option<String>,
     model_override: Option<String>,
-    backend: LlamaBackend,
+    registry: Arc<ModelRegistry>,
+    backend: OnceLock<Box<dyn TextBackend>>,
+    /// The settings, read once so every prompt of a run sees the same ones
+    settings: OnceLock<Option<crate::EmojiMappings>>,
+    /// Accepted and rejected picks, read once like `settings`
+    feedback: OnceLock<FeedbackLog>,
+    /// Definitions for explanations; the bundled data unless given
+    database: OnceLock<Arc<EmojiDatabase>>,
 }
 
 impl AiEmojiSelector {
@@ -37,109 +49,141 @@ impl AiEmojiSelector {
         Self::with_override(Some(model_id))
     }
 
-    fn with_override(model_override: Option<String>) -> Self {
-        let mut backend = LlamaBackend::init()
-            .unwrap_or_else(|e| panic!("Failed to init backend: {}", e));
-        backend.void_logs();
+    /// Share a registry with other selectors, so the model list is fetched
+    /// once and the downloaded models are reused
+    pub fn with_registry(model_id: Option<String>, registry: Arc<ModelRegistry>) -> Self {
+        Self {
+            registry,
+            ..Self::with_override(model_id)
+        }
+    }
+
+    /// Use an already constructed backend instead of resolving one from config
+    pub fn with_backend(backend: Box<dyn TextBackend>) -> Self {
+        let selector = Self::with_override(None);
+        let _ = selector.backend.set(backend);
+        selector
+    }
 
+    /// Read config from `config_path` and keep models in `model_dir`,
+    /// instead of the config directory
+    pub fn with_paths(self, config_path: PathBuf, model_dir: PathBuf) -> Self {
         Self {
-            model_path: Self::get_config_dir().join("emo").join("models"),
-            backend,
-            model_override,
+            config_path,
+            model_path: model_dir,
+            ..self
         }
     }
 
-    fn download_model_sync(&self) -> AnyhowResult<PathBuf> {
-        // Create models directory in ~/.config/emo/models if it doesn't exist
-        std::fs::create_dir_all(&self.model_path)?;
+    /// Read settings from layered config files, lowest precedence first.
+    /// Feedback is still kept next to `config_path`.
+    pub fn with_layers(self, layers: Vec<PathBuf>) -> Self {
+        Self { layers, ..self }
+    }
+
+    /// Learn from the memos of a profile instead of the base ones
+    pub fn with_profile(self, profile: Option<String>) -> Self {
+        Self { profile, ..self }
+    }
 
-        // Use override if provided, otherwise check config
-        let model_id = self.model_override.clone()
-            .or_else(|| crate::EmojiMappings::load().ok()?.model);
+    /// Use settings that are already loaded, with the profile's memos as
+    /// `mappings`, instead of reading the config files
+    pub fn with_config(self, config: crate::EmojiMappings) -> Self {
+        let _ = self.settings.set(Some(config));
+        self
+    }
 
-        // Fetch available models from registry
-        let registry = crate::models::ModelRegistry::new();
-        let available_models = registry.fetch_models()
-            .map_err(|e| anyhow::anyhow!("Failed to fetch models: {}", e))?;
+    /// Look up emoji definitions in `database`
+    pub fn with_database(self, database: Arc<EmojiDatabase>) -> Self {
+        let _ = self.database.set(database);
+        self
+    }
 
-        if available_models.is_empty() {
-            return Err(anyhow::anyhow!("No models available from HuggingFace"));
+    fn with_override(model_override: Option<String>) -> Self {
+        let emo_dir = Self::get_config_dir().join("emo");
+        Self {
+            model_path: emo_dir.join("models"),
+            config_path: emo_dir.join("config.json"),
+            layers: Vec::new(),
+            profile: None,
+            model_override,
+            registry: Arc::new(ModelRegistry::new()),
+            backend: OnceLock::new(),
+            settings: OnceLock::new(),
+            feedback: OnceLock::new(),
+            database: OnceLock::new(),
         }
+    }
 
-        // Find the model by ID or use the first available
-        let model = match model_id {
-            Some(id) => available_models.iter()
-                .find(|m| m.id == id)
-                .ok_or_else(|| anyhow::anyhow!("Model '{}' not found", id))?,
-            None => &available_models[0],
-        };
+    fn database(&self) -> &EmojiDatabase {
+        self.database.get_or_init(|| Arc::new(EmojiDatabase::default()))
+    }
 
-        let url = &model.url;
-        let filename = model.filename();
+    /// The settings, with the profile's memos as `mappings`
+    fn config(&self) -> Option<&crate::EmojiMappings> {
+        self.settings.get_or_init(|| self.load_config()).as_ref()
+    }
 
-        let model_file = self.model_path.join(filename);
+    fn load_config(&self) -> Option<crate::EmojiMappings> {
+        let mut config = if self.layers.is_empty() {
+            crate::EmojiMappings::load_from(&self.config_path).ok()?
+        } else {
+            let configs = self
+                .layers
+                .iter()
+                .map(|path| crate::EmojiMappings::load_from(path).ok())
+                .collect::<Option<Vec<_>>>()?;
+            crate::config::merge(&configs)
+        };
+        config.mappings = crate::config::profile_memos(&config, self.profile.as_deref()).ok()?;
+        Some(config)
+    }
 
-        // Check if model already exists (silently return if it does)
-        if model_file.exists() {
-            return Ok(model_file);
-        }
+    fn model_id(&self) -> Option<String> {
+        self.model_override.clone()
+            .or_else(|| self.config()?.model.clone())
+    }
 
-        // Try HuggingFace Hub API first
-        if let Ok(api) = ApiBuilder::new().with_progress(true).build() {
-            // Parse the URL to get repo info
-            let url_parts: Vec<&str> = model.url.split('/').collect();
-            if url_parts.len() > 5 {
-                let owner = url_parts[3];
-                let repo_name = url_parts[4];
-                let repo_id = format!("{}/{}", owner, repo_name);
-
-                // The HF API will use cached version if available, only print messages if actually downloading
-                // We can't easily detect if it's cached beforehand, so we'll rely on the progress bar
-                if let Ok(path) = api.model(repo_id).get(&model.filename()) {
-                    // Model was either cached or downloaded - the progress bar handles the feedback
-                    return Ok(path);
-                }
-            }
+    /// The backend for the configured model, connected on first use and
+    /// reused for every generation after that
+    fn backend(&self) -> Result<&dyn TextBackend> {
+        if let Some(backend) = self.backend.get() {
+            return Ok(backend.as_ref());
         }
 
-        // Fallback to direct download
-        eprintln!("📥 Downloading {} model ({})...", model.name, model.id);
-        eprintln!("This is a one-time download for AI-powered emoji selection.");
-
-        // Create progress bar
-        let client = reqwest::blocking::Client::new();
-        let mut response = client.get(url).send()?;
-
-        let total_size = response
-            .content_length()
-            .unwrap_or(1_600_000_000); // Default to ~1.6GB if unknown
-
-        let pb = ProgressBar::new(total_size);
-        pb.set_style(
-            ProgressStyle::default_bar()
-                .template("{spinner:.green} [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
-                .expect("Failed to set progress bar template")
-                .progress_chars("#>-")
-        );
-
-        // Download with progress
-        let mut file = std::fs::File::create(&model_file)?;
-        let mut downloaded = 0u64;
-        let mut buffer = [0; 8192];
-
-        loop {
-            use std::io::Read;
-            let bytes_read = response.read(&mut buffer)?;
-            if bytes_read == 0 {
-                break;
-            }
-            file.write_all(&buffer[..bytes_read])?;
-            downloaded += bytes_read as u64;
-            pb.set_position(downloaded);
-        }
+        let base_url = self.config().and_then(|c| c.base_url.clone());
+        let backend = backend::connect(self.model_id().as_deref(), base_url.as_deref(), &self.registry, &self.model_path)?;
+        Ok(self.backend.get_or_init(|| backend).as_ref())
+    }
+
+    fn feedback(&self) -> &FeedbackLog {
+        self.feedback.get_or_init(|| {
+            FeedbackLog::load_from(&self.config_path.with_file_name("feedback.json")).unwrap_or_default()
+        })
+    }
+
+    fn chat_prompt(&self, kind: PromptKind, vars: PromptVars) -> ChatPrompt {
+        let situation = vars.situation;
+        let config = self.config();
+        let feedback = self.feedback();
+
+        // Memos and accepted picks teach the model our conventions
+        let examples = config
+            .map(|c| fewshot::select(situation, fewshot::gather(c, feedback), fewshot::DEFAULT_EXAMPLES))
+            .unwrap_or_default();
+
+        // Picks rejected for similar situations are off the table
+        let exclude = with_rejected(vars.exclude, situation, feedback);
 
-        pb.finish_with_message("✅ Model downloaded successfully!");
-        Ok(model_file)
+        // Per-model overrides from config, if any
+        let model_id = self.model_id();
+        let overrides = config.and_then(|c| prompts::lookup(&c.prompts, model_id.as_deref()));
+
+        ChatPrompt::build(kind, overrides, &PromptVars {
+            exclude: &exclude,
+            examples: &examples,
+            ..vars
+        })
     }
 
     pub fn select_emoji_llm(&self, situation: &str) -> Result<String> {
@@ -147,217 +191,344 @@ impl AiEmojiSelector {
     }
 
     pub fn select_emoji_with_exclusions(&self, situation: &str, exclude: &[String]) -> Result<String> {
-        // Download model if needed
-        let model_path = self.download_model_sync()
-            .map_err(|e| EmoError::ConfigError(format!("Failed to download model: {}", e)))?;
-
-        // Set up model parameters
-        let model_params = LlamaModelParams::default();
-
-        // Load the model
-        let model = LlamaModel::load_from_file(&self.backend, model_path, &model_params)
-            .map_err(|e| EmoError::ConfigError(format!("Failed to load model: {}", e)))?;
+        let backend = self.backend()?;
 
-        // Create context parameters
-        let ctx_params = LlamaContextParams::default()
-            .with_n_ctx(Some(NonZeroU32::new(2048).unwrap()));
-
-        // Create context
-        let mut ctx = model.new_context(&self.backend, ctx_params)
-            .map_err(|e| EmoError::ConfigError(format!("Failed to create context: {}", e)))?;
+        // The prompt asks the model to avoid rejected picks, but it may
+        // still answer with one
+        let rejected = with_rejected(exclude, situation, self.feedback());
 
         // Create a prompt that encourages emoji-only output
-        let prompt = match exclude.is_empty() {
-            true => format!("Task: Select ONE emoji that best represents: {}. Reply with only the emoji, nothing else.\nEmoji:", situation),
-            false => format!("Task: Select ONE emoji that best represents: {}. Do not use: {}. Reply with only the emoji.\nEmoji:", situation, exclude.join(", ")),
+        let prompt = self.chat_prompt(PromptKind::Emoji, PromptVars {
+            situation,
+            count: 1,
+            length: 1,
+            exclude,
+            ..Default::default()
+        });
+
+        // Use higher temperature for more varied/creative output
+        let params = GenerationParams {
+            max_tokens: 20,
+            temperature: 0.8,
+            seed: 1234,
         };
 
-        // Tokenize the prompt
-        let tokens_list = model.str_to_token(&prompt, AddBos::Always)
-            .map_err(|e| EmoError::ConfigError(format!("Failed to tokenize: {}", e)))?;
+        let mut output = String::new();
 
-        // Create batch for prompt processing
-        let mut batch = LlamaBatch::new(512, 1);
+        backend.generate(&prompt, &params, &mut |text| {
+            output.push_str(text);
+
+            // The last emoji may still grow (skin tone, ZWJ sequence), so
+            // only settle on one once something follows it
+            let settled = first_new_emoji(&output, &rejected)
+                .is_some_and(|(offset, emoji)| offset + emoji.len() < output.len());
+
+            // Stop once we have an emoji or generated enough text
+            !settled && output.len() <= 50
+        })?;
+
+        // Take the first emoji the model didn't already give us, or fail loudly
+        first_new_emoji(&output, &rejected)
+            .map(|(_, emoji)| emoji.to_string())
+            .ok_or_else(|| EmoError::no_answer(format!(
+                "LLM did not generate an emoji. Generated text: '{}'",
+                output
+            )))
+    }
 
-        let last_index = tokens_list.len() - 1;
-        for (i, token) in tokens_list.iter().enumerate() {
-            batch.add(*token, i as i32, &[0], i == last_index)
-                .map_err(|e| EmoError::ConfigError(format!("Failed to add to batch: {}", e)))?;
+    /// A plain-words reading of an emoji message like "😴☕💼😅🏃". Words
+    /// around the emojis are taken as context, and the database definitions
+    /// of the emojis guide the model.
+    pub fn explain_emoji(&self, message: &str) -> Result<String> {
+        let emojis: Vec<&str> = unicode::emojis(message).collect();
+        if emojis.is_empty() {
+            return Err(EmoError::InvalidInput(format!("No emoji to explain in '{}'", message)));
         }
+        let backend = self.backend()?;
+
+        let context = unicode::graphemes(message)
+            .filter(|g| !unicode::is_emoji_cluster(g))
+            .collect::<String>();
+        let context = context.split_whitespace().collect::<Vec<_>>().join(" ");
+        let sequence = emojis.concat();
+        let definitions = definitions(self.database(), &emojis);
+
+        let prompt = self.chat_prompt(PromptKind::Explain, PromptVars {
+            situation: &context,
+            emojis: &sequence,
+            definitions: &definitions,
+            ..Default::default()
+        });
+
+        let params = GenerationParams {
+            max_tokens: 80,
+            temperature: 0.3, // Low temperature for a faithful reading
+            seed: 1234,
+        };
 
-        // Process the prompt
-        ctx.decode(&mut batch)
-            .map_err(|e| EmoError::ConfigError(format!("Failed to decode: {}", e)))?;
+        let mut output = String::new();
+        backend.generate(&prompt, &params, &mut |text| {
+            output.push_str(text);
+            true
+        })?;
+
+        let explanation = output.trim();
+        if explanation.is_empty() {
+            return Err(EmoError::no_answer("LLM did not generate an explanation"));
+        }
+        Ok(explanation.to_string())
+    }
 
-        // Generate tokens looking for an emoji
-        // Use higher temperature for more varied/creative output
-        let mut sampler = LlamaSampler::chain_simple([
-            LlamaSampler::temp(0.8),
-            LlamaSampler::dist(1234),
-        ]);
+    /// The gitmoji among `options` that the model finds fits the commit
+    /// message best
+    pub fn choose_gitmoji(&self, message: &str, options: &[&'static Gitmoji]) -> Result<&'static Gitmoji> {
+        let backend = self.backend()?;
+
+        let listing = options
+            .iter()
+            .map(|g| format!("{} {} {}", g.code, g.emoji, g.description))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let prompt = self.chat_prompt(PromptKind::Gitmoji, PromptVars {
+            situation: message,
+            options: &listing,
+            ..Default::default()
+        });
+
+        let params = GenerationParams {
+            max_tokens: 20,
+            temperature: 0.1, // The table decides, not creativity
+            seed: 1234,
+        };
 
-        let mut decoder = encoding_rs::UTF_8.new_decoder();
         let mut output = String::new();
-        let mut n_cur = batch.n_tokens();
+        backend.generate(&prompt, &params, &mut |text| {
+            output.push_str(text);
+            pick_gitmoji(&output, options).is_none() && output.len() <= 80
+        })?;
+
+        pick_gitmoji(&output, options).ok_or_else(|| EmoError::no_answer(format!(
+            "LLM did not pick a gitmoji. Generated text: '{}'",
+            output
+        )))
+    }
+
+    pub fn generate_emoji_sentence(&self, situation: &str, length: usize) -> Result<String> {
+        self.generate_sentence(situation, &SentenceOptions::new(length))
+    }
+
+    /// Exactly `options.length` emojis telling the story of the situation.
+    /// Generation continues from what the model produced so far until the
+    /// sentence is complete or the attempts run out.
+    pub fn generate_sentence(&self, situation: &str, options: &SentenceOptions) -> Result<String> {
+        options.validate()?;
+        let backend = self.backend()?;
 
-        // Generate up to 20 tokens
-        for _ in 0..20 {
-            // Sample next token (always from the last position in the batch)
-            let new_token_id = sampler.sample(&ctx, batch.n_tokens() - 1);
-            sampler.accept(new_token_id);
+        let mut sentence: Vec<String> = options.start_with.iter().cloned().collect();
 
-            // Check if it's EOG (end of generation)
-            if model.is_eog_token(new_token_id) {
+        for _ in 0..SENTENCE_ATTEMPTS {
+            if sentence.len() >= options.length {
                 break;
             }
 
-            // Decode token to string
-            let token_bytes = model.token_to_bytes(new_token_id, Special::Tokenize)
-                .map_err(|e| EmoError::ConfigError(format!("Failed to get token bytes: {}", e)))?;
-
-            let mut token_str = String::with_capacity(32);
-            let (_result, _read, _had_errors) = decoder.decode_to_string(&token_bytes, &mut token_str, false);
+            // Continue from the sentence so far, asking for what's still missing
+            let start = sentence.concat();
+            let include = options.missing(&sentence);
+            let exclude = if options.no_repeat { sentence.clone() } else { Vec::new() };
+            let prompt = self.chat_prompt(PromptKind::Sentence, PromptVars {
+                situation,
+                count: options.length,
+                length: options.length,
+                exclude: &exclude,
+                include: &include,
+                start: &start,
+                ..Default::default()
+            });
+
+            let params = GenerationParams {
+                max_tokens: 100, // More tokens for full sentence
+                temperature: 0.2, // Very low temperature for focused output
+                seed: random_seed(),
+            };
+
+            let mut output = String::new();
+
+            // Generate until we have enough complete emojis
+            backend.generate(&prompt, &params, &mut |text| {
+                output.push_str(text);
+                let complete: Vec<_> = unicode::emoji_indices(&output)
+                    .filter(|(offset, emoji)| offset + emoji.len() < output.len())
+                    .map(|(_, emoji)| emoji)
+                    .collect();
+
+                let mut trial = sentence.clone();
+                options.extend(&mut trial, &complete);
+                trial.len() < options.length
+            })?;
+
+            options.extend(&mut sentence, &unicode::emojis(&output).collect::<Vec<_>>());
+        }
 
-            output.push_str(&token_str);
+        // Emojis the model never came up with take the slots kept for them
+        let missing = options.missing(&sentence);
+        if sentence.len() + missing.len() == options.length {
+            sentence.extend(missing);
+        }
 
-            // Check if we found an emoji
-            for ch in token_str.chars() {
-                if is_emoji_char(ch) {
-                    return Ok(ch.to_string());
-                }
-            }
+        if sentence.len() < options.length {
+            return Err(EmoError::no_answer(format!(
+                "Failed to generate emoji sentence: got {} of {} emojis ('{}')",
+                sentence.len(),
+                options.length,
+                sentence.concat()
+            )));
+        }
+        Ok(sentence.concat())
+    }
+}
 
-            // Add token to batch for next iteration
-            batch.clear();
-            batch.add(new_token_id, n_cur, &[0], true)
-                .map_err(|e| EmoError::ConfigError(format!("Failed to add to batch: {}", e)))?;
+/// The first of `options` named in the output, by `:code:` or by emoji
+fn pick_gitmoji(output: &str, options: &[&'static Gitmoji]) -> Option<&'static Gitmoji> {
+    let by_code = output
+        .split(|c: char| c.is_whitespace() || c == '`')
+        .find_map(|word| options.iter().find(|g| word.starts_with(g.code.as_str())));
+    let by_emoji = || {
+        unicode::emojis(output).find_map(|emoji| {
+            let emoji = unicode::strip_selectors(emoji);
+            options.iter().find(|g| unicode::strip_selectors(&g.emoji) == emoji)
+        })
+    };
+    by_code.or_else(by_emoji).copied()
+}
 
-            n_cur += 1;
+/// How many times sentence generation continues before giving up
+const SENTENCE_ATTEMPTS: usize = 4;
+
+/// Constraints for an emoji sentence
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SentenceOptions {
+    /// Number of emojis, counted as grapheme clusters
+    pub length: usize,
+    /// Use each emoji at most once
+    pub no_repeat: bool,
+    /// Emojis that must appear somewhere
+    pub include: Vec<String>,
+    /// Emoji the sentence must begin with
+    pub start_with: Option<String>,
+}
 
-            ctx.decode(&mut batch)
-                .map_err(|e| EmoError::ConfigError(format!("Failed to decode: {}", e)))?;
+impl SentenceOptions {
+    pub fn new(length: usize) -> Self {
+        SentenceOptions {
+            length,
+            ..Default::default()
+        }
+    }
 
-            // Stop if we generated enough text
-            if output.len() > 50 {
-                break;
+    fn validate(&self) -> Result<()> {
+        for emoji in self.include.iter().chain(&self.start_with) {
+            if unicode::emojis(emoji).collect::<Vec<_>>() != [emoji.as_str()] {
+                return Err(EmoError::InvalidInput(format!("'{}' is not a single emoji", emoji)));
             }
         }
 
-        // No emoji found - fail loudly
-        Err(EmoError::ConfigError(format!(
-            "LLM did not generate an emoji. Generated text: '{}'",
-            output
-        )))
+        let required = self.missing(&self.start_with.iter().cloned().collect::<Vec<_>>()).len()
+            + self.start_with.iter().count();
+        if required > self.length {
+            return Err(EmoError::InvalidInput(format!(
+                "A sentence of {} emojis can't fit the {} required ones",
+                self.length, required
+            )));
+        }
+        Ok(())
     }
 
-    pub fn generate_emoji_sentence(&self, situation: &str, length: usize) -> Result<String> {
-        // Download model if needed
-        let model_path = self.download_model_sync()
-            .map_err(|e| EmoError::ConfigError(format!("Failed to download model: {}", e)))?;
-
-        // Load model
-        let model_params = LlamaModelParams::default();
-        let model = LlamaModel::load_from_file(&self.backend, model_path, &model_params)
-            .map_err(|e| EmoError::ConfigError(format!("Failed to load model: {}", e)))?;
-
-        // Create context
-        let ctx_params = LlamaContextParams::default()
-            .with_n_ctx(Some(NonZeroU32::new(2048).unwrap()));
-        let mut ctx = model.new_context(&self.backend, ctx_params)
-            .map_err(|e| EmoError::ConfigError(format!("Failed to create context: {}", e)))?;
-
-        // Create prompt for emoji sentence generation
-        let prompt = format!(
-            "Task: Create a sequence of exactly {} emojis that tells a story about: {}. \
-             Use only emojis, no text. Reply with the emoji sequence.\n\
-             Emoji sequence:",
-            length, situation
-        );
-
-        // Tokenize prompt
-        let tokens_list = model.str_to_token(&prompt, AddBos::Always)
-            .map_err(|e| EmoError::ConfigError(format!("Failed to tokenize: {}", e)))?;
-
-        // Process prompt
-        let mut batch = LlamaBatch::new(512, 1);
-        let last_index = tokens_list.len() - 1;
-        for (i, token) in tokens_list.iter().enumerate() {
-            batch.add(*token, i as i32, &[0], i == last_index)
-                .map_err(|e| EmoError::ConfigError(format!("Failed to add to batch: {}", e)))?;
+    /// Included emojis the sentence doesn't have yet
+    fn missing(&self, sentence: &[String]) -> Vec<String> {
+        let mut missing: Vec<String> = Vec::new();
+        for emoji in &self.include {
+            if !contains(sentence, emoji) && !contains(&missing, emoji) {
+                missing.push(emoji.clone());
+            }
         }
-        ctx.decode(&mut batch)
-            .map_err(|e| EmoError::ConfigError(format!("Failed to decode: {}", e)))?;
-
-        // Use truly random seed
-        use std::collections::hash_map::RandomState;
-        use std::hash::{BuildHasher, Hash, Hasher};
-        let mut hasher = RandomState::new().build_hasher();
-        std::time::SystemTime::now().hash(&mut hasher);
-        let seed = hasher.finish() as u32;
-
-        let mut sampler = LlamaSampler::chain_simple([
-            LlamaSampler::temp(0.2),  // Very low temperature for focused output
-            LlamaSampler::dist(seed),
-        ]);
-
-        let mut decoder = encoding_rs::UTF_8.new_decoder();
-        let mut output = String::new();
-        let mut emoji_count = 0;
-        let mut n_cur = batch.n_tokens();
+        missing
+    }
 
-        // Generate until we have enough emojis
-        for _ in 0..100 {  // More tokens for full sentence
-            let new_token_id = sampler.sample(&ctx, batch.n_tokens() - 1);
-            sampler.accept(new_token_id);
+    /// Add generated emojis to the sentence as far as the constraints allow
+    fn extend(&self, sentence: &mut Vec<String>, generated: &[&str]) {
+        // Models often repeat the part they were asked to continue from
+        let echoed = !sentence.is_empty()
+            && generated.len() >= sentence.len()
+            && sentence.iter().zip(generated).all(|(s, g)| same_emoji(s, g));
+        let generated = if echoed { &generated[sentence.len()..] } else { generated };
 
-            if model.is_eog_token(new_token_id) {
+        for &emoji in generated {
+            if sentence.len() >= self.length {
                 break;
             }
+            if self.no_repeat && contains(sentence, emoji) {
+                continue;
+            }
 
-            let token_bytes = model.token_to_bytes(new_token_id, Special::Tokenize)
-                .map_err(|e| EmoError::ConfigError(format!("Failed to get token bytes: {}", e)))?;
-
-            let mut token_str = String::with_capacity(32);
-            let (_result, _read, _had_errors) = decoder.decode_to_string(&token_bytes, &mut token_str, false);
-
-            // Count emojis in this token
-            for ch in token_str.chars() {
-                if is_emoji_char(ch) {
-                    emoji_count += 1;
-                    output.push(ch);
-                    if emoji_count >= length {
-                        return Ok(output);
-                    }
-                }
+            // Keep room for the included emojis still to come
+            let missing = self.missing(sentence);
+            if !contains(&missing, emoji) && sentence.len() + missing.len() >= self.length {
+                continue;
             }
+            sentence.push(emoji.to_string());
+        }
+    }
+}
 
-            // Continue generation
-            batch.clear();
-            batch.add(new_token_id, n_cur, &[0], true)
-                .map_err(|e| EmoError::ConfigError(format!("Failed to add to batch: {}", e)))?;
-            n_cur += 1;
-            ctx.decode(&mut batch)
-                .map_err(|e| EmoError::ConfigError(format!("Failed to decode: {}", e)))?;
+/// "😴 sleeping face; 🔥 fire: Fire is hot and lit." for the emojis that
+/// are in the database, each listed once
+pub fn definitions(database: &EmojiDatabase, emojis: &[&str]) -> String {
+    let mut seen: Vec<char> = Vec::new();
+    let mut definitions = Vec::new();
+    for emoji in emojis {
+        let Some((ch, record)) = database.lookup(emoji) else { continue };
+        if seen.contains(&ch) {
+            continue;
         }
+        seen.push(ch);
+        definitions.push(match &record.definition {
+            Some(definition) => format!("{} {}: {}", ch, record.name, definition.trim_end_matches('.')),
+            None => format!("{} {}", ch, record.name),
+        });
+    }
+    definitions.join("; ")
+}
 
-        // Return what we got even if not exactly the right length
-        if !output.is_empty() {
-            Ok(output)
-        } else {
-            Err(EmoError::ConfigError(
-                "Failed to generate emoji sentence".to_string()
-            ))
+/// Compare emojis regardless of presentation selectors, so "❤" matches "❤️"
+fn same_emoji(a: &str, b: &str) -> bool {
+    unicode::strip_selectors(a) == unicode::strip_selectors(b)
+}
+
+fn contains(emojis: &[String], emoji: &str) -> bool {
+    emojis.iter().any(|e| same_emoji(e, emoji))
+}
+
+fn random_seed() -> u32 {
+    // Use truly random seed
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hash, Hasher};
+    let mut hasher = RandomState::new().build_hasher();
+    std::time::SystemTime::now().hash(&mut hasher);
+    hasher.finish() as u32
+}
+
+/// `exclude` and the picks rejected for situations like this one
+fn with_rejected(exclude: &[String], situation: &str, feedback: &FeedbackLog) -> Vec<String> {
+    let mut exclude = exclude.to_vec();
+    for emoji in fewshot::rejected_for(situation, feedback) {
+        if !contains(&exclude, &emoji) {
+            exclude.push(emoji);
         }
     }
+    exclude
 }
 
-fn is_emoji_char(ch: char) -> bool {
-    // Check if character is in emoji ranges
-    matches!(ch as u32,
-        0x1F300..=0x1F9FF | // Emoticons & misc
-        0x2600..=0x26FF |   // Misc symbols
-        0x2700..=0x27BF |   // Dingbats
-        0x1F000..=0x1F02F | // Mahjong/Domino
-        0x1FA70..=0x1FAFF   // More symbols
-    )
-}
\ No newline at end of file
+/// First emoji in `text` that isn't excluded, with its byte offset
+fn first_new_emoji<'a>(text: &'a str, exclude: &[String]) -> Option<(usize, &'a str)> {
+    unicode::emoji_indices(text).find(|(_, emoji)| !contains(exclude, emoji))
+}
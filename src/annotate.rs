@@ -0,0 +1,259 @@
+// Inline emoji decoration of prose
+//
+// The document is split into blocks: each bullet item, and each run of
+// non-bullet lines between blank lines. Bullets get an emoji right after the
+// bullet marker; sentences get one after their last word. `Density` decides
+// how many sentences of a block are decorated. Apart from the insertions the
+// text is left exactly as it was.
+use crate::ai::AiEmojiSelector;
+use crate::error::{EmoError, Result};
+use crate::unicode;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Density {
+    /// One emoji per bullet or paragraph
+    Low,
+    /// Every other sentence
+    #[default]
+    Medium,
+    /// Every sentence
+    High,
+}
+
+impl Density {
+    /// Whether the sentence at `index` within its block gets an emoji
+    fn picks(self, index: usize) -> bool {
+        match self {
+            Density::Low => index == 0,
+            Density::Medium => index.is_multiple_of(2),
+            Density::High => true,
+        }
+    }
+}
+
+impl FromStr for Density {
+    type Err = EmoError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "low" => Ok(Density::Low),
+            "medium" => Ok(Density::Medium),
+            "high" => Ok(Density::High),
+            _ => Err(EmoError::InvalidInput(format!(
+                "Unknown density '{}', expected low, medium or high",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// At the start of a bullet: "- 🚀 Shipped v2"
+    Before,
+    /// After a phrase: "Shipped v2 🚀."
+    After,
+}
+
+/// A place in the text that gets an emoji
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slot {
+    /// Byte offset into the original text
+    pub offset: usize,
+    pub placement: Placement,
+    /// The phrase the emoji should describe
+    pub context: String,
+}
+
+/// Where emojis go for the given density, in text order
+pub fn slots(text: &str, density: Density) -> Vec<Slot> {
+    let mut slots = Vec::new();
+    for block in blocks(text) {
+        let mut sentences = sentences(text, block.start, block.end).into_iter().enumerate();
+
+        // A bullet's emoji goes after the marker, in place of its first sentence's
+        if let Some(content) = block.bullet {
+            let context = text[content..block.end].trim();
+            if sentences.next().is_some() && !has_emoji(context) {
+                slots.push(Slot {
+                    offset: content,
+                    placement: Placement::Before,
+                    context: context.to_string(),
+                });
+            }
+        }
+
+        for (i, (start, end)) in sentences {
+            if density.picks(i) {
+                push_after(&mut slots, text, start, end);
+            }
+        }
+    }
+    slots
+}
+
+fn push_after(slots: &mut Vec<Slot>, text: &str, start: usize, end: usize) {
+    let context = &text[start..end];
+    if !has_emoji(context) {
+        slots.push(Slot {
+            offset: end,
+            placement: Placement::After,
+            context: context.to_string(),
+        });
+    }
+}
+
+fn has_emoji(text: &str) -> bool {
+    unicode::emojis(text).next().is_some()
+}
+
+/// Insert one emoji per slot. `slots` and `emojis` pair up; a `None` leaves
+/// the slot empty.
+pub fn apply(text: &str, slots: &[Slot], emojis: &[Option<String>]) -> String {
+    let mut out = String::with_capacity(text.len() + slots.len() * 8);
+    let mut copied = 0;
+    for (slot, emoji) in slots.iter().zip(emojis) {
+        let Some(emoji) = emoji else { continue };
+        out.push_str(&text[copied..slot.offset]);
+        match slot.placement {
+            Placement::Before => {
+                out.push_str(emoji);
+                out.push(' ');
+            }
+            Placement::After => {
+                out.push(' ');
+                out.push_str(emoji);
+            }
+        }
+        copied = slot.offset;
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Decorate the text with emojis picked by the selector. The same selector
+/// (and so the same loaded model) is used for every slot, and emojis already
+/// used in the document are avoided.
+pub fn annotate(text: &str, density: Density, selector: &AiEmojiSelector) -> Result<String> {
+    let slots = slots(text, density);
+    let mut emojis = Vec::with_capacity(slots.len());
+    let mut used: Vec<String> = Vec::new();
+    let mut last_error = None;
+
+    for slot in &slots {
+        match selector.select_emoji_with_exclusions(&slot.context, &used) {
+            Ok(emoji) => {
+                used.push(emoji.clone());
+                emojis.push(Some(emoji));
+            }
+            Err(e) => {
+                eprintln!("Warning: no emoji for '{}': {}", slot.context, crate::error::report(&e));
+                last_error = Some(e);
+                emojis.push(None);
+            }
+        }
+    }
+
+    // Nothing worked at all, most likely the model itself
+    if used.is_empty() {
+        if let Some(e) = last_error {
+            return Err(e);
+        }
+    }
+    Ok(apply(text, &slots, &emojis))
+}
+
+/// A bullet item or a paragraph, as byte offsets into the text
+struct Block {
+    start: usize,
+    end: usize,
+    /// Where the bullet's content starts, after the marker
+    bullet: Option<usize>,
+}
+
+fn blocks(text: &str) -> Vec<Block> {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut open = false;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let content = line.trim_end();
+        let end = start + content.len();
+
+        if content.trim().is_empty() {
+            open = false;
+            continue;
+        }
+
+        match bullet_content(content) {
+            Some(marker) => {
+                blocks.push(Block { start: start + marker, end, bullet: Some(start + marker) });
+                open = true;
+            }
+            // Wrapped lines continue the bullet or paragraph above
+            None if open => {
+                if let Some(block) = blocks.last_mut() {
+                    block.end = end;
+                }
+            }
+            None => {
+                let indent = content.len() - content.trim_start().len();
+                blocks.push(Block { start: start + indent, end, bullet: None });
+                open = true;
+            }
+        }
+    }
+    blocks
+}
+
+/// Byte length of a bullet marker like "- ", "  * " or "12. ", if any
+fn bullet_content(line: &str) -> Option<usize> {
+    let indent = line.len() - line.trim_start().len();
+    let rest = &line[indent..];
+
+    let marker = if rest.starts_with(['-', '*', '+']) {
+        1
+    } else {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        match rest.as_bytes().get(digits) {
+            Some(b'.' | b')') if digits > 0 => digits + 1,
+            _ => return None,
+        }
+    };
+
+    let spaces = rest[marker..].len() - rest[marker..].trim_start_matches([' ', '\t']).len();
+    if spaces == 0 || rest[marker..].trim().is_empty() {
+        return None;
+    }
+    Some(indent + marker + spaces)
+}
+
+/// Sentences in `text[start..end]`, without their closing punctuation
+fn sentences(text: &str, start: usize, end: usize) -> Vec<(usize, usize)> {
+    let block = &text[start..end];
+    let mut sentences = Vec::new();
+    let mut sentence_start = 0;
+    let mut chars = block.char_indices().peekable();
+
+    while let Some((i, ch)) = chars.next() {
+        let at_end = match chars.peek() {
+            None => true,
+            Some((_, next)) => matches!(ch, '.' | '!' | '?' | ':' | ';') && next.is_whitespace(),
+        };
+        if !at_end {
+            continue;
+        }
+
+        let sentence = &block[sentence_start..i + ch.len_utf8()];
+        let phrase = sentence.trim_end_matches(|c: char| ".!?:;,)\"'".contains(c) || c.is_whitespace());
+        let leading = phrase.len() - phrase.trim_start().len();
+        if phrase.chars().any(char::is_alphanumeric) {
+            sentences.push((start + sentence_start + leading, start + sentence_start + phrase.len()));
+        }
+        sentence_start = i + ch.len_utf8();
+    }
+    sentences
+}
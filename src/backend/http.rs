@@ -0,0 +1,134 @@
+// HTTP backend for models served by Ollama or an OpenAI-compatible server
+// such as llama-server, vLLM or LM Studio
+use super::{GenerationParams, TextBackend};
+use crate::error::{EmoError, Result};
+use crate::prompts::ChatPrompt;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+pub const OLLAMA_URL: &str = "http://localhost:11434";
+pub const OPENAI_URL: &str = "http://localhost:8080";
+
+/// Bearer token sent to OpenAI-compatible servers when set
+pub const API_KEY_ENV: &str = "EMO_API_KEY";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Api {
+    Ollama,
+    OpenAi,
+}
+
+pub struct HttpBackend {
+    api: Api,
+    base_url: String,
+    model: String,
+    client: reqwest::blocking::Client,
+}
+
+impl HttpBackend {
+    pub fn new(api: Api, base_url: Option<&str>, model: String) -> Self {
+        let default_url = match api {
+            Api::Ollama => OLLAMA_URL,
+            Api::OpenAi => OPENAI_URL,
+        };
+
+        Self {
+            api,
+            base_url: base_url.unwrap_or(default_url).trim_end_matches('/').to_string(),
+            model,
+            client: reqwest::blocking::Client::builder()
+                .timeout(Duration::from_secs(120))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn ollama(base_url: Option<&str>, model: String) -> Self {
+        Self::new(Api::Ollama, base_url, model)
+    }
+
+    pub fn openai(base_url: Option<&str>, model: String) -> Self {
+        Self::new(Api::OpenAi, base_url, model)
+    }
+
+    fn request(&self, prompt: &ChatPrompt, params: &GenerationParams) -> (String, Value) {
+        match self.api {
+            Api::Ollama => (
+                format!("{}/api/generate", self.base_url),
+                json!({
+                    "model": self.model,
+                    "system": prompt.system,
+                    "prompt": prompt.user,
+                    "stream": false,
+                    "options": {
+                        "temperature": params.temperature,
+                        "seed": params.seed,
+                        "num_predict": params.max_tokens,
+                    },
+                }),
+            ),
+            Api::OpenAi => (
+                format!("{}/v1/chat/completions", self.base_url),
+                json!({
+                    "model": self.model,
+                    "messages": [
+                        { "role": "system", "content": prompt.system },
+                        { "role": "user", "content": prompt.user },
+                    ],
+                    "temperature": params.temperature,
+                    "seed": params.seed,
+                    "max_tokens": params.max_tokens,
+                }),
+            ),
+        }
+    }
+
+    fn reply_text(&self, body: &Value) -> Option<String> {
+        let text = match self.api {
+            Api::Ollama => body.get("response"),
+            Api::OpenAi => body.pointer("/choices/0/message/content"),
+        };
+        text.and_then(Value::as_str).map(str::to_string)
+    }
+}
+
+impl TextBackend for HttpBackend {
+    fn generate(
+        &self,
+        prompt: &ChatPrompt,
+        params: &GenerationParams,
+        on_text: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<()> {
+        let (url, payload) = self.request(prompt, params);
+
+        let mut request = self.client.post(&url).json(&payload);
+        if let (Api::OpenAi, Ok(key)) = (self.api, std::env::var(API_KEY_ENV)) {
+            request = request.bearer_auth(key);
+        }
+
+        if crate::error::offline() {
+            return Err(EmoError::Offline(format!("not connecting to {}", url)));
+        }
+
+        let response = request.send()
+            .map_err(|e| EmoError::network(format!("Failed to reach {}", url), e))?;
+
+        if !response.status().is_success() {
+            return Err(EmoError::Network {
+                message: format!("{} returned HTTP {}", url, response.status()),
+                source: None,
+            });
+        }
+
+        let body: Value = response.json()
+            .map_err(|e| EmoError::network(format!("Failed to parse reply from {}", url), e))?;
+
+        let text = self.reply_text(&body).ok_or_else(|| {
+            EmoError::no_answer(format!("Unexpected reply from {}: {}", url, body))
+        })?;
+
+        // The whole reply arrives at once
+        on_text(&text);
+        Ok(())
+    }
+}
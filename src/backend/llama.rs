@@ -0,0 +1,154 @@
+// llama.cpp backend: runs a local GGUF model downloaded from HuggingFace
+use super::{GenerationParams, TextBackend};
+use crate::error::{EmoError, Result};
+use crate::models::ModelRegistry;
+use crate::prompts::ChatPrompt;
+use llama_cpp_2::context::params::LlamaContextParams;
+use llama_cpp_2::llama_backend::LlamaBackend;
+use llama_cpp_2::llama_batch::LlamaBatch;
+use llama_cpp_2::model::params::LlamaModelParams;
+use llama_cpp_2::model::{AddBos, LlamaChatMessage, LlamaModel, Special};
+use llama_cpp_2::sampling::LlamaSampler;
+use std::num::NonZeroU32;
+use std::path::Path;
+use std::sync::OnceLock;
+
+// llama.cpp can only be initialised once per process
+static BACKEND: OnceLock<LlamaBackend> = OnceLock::new();
+
+fn llama_backend() -> Result<&'static LlamaBackend> {
+    if let Some(backend) = BACKEND.get() {
+        return Ok(backend);
+    }
+
+    let mut backend = LlamaBackend::init()
+        .map_err(|e| EmoError::inference("Failed to init backend", e))?;
+    backend.void_logs();
+    Ok(BACKEND.get_or_init(|| backend))
+}
+
+pub struct LlamaCppBackend {
+    model: LlamaModel,
+}
+
+impl LlamaCppBackend {
+    /// Load a GGUF model from disk
+    pub fn load(model_path: &Path) -> Result<Self> {
+        let backend = llama_backend()?;
+        let model_params = LlamaModelParams::default();
+        let model = LlamaModel::load_from_file(backend, model_path, &model_params)
+            .map_err(|e| EmoError::inference(format!("Failed to load model {}", model_path.display()), e))?;
+
+        Ok(Self { model })
+    }
+
+    /// Resolve the model through the registry, download it into the shared
+    /// cache if needed, then load it
+    pub fn download_and_load(registry: &ModelRegistry, model_dir: &Path, model_id: Option<&str>) -> Result<Self> {
+        // An unreachable registry means the model can't be fetched
+        let model_path = registry.locate(model_id, model_dir).map_err(|e| match e {
+            EmoError::Network { .. } => EmoError::download("Failed to download model", e),
+            e => e,
+        })?;
+        Self::load(&model_path)
+    }
+}
+
+impl TextBackend for LlamaCppBackend {
+    fn generate(
+        &self,
+        prompt: &ChatPrompt,
+        params: &GenerationParams,
+        on_text: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<()> {
+        let backend = llama_backend()?;
+
+        // Create context
+        let ctx_params = LlamaContextParams::default()
+            .with_n_ctx(Some(NonZeroU32::new(2048).unwrap()));
+        let mut ctx = self.model.new_context(backend, ctx_params)
+            .map_err(|e| EmoError::inference("Failed to create context", e))?;
+
+        // Tokenize the prompt
+        let (text, add_bos) = apply_chat_template(&self.model, prompt);
+        let tokens_list = self.model.str_to_token(&text, add_bos)
+            .map_err(|e| EmoError::inference("Failed to tokenize", e))?;
+        if tokens_list.is_empty() {
+            return Err(EmoError::no_answer("Prompt produced no tokens"));
+        }
+
+        // Create batch for prompt processing
+        let mut batch = LlamaBatch::new(tokens_list.len().max(512), 1);
+
+        let last_index = tokens_list.len() - 1;
+        for (i, token) in tokens_list.iter().enumerate() {
+            batch.add(*token, i as i32, &[0], i == last_index)
+                .map_err(|e| EmoError::inference("Failed to add to batch", e))?;
+        }
+
+        // Process the prompt
+        ctx.decode(&mut batch)
+            .map_err(|e| EmoError::inference("Failed to decode", e))?;
+
+        let mut sampler = LlamaSampler::chain_simple([
+            LlamaSampler::temp(params.temperature),
+            LlamaSampler::dist(params.seed),
+        ]);
+
+        let mut decoder = encoding_rs::UTF_8.new_decoder();
+        let mut n_cur = batch.n_tokens();
+
+        for _ in 0..params.max_tokens {
+            // Sample next token (always from the last position in the batch)
+            let new_token_id = sampler.sample(&ctx, batch.n_tokens() - 1);
+            sampler.accept(new_token_id);
+
+            // Check if it's EOG (end of generation)
+            if self.model.is_eog_token(new_token_id) {
+                break;
+            }
+
+            // Decode token to string
+            let token_bytes = self.model.token_to_bytes(new_token_id, Special::Tokenize)
+                .map_err(|e| EmoError::inference("Failed to get token bytes", e))?;
+
+            let mut token_str = String::with_capacity(32);
+            let (_result, _read, _had_errors) = decoder.decode_to_string(&token_bytes, &mut token_str, false);
+
+            if !on_text(&token_str) {
+                break;
+            }
+
+            // Add token to batch for next iteration
+            batch.clear();
+            batch.add(new_token_id, n_cur, &[0], true)
+                .map_err(|e| EmoError::inference("Failed to add to batch", e))?;
+
+            n_cur += 1;
+
+            ctx.decode(&mut batch)
+                .map_err(|e| EmoError::inference("Failed to decode", e))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Format the prompt with the chat template embedded in the GGUF metadata,
+/// falling back to a raw completion prompt when the model has none
+fn apply_chat_template(model: &LlamaModel, prompt: &ChatPrompt) -> (String, AddBos) {
+    let templated = model.chat_template(None).ok().and_then(|template| {
+        let messages = [("system", &prompt.system), ("user", &prompt.user)]
+            .into_iter()
+            .map(|(role, content)| LlamaChatMessage::new(role.to_string(), content.clone()))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .ok()?;
+        model.apply_chat_template(&template, &messages, true).ok()
+    });
+
+    match templated {
+        // The template already emits the BOS token if the model expects one
+        Some(text) => (text, AddBos::Never),
+        None => (prompt.to_completion(), AddBos::Always),
+    }
+}
@@ -0,0 +1,162 @@
+// Scripted backend for hermetic tests and offline development
+//
+// Select it with `EMO_AI_BACKEND=mock:path/to/script.json`. Each generation
+// plays back the next response from the script; the last one repeats once
+// the script runs out.
+//
+// {
+//   "responses": [
+//     { "tokens": ["Sure", "! ", "🚀"] },
+//     { "tokens": [[240, 159], [142, 137]] },
+//     { "error": "model crashed" }
+//   ],
+//   "record": "prompts.jsonl"
+// }
+//
+// Tokens are strings or raw UTF-8 bytes, so emoji split across tokens are
+// decoded the same way as real model output. When `record` is set, every
+// prompt received is appended to that file as a JSON line.
+use super::{GenerationParams, TextBackend};
+use crate::error::{EmoError, Result};
+use crate::prompts::ChatPrompt;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MockToken {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MockResponse {
+    #[serde(default)]
+    pub tokens: Vec<MockToken>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MockScript {
+    pub responses: Vec<MockResponse>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<PathBuf>,
+}
+
+/// A prompt as the mock saw it, with the sampling settings it came with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedPrompt {
+    pub system: String,
+    pub user: String,
+    pub max_tokens: usize,
+    pub temperature: f32,
+}
+
+pub struct MockBackend {
+    script: MockScript,
+    next: AtomicUsize,
+    prompts: Mutex<Vec<RecordedPrompt>>,
+}
+
+impl MockBackend {
+    pub fn new(script: MockScript) -> Self {
+        Self {
+            script,
+            next: AtomicUsize::new(0),
+            prompts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Reply with the given texts in order, one token each
+    pub fn replies<S: Into<String>>(replies: impl IntoIterator<Item = S>) -> Self {
+        Self::new(MockScript {
+            responses: replies
+                .into_iter()
+                .map(|reply| MockResponse {
+                    tokens: vec![MockToken::Text(reply.into())],
+                    error: None,
+                })
+                .collect(),
+            record: None,
+        })
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path).map_err(|e| {
+            EmoError::ConfigError(format!("Failed to open mock script {}: {}", path.display(), e))
+        })?;
+        let mut script: MockScript = serde_json::from_reader(file)?;
+
+        // Relative record paths live next to the script
+        if let (Some(record), Some(dir)) = (script.record.as_mut(), path.parent()) {
+            if record.is_relative() {
+                *record = dir.join(&*record);
+            }
+        }
+        Ok(Self::new(script))
+    }
+
+    /// Every prompt received so far
+    pub fn prompts(&self) -> Vec<RecordedPrompt> {
+        self.prompts.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn record(&self, prompt: RecordedPrompt) -> Result<()> {
+        if let Some(path) = &self.script.record {
+            let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{}", serde_json::to_string(&prompt)?)?;
+        }
+        self.prompts.lock().unwrap_or_else(|e| e.into_inner()).push(prompt);
+        Ok(())
+    }
+}
+
+impl TextBackend for MockBackend {
+    fn generate(
+        &self,
+        prompt: &ChatPrompt,
+        params: &GenerationParams,
+        on_text: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<()> {
+        self.record(RecordedPrompt {
+            system: prompt.system.clone(),
+            user: prompt.user.clone(),
+            max_tokens: params.max_tokens,
+            temperature: params.temperature,
+        })?;
+
+        let index = self.next.fetch_add(1, Ordering::SeqCst);
+        let response = self
+            .script
+            .responses
+            .get(index)
+            .or_else(|| self.script.responses.last())
+            .ok_or_else(|| EmoError::ConfigError("Mock script has no responses".to_string()))?;
+
+        if let Some(error) = &response.error {
+            return Err(EmoError::no_answer(error.clone()));
+        }
+
+        let mut decoder = encoding_rs::UTF_8.new_decoder();
+        for token in response.tokens.iter().take(params.max_tokens) {
+            let bytes = match token {
+                MockToken::Text(text) => text.as_bytes(),
+                MockToken::Bytes(bytes) => bytes.as_slice(),
+            };
+
+            let capacity = decoder.max_utf8_buffer_length(bytes.len()).unwrap_or(bytes.len() + 4);
+            let mut token_str = String::with_capacity(capacity);
+            let (_result, _read, _had_errors) = decoder.decode_to_string(bytes, &mut token_str, false);
+
+            if !on_text(&token_str) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+// Text generation backends for AI emoji selection
+pub mod http;
+pub mod llama;
+pub mod mock;
+
+use crate::error::{EmoError, Result};
+use crate::models::ModelRegistry;
+use crate::prompts::ChatPrompt;
+use std::path::Path;
+use std::sync::Arc;
+
+pub use http::HttpBackend;
+pub use llama::LlamaCppBackend;
+pub use mock::MockBackend;
+
+/// Overrides the backend for every model, e.g. `mock:tests/script.json`
+pub const BACKEND_ENV: &str = "EMO_AI_BACKEND";
+
+/// Sampling settings for one generation
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerationParams {
+    pub max_tokens: usize,
+    pub temperature: f32,
+    pub seed: u32,
+}
+
+pub trait TextBackend: Send + Sync {
+    /// Generate a reply to the prompt. `on_text` receives the output piece by
+    /// piece and returns `false` once it has seen enough.
+    fn generate(
+        &self,
+        prompt: &ChatPrompt,
+        params: &GenerationParams,
+        on_text: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<()>;
+}
+
+// Lets callers keep a handle on a backend they hand to a selector
+impl<T: TextBackend + ?Sized> TextBackend for Arc<T> {
+    fn generate(
+        &self,
+        prompt: &ChatPrompt,
+        params: &GenerationParams,
+        on_text: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<()> {
+        (**self).generate(prompt, params, on_text)
+    }
+}
+
+/// Which backend a `model` value refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelSpec {
+    /// Local GGUF model run through llama.cpp, e.g. `qwen2.5-0.5b` or `llama:qwen2.5-0.5b`
+    Llama(Option<String>),
+    /// Ollama `/api/generate`, e.g. `ollama:llama3.2:1b`
+    Ollama(String),
+    /// OpenAI-compatible `/v1/chat/completions`, e.g. `openai:gpt-4o-mini`
+    OpenAi(String),
+}
+
+impl ModelSpec {
+    pub fn parse(model: Option<&str>) -> Self {
+        let Some(model) = model else {
+            return ModelSpec::Llama(None);
+        };
+
+        match model.split_once(':') {
+            Some(("ollama", name)) => ModelSpec::Ollama(name.to_string()),
+            Some(("openai", name)) => ModelSpec::OpenAi(name.to_string()),
+            Some(("llama", name)) => ModelSpec::Llama(Some(name.to_string())),
+            _ => ModelSpec::Llama(Some(model.to_string())),
+        }
+    }
+}
+
+/// Build the backend for a model, resolving local models through `registry`
+/// and downloading them into `model_dir`. `EMO_AI_BACKEND` takes precedence
+/// over the model setting.
+pub fn connect(
+    model: Option<&str>,
+    base_url: Option<&str>,
+    registry: &ModelRegistry,
+    model_dir: &Path,
+) -> Result<Box<dyn TextBackend>> {
+    if let Some(spec) = std::env::var(BACKEND_ENV).ok().filter(|s| !s.is_empty()) {
+        return from_env(&spec);
+    }
+
+    match ModelSpec::parse(model) {
+        ModelSpec::Llama(id) => Ok(Box::new(LlamaCppBackend::download_and_load(registry, model_dir, id.as_deref())?)),
+        ModelSpec::Ollama(name) if !name.is_empty() => Ok(Box::new(HttpBackend::ollama(base_url, name))),
+        ModelSpec::OpenAi(name) if !name.is_empty() => Ok(Box::new(HttpBackend::openai(base_url, name))),
+        _ => Err(EmoError::ConfigError(format!(
+            "Model '{}' is missing a name after the backend prefix",
+            model.unwrap_or_default()
+        ))),
+    }
+}
+
+fn from_env(spec: &str) -> Result<Box<dyn TextBackend>> {
+    match spec.split_once(':') {
+        Some(("mock", path)) => Ok(Box::new(MockBackend::from_file(Path::new(path))?)),
+        _ => Err(EmoError::ConfigError(format!(
+            "Unknown {} value '{}', expected mock:<script.json>",
+            BACKEND_ENV, spec
+        ))),
+    }
+}
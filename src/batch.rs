@@ -0,0 +1,186 @@
+// Many queries per invocation
+//
+// A `Batch` is set up once for a mode, so the emoji index, the generator
+// pipeline and any model are loaded a single time, then answers one query
+// per line. Each answer is a `Record` that renders as text, a JSON line or a
+// TSV row. Failures are kept in the record so one bad line doesn't stop the
+// rest.
+use crate::ai::SentenceOptions;
+use crate::client::Emo;
+use crate::config::Scope;
+use crate::error::{self, EmoError, Result};
+use crate::generators::EmojiGenerator;
+use crate::memos::Memo;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// The results, space-separated
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    /// Query, then one column per result
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = EmoError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(EmoError::InvalidInput(format!(
+                "Unknown format '{}', expected text, json or tsv",
+                s
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Tsv => "tsv",
+        })
+    }
+}
+
+/// What each query is answered with
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mode {
+    /// The configured strategy, memos first
+    Search { count: usize },
+    /// The emoji's name and definition
+    Define,
+    /// Save a memo mapping each query to these emoji or search result indexes,
+    /// in the config of `scope`
+    Memo { emoji: String, scope: Scope },
+    /// AI picks, falling back to search
+    Ai { count: usize },
+    /// `count` emoji sentences from the first model
+    Sentence { count: usize, options: SentenceOptions },
+}
+
+/// The answer to one query
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Record {
+    pub query: String,
+    pub results: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definition: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// One line, without the newline
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => match (&self.name, self.results.first()) {
+                (Some(name), Some(emoji)) => {
+                    format!("{} - {} {}", emoji, name, self.definition.as_deref().unwrap_or(""))
+                }
+                _ => self.results.join(" "),
+            },
+            Format::Json => serde_json::to_string(self).expect("records serialize"),
+            Format::Tsv => {
+                let mut columns = vec![self.query.as_str()];
+                columns.extend(self.results.iter().map(String::as_str));
+                columns.extend(self.name.as_deref());
+                columns.extend(self.definition.as_deref());
+                columns.iter().map(|c| tsv_field(c)).collect::<Vec<_>>().join("\t")
+            }
+        }
+    }
+}
+
+/// Tabs and newlines would break the row, so they become spaces
+fn tsv_field(field: &str) -> String {
+    field.replace(['\t', '\n', '\r'], " ")
+}
+
+pub struct Batch {
+    mode: Mode,
+    generator: Option<Box<dyn EmojiGenerator>>,
+    emo: Emo,
+    // Memos saved so far, written once at the end
+    memos: Vec<(String, Memo)>,
+}
+
+impl Batch {
+    pub fn new(mode: Mode, emo: Emo) -> Result<Self> {
+        let generator = match &mode {
+            Mode::Search { .. } => Some(emo.search_pipeline()?),
+            Mode::Ai { .. } => Some(emo.ai_pipeline()?),
+            _ => None,
+        };
+        Ok(Batch { mode, generator, emo, memos: Vec::new() })
+    }
+
+    /// Answer one query. Blank queries get an empty record, so output lines
+    /// stay aligned with input lines.
+    pub fn run(&mut self, query: &str) -> Record {
+        let query = query.trim();
+        let mut record = Record {
+            query: query.to_string(),
+            ..Default::default()
+        };
+        if query.is_empty() {
+            return record;
+        }
+
+        if let Err(e) = self.answer(query, &mut record) {
+            record.error = Some(error::report(&e));
+        }
+        record
+    }
+
+    fn answer(&mut self, query: &str, record: &mut Record) -> Result<()> {
+        match &self.mode {
+            Mode::Search { count } | Mode::Ai { count } => {
+                let generator = self.generator.as_ref().expect("generator built for mode");
+                record.results = generator.generate_n(query, *count)?;
+            }
+            Mode::Define => {
+                if let Some((ch, emoji)) = self.emo.define(query) {
+                    record.results.push(ch.to_string());
+                    record.name = Some(emoji.name.clone());
+                    record.definition = emoji.definition.clone();
+                }
+            }
+            Mode::Memo { emoji, .. } => {
+                let memo = self.emo.resolve_memos(query, emoji)?;
+                record.results.extend(memo.emojis().iter().cloned());
+                self.memos.push((query.to_string(), memo));
+            }
+            Mode::Sentence { count, options, .. } => {
+                for _ in 0..*count {
+                    record.results.push(self.emo.sentence(query, options)?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Save what the batch changed; memos are written once at the end
+    pub fn finish(self) -> Result<()> {
+        if self.memos.is_empty() {
+            return Ok(());
+        }
+        let Batch { mut emo, memos, mode, .. } = self;
+        let scope = match mode {
+            Mode::Memo { scope, .. } => scope,
+            _ => Scope::User,
+        };
+        emo.add_memos(scope, memos)
+    }
+}
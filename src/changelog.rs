@@ -0,0 +1,220 @@
+// Markdown changelogs from commit subjects
+//
+// Subjects are grouped by conventional-commit type into sections with an
+// emoji header. Section emoji come from the gitmoji table unless a memo for
+// the type ("feat", "fix", ..., "breaking", "other") says otherwise. Each
+// entry gets its own emoji: the gitmoji it was committed with, or one picked
+// by `Pick`.
+use crate::ai::AiEmojiSelector;
+use crate::error::{EmoError, Result};
+use crate::memos::MemoIndex;
+use crate::{gitmoji, EmojiDatabase};
+use std::str::FromStr;
+
+/// How entries without a gitmoji get their emoji
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pick {
+    /// The gitmoji table; entries it has nothing for go without
+    #[default]
+    Gitmoji,
+    /// Emoji search on the entry's words
+    Search,
+    /// The model
+    Ai,
+}
+
+impl FromStr for Pick {
+    type Err = EmoError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "gitmoji" => Ok(Pick::Gitmoji),
+            "search" => Ok(Pick::Search),
+            "ai" => Ok(Pick::Ai),
+            _ => Err(EmoError::InvalidInput(format!(
+                "Unknown pick '{}', expected gitmoji, search or ai",
+                s
+            ))),
+        }
+    }
+}
+
+/// Sections in changelog order: memo key, title, and the default emoji
+const SECTIONS: &[(&str, &str, &str)] = &[
+    ("breaking", "Breaking Changes", ":boom:"),
+    ("feat", "Features", ":sparkles:"),
+    ("fix", "Bug Fixes", ":bug:"),
+    ("perf", "Performance", ":zap:"),
+    ("refactor", "Refactoring", ":recycle:"),
+    ("docs", "Documentation", ":memo:"),
+    ("test", "Tests", ":white_check_mark:"),
+    ("style", "Style", ":art:"),
+    ("build", "Build", ":package:"),
+    ("ci", "Continuous Integration", ":construction_worker:"),
+    ("chore", "Chores", ":wrench:"),
+    ("revert", "Reverts", ":rewind:"),
+    ("other", "Other Changes", ":bookmark:"),
+];
+
+/// One commit, split into the parts the changelog shows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Memo key of the section the entry goes in
+    pub section: &'static str,
+    pub scope: Option<String>,
+    pub description: String,
+    /// The gitmoji the subject started with, if any
+    pub emoji: Option<String>,
+}
+
+impl Entry {
+    pub fn parse(subject: &str) -> Entry {
+        let (emoji, subject) = match gitmoji::split_prefix(subject) {
+            // Keep codes as emoji, so the changelog reads the same either way
+            Some((prefix, rest)) => {
+                let emoji = gitmoji::find(prefix).map_or(prefix, |g| g.emoji.as_str());
+                (Some(emoji.to_string()), rest)
+            }
+            None => (None, subject.trim()),
+        };
+
+        let conventional = gitmoji::conventional(subject);
+        let section = match &conventional {
+            Some(c) if c.breaking => "breaking",
+            Some(c) => SECTIONS
+                .iter()
+                .map(|(key, _, _)| *key)
+                .find(|key| key.eq_ignore_ascii_case(c.commit_type))
+                .unwrap_or("other"),
+            // "🐛 crash on empty config" is a fix all the same
+            None => emoji
+                .as_deref()
+                .and_then(gitmoji::find)
+                .and_then(|g| SECTIONS.iter().find(|(_, _, code)| *code == g.code))
+                .map_or("other", |(key, _, _)| *key),
+        };
+
+        Entry {
+            section,
+            scope: conventional.as_ref().and_then(|c| c.scope).map(str::to_string),
+            description: conventional.map_or(subject, |c| c.description).to_string(),
+            emoji,
+        }
+    }
+}
+
+/// Subjects from `text`, one per line. Lines from `git log --oneline` lose
+/// their hash.
+pub fn subjects(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.split_once(' ') {
+            Some((hash, rest)) if is_hash(hash) => rest.trim().to_string(),
+            _ => line.to_string(),
+        })
+        .collect()
+}
+
+fn is_hash(word: &str) -> bool {
+    (7..=40).contains(&word.len()) && word.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Commit subjects from `git log` in the current directory, newest first
+pub fn git_log(range: Option<&str>) -> Result<Vec<String>> {
+    let mut git = std::process::Command::new("git");
+    git.args(["log", "--no-merges", "--format=%s"]);
+    if let Some(range) = range {
+        git.arg(range);
+    }
+
+    let output = git.output()?;
+    if !output.status.success() {
+        return Err(EmoError::InvalidInput(format!(
+            "git log failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(subjects(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Render the changelog. `memos` override section emoji by type, the way
+/// they answer queries; `database` is searched for `Pick::Search`;
+/// `selector` is needed for `Pick::Ai` and settles ambiguous gitmoji for
+/// `Pick::Gitmoji`.
+pub fn render(
+    subjects: &[String],
+    pick: Pick,
+    memos: &MemoIndex,
+    database: &EmojiDatabase,
+    selector: Option<&AiEmojiSelector>,
+) -> Result<String> {
+    if subjects.is_empty() {
+        return Err(EmoError::InvalidInput("No commits to write a changelog for".to_string()));
+    }
+    if pick == Pick::Ai && selector.is_none() {
+        return Err(EmoError::InvalidInput("Picking entry emoji with AI needs a model".to_string()));
+    }
+
+    let entries: Vec<Entry> = subjects.iter().map(|s| Entry::parse(s)).collect();
+    let mut sections = Vec::new();
+
+    for (key, title, code) in SECTIONS {
+        let section: Vec<&Entry> = entries.iter().filter(|e| e.section == *key).collect();
+        if section.is_empty() {
+            continue;
+        }
+
+        let emoji = match memos.get(key) {
+            Some((_, memo)) => memo.first().to_string(),
+            None => gitmoji::find(code).map_or(String::new(), |g| g.emoji.clone()),
+        };
+        let mut lines = vec![format!("## {} {}", emoji, title), String::new()];
+        for entry in section {
+            lines.push(render_entry(entry, pick, database, selector));
+        }
+        sections.push(lines.join("\n"));
+    }
+    Ok(format!("{}\n", sections.join("\n\n")))
+}
+
+fn render_entry(entry: &Entry, pick: Pick, database: &EmojiDatabase, selector: Option<&AiEmojiSelector>) -> String {
+    let emoji = entry.emoji.clone().or_else(|| pick_emoji(&entry.description, pick, database, selector));
+    let mut line = String::from("-");
+    if let Some(emoji) = emoji {
+        line.push(' ');
+        line.push_str(&emoji);
+    }
+    if let Some(scope) = &entry.scope {
+        line.push_str(&format!(" **{}:**", scope));
+    }
+    line.push(' ');
+    line.push_str(&entry.description);
+    line
+}
+
+fn pick_emoji(
+    description: &str,
+    pick: Pick,
+    database: &EmojiDatabase,
+    selector: Option<&AiEmojiSelector>,
+) -> Option<String> {
+    match pick {
+        Pick::Gitmoji => gitmoji::suggest(description, selector).ok().map(|g| g.emoji.clone()),
+        Pick::Search => search_emoji(database, description),
+        Pick::Ai => match selector?.select_emoji_llm(description) {
+            Ok(emoji) => Some(emoji),
+            Err(e) => {
+                eprintln!("Warning: no emoji for '{}': {}", description, crate::error::report(&e));
+                None
+            }
+        },
+    }
+}
+
+/// The first search hit for the whole description, then for its longer words
+fn search_emoji(database: &EmojiDatabase, description: &str) -> Option<String> {
+    std::iter::once(description)
+        .chain(description.split_whitespace().filter(|w| w.len() > 3))
+        .find_map(|term| database.search(term, 1).first().map(|(ch, _)| ch.to_string()))
+}
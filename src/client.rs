@@ -0,0 +1,631 @@
+// High-level entry point for embedding emo
+//
+// `Emo` bundles everything the CLI works with: the config (memos, model,
+// strategy), the emoji records, and one AI selector whose model is loaded on
+// first use and then kept. Paths are explicit, so nothing depends on the
+// process environment unless the defaults are used.
+//
+//     let mut emo = Emo::builder().config_path("/srv/emo/config.json").build()?;
+//     emo.set_memo("deploy", "🚀")?;
+//     assert_eq!(emo.search("deploy", 1)?, vec!["🚀"]);
+use crate::ai::{AiEmojiSelector, SentenceOptions};
+use crate::backend::TextBackend;
+use crate::batch::Format;
+use crate::config::{self, Layer, Overrides, Scope};
+use crate::memos::{self, ImportSummary, Memo, MemoIndex, Merge};
+use crate::error::{EmoError, Result};
+use crate::feedback::{FeedbackLog, FeedbackRecord};
+use crate::generators::strategy::{self, Sources};
+use crate::generators::{Candidate, EmojiGenerator};
+use crate::{memo_emoji, EmojiDatabase, EmojiMappings, EmojiRecord};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+enum Overlay {
+    File(PathBuf),
+    Records(Vec<EmojiRecord>),
+}
+
+/// Configures an `Emo`. Anything left unset falls back to what the CLI uses:
+/// `emo/config.json` and `emo/models` in the config directory, the bundled
+/// emoji data, and the model from config.
+#[derive(Default)]
+pub struct EmoBuilder {
+    config_path: Option<PathBuf>,
+    system_config: Option<PathBuf>,
+    project_config: Option<PathBuf>,
+    model_dir: Option<PathBuf>,
+    database: Option<Arc<EmojiDatabase>>,
+    overlays: Vec<Overlay>,
+    models: Vec<String>,
+    backend: Option<Box<dyn TextBackend>>,
+    overrides: Overrides,
+    profile: Option<String>,
+}
+
+impl EmoBuilder {
+    /// The config file with memos, model and strategy. Feedback on AI picks
+    /// is kept next to it.
+    pub fn config_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config_path = Some(path.into());
+        self
+    }
+
+    /// A config under the user's, like `/etc/emo/config.json`
+    pub fn system_config(mut self, path: impl Into<PathBuf>) -> Self {
+        self.system_config = Some(path.into());
+        self
+    }
+
+    /// A config over the user's, like a repository's `.emo.json`. It doesn't
+    /// have to exist yet; memos saved to the project scope create it.
+    pub fn project_config(mut self, path: impl Into<PathBuf>) -> Self {
+        self.project_config = Some(path.into());
+        self
+    }
+
+    /// Where downloaded models are kept
+    pub fn model_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.model_dir = Some(path.into());
+        self
+    }
+
+    /// The emoji data, instead of the bundled data. Clients given the same
+    /// database share it.
+    pub fn database(mut self, database: Arc<EmojiDatabase>) -> Self {
+        self.database = Some(database);
+        self
+    }
+
+    /// Emoji records in the bundled format to lay over the emoji data. A
+    /// record replaces the one with the same `unicode`.
+    pub fn overlay_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.overlays.push(Overlay::File(path.into()));
+        self
+    }
+
+    pub fn overlay(mut self, records: Vec<EmojiRecord>) -> Self {
+        self.overlays.push(Overlay::Records(records));
+        self
+    }
+
+    /// The model for AI operations, instead of the one in config
+    pub fn model(self, model: impl Into<String>) -> Self {
+        self.models(vec![model.into()])
+    }
+
+    /// Several models vote on AI picks; the first one writes sentences
+    pub fn models(mut self, models: Vec<String>) -> Self {
+        self.models = models;
+        self
+    }
+
+    /// Settings that beat the config file, like `Overrides::from_env()`.
+    /// Explicit models still beat an overridden model.
+    pub fn overrides(mut self, overrides: Overrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// The memo profile to use, instead of `EMO_PROFILE` or the config's
+    /// `profile`; "base" is the top-level memos
+    pub fn profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// An already constructed backend for AI operations
+    pub fn backend(mut self, backend: Box<dyn TextBackend>) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    pub fn build(self) -> Result<Emo> {
+        let config_path = match self.config_path {
+            Some(path) => path,
+            None => EmojiMappings::default_path()?,
+        };
+        let model_dir = self
+            .model_dir
+            .unwrap_or_else(|| config_path.with_file_name("models"));
+        let layer = |scope, path: PathBuf| -> Result<Layer> {
+            Ok(Layer { scope, config: EmojiMappings::load_from(&path)?, path })
+        };
+        let mut layers = Vec::new();
+        if let Some(path) = self.system_config {
+            layers.push(layer(Scope::System, path)?);
+        }
+        layers.push(layer(Scope::User, config_path.clone())?);
+        // A project config that doesn't exist yet isn't in use until a memo
+        // is saved to it
+        let project_path = self.project_config;
+        if let Some(path) = project_path.as_ref().filter(|p| p.is_file()) {
+            layers.push(layer(Scope::Project, path.clone())?);
+        }
+        let config = config::merge(layers.iter().map(|l| &l.config));
+
+        let mut database = self.database.unwrap_or_default();
+        for overlay in self.overlays {
+            let records = match overlay {
+                Overlay::File(path) => {
+                    let file = std::fs::File::open(&path).map_err(|e| {
+                        EmoError::ConfigError(format!("Failed to open overlay {}: {}", path.display(), e))
+                    })?;
+                    serde_json::from_reader(file)?
+                }
+                Overlay::Records(records) => records,
+            };
+            database = Arc::new(database.with_overlay(records));
+        }
+
+        let models = match (self.models.is_empty(), &self.overrides.model) {
+            (true, Some(model)) => vec![model.clone()],
+            _ => self.models,
+        };
+        let profile = self
+            .profile
+            .or_else(|| self.overrides.profile.clone())
+            .or_else(|| config.profile.clone())
+            .filter(|p| config::profile_name(Some(p)).is_some());
+        let resolved = resolve(&config, profile.as_deref())?;
+        let memo_index = memo_index(&config, &layers, profile.as_deref())?;
+
+        let model = models.first().cloned();
+        let injected_backend = self.backend.is_some();
+        let selector = match self.backend {
+            Some(backend) => AiEmojiSelector::with_backend(backend),
+            None => match model {
+                Some(model) => AiEmojiSelector::with_model(model),
+                None => AiEmojiSelector::new(),
+            },
+        };
+
+        Ok(Emo {
+            selector: Arc::new(
+                selector
+                    .with_paths(config_path.clone(), model_dir.clone())
+                    .with_layers(layers.iter().map(|l| l.path.clone()).collect())
+                    .with_profile(profile.clone())
+                    .with_config(resolved.clone())
+                    .with_database(database.clone()),
+            ),
+            config_path,
+            model_dir,
+            config,
+            resolved,
+            memo_index,
+            profile,
+            layers,
+            project_path,
+            injected_backend,
+            database,
+            models,
+            overrides: self.overrides,
+        })
+    }
+}
+
+/// The config with the memos of `profile` as its `mappings`
+fn resolve(config: &EmojiMappings, profile: Option<&str>) -> Result<EmojiMappings> {
+    Ok(EmojiMappings {
+        mappings: config::profile_memos(config, profile)?,
+        ..config.clone()
+    })
+}
+
+/// The memos of each layer along the profile chain, most specific first:
+/// the profile's memos in every layer before those of the profile it inherits
+fn memo_index(config: &EmojiMappings, layers: &[Layer], profile: Option<&str>) -> Result<MemoIndex> {
+    let mut chain: Vec<Option<&str>> = config::profile_chain(config, profile)?.into_iter().map(Some).collect();
+    chain.push(None);
+    let memos = chain
+        .into_iter()
+        .flat_map(|profile| layers.iter().rev().filter_map(move |l| config::own_memos(&l.config, profile)));
+    Ok(MemoIndex::layered(memos))
+}
+
+pub struct Emo {
+    config_path: PathBuf,
+    model_dir: PathBuf,
+    /// All layers merged
+    config: EmojiMappings,
+    /// `config` with the active profile's memos, what lookups use
+    resolved: EmojiMappings,
+    /// The memos of `resolved`, ready for queries
+    memo_index: MemoIndex,
+    /// The active memo profile; None for the base profile
+    profile: Option<String>,
+    layers: Vec<Layer>,
+    /// Where the project config is or would go, even when not in use
+    project_path: Option<PathBuf>,
+    /// Set when `EmoBuilder::backend` was used
+    injected_backend: bool,
+    database: Arc<EmojiDatabase>,
+    models: Vec<String>,
+    overrides: Overrides,
+    selector: Arc<AiEmojiSelector>,
+}
+
+// Callers share one client across threads, so keep it Send + Sync
+fn assert_send_sync<T: Send + Sync>() {}
+const _: fn() = assert_send_sync::<Emo>;
+
+impl Emo {
+    pub fn builder() -> EmoBuilder {
+        EmoBuilder::default()
+    }
+
+    /// Everything from the default locations, like the CLI
+    pub fn new() -> Result<Self> {
+        Self::builder().build()
+    }
+
+    pub fn config(&self) -> &EmojiMappings {
+        &self.config
+    }
+
+    /// Changes apply to this client only; `update_config` saves them
+    pub fn config_mut(&mut self) -> &mut EmojiMappings {
+        &mut self.config
+    }
+
+    /// The config files in use, lowest precedence first
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    pub fn layer(&self, scope: Scope) -> Option<&Layer> {
+        self.layers.iter().find(|l| l.scope == scope)
+    }
+
+    /// Change the user config on disk and here. The file is locked and read
+    /// fresh first, so changes saved by other processes since `build` are kept.
+    pub fn update_config<T>(&mut self, change: impl FnOnce(&mut EmojiMappings) -> Result<T>) -> Result<T> {
+        self.update_layer(Scope::User, change)
+    }
+
+    /// Like `update_config`, for the config of another scope
+    pub fn update_layer<T>(
+        &mut self,
+        scope: Scope,
+        change: impl FnOnce(&mut EmojiMappings) -> Result<T>,
+    ) -> Result<T> {
+        if scope == Scope::Project && self.layer(scope).is_none() {
+            if let Some(path) = self.project_path.clone() {
+                self.layers.push(Layer { scope, path, config: EmojiMappings::default() });
+            }
+        }
+        let layer = self
+            .layers
+            .iter_mut()
+            .find(|l| l.scope == scope)
+            .ok_or_else(|| EmoError::ConfigError(format!("No {} config in use", scope)))?;
+        let (config, output) = EmojiMappings::update(&layer.path, change)?;
+        layer.config = config;
+        self.config = config::merge(self.layers.iter().map(|l| &l.config));
+        self.resolved = resolve(&self.config, self.profile.as_deref())?;
+        self.memo_index = memo_index(&self.config, &self.layers, self.profile.as_deref())?;
+        Ok(output)
+    }
+
+    /// The active memo profile; None for the base profile
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    pub fn overrides(&self) -> &Overrides {
+        &self.overrides
+    }
+
+    /// The default number of results: overridden, from config, or 1
+    pub fn count(&self) -> usize {
+        self.overrides.count.or(self.config.count).unwrap_or(1)
+    }
+
+    /// The default output format: overridden, from config, or text
+    pub fn format(&self) -> Format {
+        self.overrides.format.or(self.config.format).unwrap_or_default()
+    }
+
+    pub fn config_path(&self) -> &Path {
+        &self.config_path
+    }
+
+    pub fn model_dir(&self) -> &Path {
+        &self.model_dir
+    }
+
+    /// The emoji data with any overlays applied
+    pub fn database(&self) -> &Arc<EmojiDatabase> {
+        &self.database
+    }
+
+    pub fn emojis(&self) -> &[EmojiRecord] {
+        self.database.records()
+    }
+
+    /// The selector behind AI operations, for `annotate`, `gitmoji` and such
+    pub fn selector(&self) -> &AiEmojiSelector {
+        &self.selector
+    }
+
+    fn sources(&self) -> Sources<'_> {
+        Sources {
+            database: Some(self.database.clone()),
+            selector: Some(self.selector.clone()),
+            injected_backend: self.injected_backend,
+            paths: Some((self.config_path.clone(), self.model_dir.clone())),
+            layers: self.layers.iter().map(|l| l.path.clone()).collect(),
+            profile: self.profile.clone(),
+            memo_index: Some(&self.memo_index),
+            ..Sources::new(&self.resolved, &self.models)
+        }
+    }
+
+    /// The pipeline for plain queries: the config's strategy, memos first by
+    /// default
+    pub fn search_pipeline(&self) -> Result<Box<dyn EmojiGenerator>> {
+        let strategy = self
+            .overrides
+            .strategy
+            .as_deref()
+            .or(self.config.strategy.as_deref())
+            .unwrap_or(strategy::DEFAULT_STRATEGY);
+        strategy::build_from(strategy, &self.sources())
+    }
+
+    /// The pipeline for AI queries: the model, or search if it fails
+    pub fn ai_pipeline(&self) -> Result<Box<dyn EmojiGenerator>> {
+        strategy::build_from(strategy::AI_STRATEGY, &self.sources())
+    }
+
+    pub fn search(&self, query: &str, count: usize) -> Result<Vec<String>> {
+        self.search_pipeline()?.generate_n(query, count)
+    }
+
+    /// Ranked AI picks with their scores, falling back to search
+    pub fn ai(&self, situation: &str, count: usize) -> Result<Vec<Candidate>> {
+        self.ai_pipeline()?.candidates(situation, count)
+    }
+
+    pub fn sentence(&self, situation: &str, options: &SentenceOptions) -> Result<String> {
+        self.selector.generate_sentence(situation, options)
+    }
+
+    pub fn explain(&self, message: &str) -> Result<String> {
+        self.selector.explain_emoji(message)
+    }
+
+    /// The emoji a query is about, with its record
+    pub fn define(&self, query: &str) -> Option<(char, &EmojiRecord)> {
+        self.database.define(query)
+    }
+
+    pub fn random(&self) -> Result<(char, &EmojiRecord)> {
+        use std::collections::hash_map::RandomState;
+        use std::hash::BuildHasher;
+
+        let emojis = self.emojis();
+        if emojis.is_empty() {
+            return Err(EmoError::InvalidInput("No emojis available".to_string()));
+        }
+
+        // Use system time as a simple random seed
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+
+        let emoji = &emojis[RandomState::new().hash_one(seed) as usize % emojis.len()];
+        Ok((crate::to_char(emoji)?, emoji))
+    }
+
+    /// The active profile's memos from all layers, sorted by term
+    pub fn memos(&self) -> Vec<(&str, &Memo)> {
+        let mut memos: Vec<_> = self.resolved.mappings.iter().map(|(t, m)| (t.as_str(), m)).collect();
+        memos.sort_by_key(|(term, _)| *term);
+        memos
+    }
+
+    /// The top emoji of the memo that answers a query, saved for the query
+    /// itself or for a pattern matching it
+    pub fn memo(&self, query: &str) -> Option<&str> {
+        self.memo_index.get(query).map(|(_, memo)| memo.first())
+    }
+
+    /// The memos in effect, as queries see them
+    pub fn memo_index(&self) -> &MemoIndex {
+        &self.memo_index
+    }
+
+    /// The whole list saved under a term or pattern, best first
+    pub fn memo_list(&self, term: &str) -> Option<&Memo> {
+        self.resolved.mappings.get(term)
+    }
+
+    /// Where a memo in effect comes from: the layer, and the profile (None
+    /// for the base profile)
+    pub fn memo_origin(&self, term: &str) -> Option<(Scope, Option<&str>)> {
+        let mut chain: Vec<Option<&str>> = config::profile_chain(&self.config, self.profile.as_deref())
+            .ok()?
+            .into_iter()
+            .map(Some)
+            .collect();
+        chain.push(None);
+        chain.into_iter().find_map(|profile| {
+            self.layers
+                .iter()
+                .rev()
+                .find(|l| config::own_memos(&l.config, profile).is_some_and(|m| m.contains_key(term)))
+                .map(|l| (l.scope, profile))
+        })
+    }
+
+    /// The layer a memo in effect comes from
+    pub fn memo_scope(&self, term: &str) -> Option<Scope> {
+        self.memo_origin(term).map(|(scope, _)| scope)
+    }
+
+    /// The emoji a memo would hold: the emoji itself, or the search result
+    /// at a 1-based index
+    pub fn resolve_memo(&self, term: &str, emoji_or_index: &str) -> Result<String> {
+        memo_emoji(self.emojis(), emoji_or_index, term)
+    }
+
+    /// A ranked list from space-separated emoji or indexes, like "🎉 2 🎊"
+    pub fn resolve_memos(&self, term: &str, emojis_or_indexes: &str) -> Result<Memo> {
+        let emojis = emojis_or_indexes
+            .split_whitespace()
+            .map(|e| self.resolve_memo(term, e))
+            .collect::<Result<Vec<_>>>()?;
+        if emojis.is_empty() {
+            return Err(EmoError::InvalidInput(
+                "Cannot save mapping for empty search term or emoji".to_string(),
+            ));
+        }
+        Memo::new(emojis)
+    }
+
+    /// Save a memo and write the user config
+    pub fn set_memo(&mut self, term: &str, emojis_or_indexes: &str) -> Result<Memo> {
+        self.set_memo_in(Scope::User, term, emojis_or_indexes)
+    }
+
+    /// Save a memo to the active profile and write the config of `scope`,
+    /// replacing any list it had
+    pub fn set_memo_in(&mut self, scope: Scope, term: &str, emojis_or_indexes: &str) -> Result<Memo> {
+        let memo = self.resolve_memos(term, emojis_or_indexes)?;
+        self.add_memos(scope, vec![(term.to_string(), memo.clone())])?;
+        Ok(memo)
+    }
+
+    /// Add an emoji to the end of a memo's list, starting one if needed
+    pub fn append_memo(&mut self, scope: Scope, term: &str, emoji_or_index: &str) -> Result<Memo> {
+        let emoji = self.resolve_memo(term, emoji_or_index)?;
+        let memo = self.edit_memo(scope, term, |memo| match memo {
+            Some(mut memo) => memo.push(&emoji).map(|_| Some(memo)),
+            None => Memo::new([emoji]).map(Some),
+        })?;
+        Ok(memo.expect("appending keeps the memo"))
+    }
+
+    /// Move an emoji of a memo to a 1-based position
+    pub fn move_memo(&mut self, scope: Scope, term: &str, emoji: &str, position: usize) -> Result<Memo> {
+        let emoji = self.resolve_memo(term, emoji)?;
+        let memo = self.edit_memo(scope, term, |memo| {
+            let mut memo = memo.ok_or_else(|| no_memo(term))?;
+            memo.move_to(&emoji, position)?;
+            Ok(Some(memo))
+        })?;
+        Ok(memo.expect("moving keeps the memo"))
+    }
+
+    /// Take one emoji out of a memo; the memo goes away with its last emoji
+    pub fn remove_memo_emoji(&mut self, scope: Scope, term: &str, emoji: &str) -> Result<Option<Memo>> {
+        let emoji = self.resolve_memo(term, emoji)?;
+        self.edit_memo(scope, term, |memo| memo.ok_or_else(|| no_memo(term))?.without(&emoji))
+    }
+
+    /// Change a memo of the active profile in the config of `scope`. The
+    /// edit starts from the memo saved there, or else the one in effect, so
+    /// editing a memo from another layer copies it into `scope`.
+    fn edit_memo(
+        &mut self,
+        scope: Scope,
+        term: &str,
+        edit: impl FnOnce(Option<Memo>) -> Result<Option<Memo>>,
+    ) -> Result<Option<Memo>> {
+        let profile = self.profile.clone();
+        let current = self.memo_list(term).cloned();
+        memos::check_key(term)?;
+        self.update_layer(scope, |config| {
+            let memos = config::own_memos_mut(config, profile.as_deref());
+            let edited = edit(memos.get(term).cloned().or(current))?;
+            match &edited {
+                Some(memo) => memos.insert(term.to_string(), memo.clone()),
+                None => memos.shift_remove(term),
+            };
+            Ok(edited)
+        })
+    }
+
+    /// Save memos to the active profile in the config of `scope`
+    pub fn add_memos(&mut self, scope: Scope, memos: Vec<(String, Memo)>) -> Result<()> {
+        for (term, _) in &memos {
+            memos::check_key(term)?;
+        }
+        let profile = self.profile.clone();
+        self.update_layer(scope, |config| {
+            config::own_memos_mut(config, profile.as_deref()).extend(memos);
+            Ok(())
+        })
+    }
+
+    /// Merge memos from a file into the config of `scope`. Nothing is
+    /// written with `Merge::Report`.
+    pub fn import_memos(
+        &mut self,
+        scope: Scope,
+        imported: Vec<(String, Vec<String>)>,
+        strategy: Merge,
+    ) -> Result<ImportSummary> {
+        let profile = self.profile.clone();
+        if strategy == Merge::Report {
+            let mut mappings = self
+                .layer(scope)
+                .and_then(|l| config::own_memos(&l.config, profile.as_deref()))
+                .cloned()
+                .unwrap_or_default();
+            return Ok(memos::merge(&mut mappings, imported, strategy));
+        }
+        self.update_layer(scope, |config| {
+            Ok(memos::merge(config::own_memos_mut(config, profile.as_deref()), imported, strategy))
+        })
+    }
+
+    /// Remove a memo from the user config, writing it if there was one
+    pub fn remove_memo(&mut self, term: &str) -> Result<bool> {
+        self.remove_memo_in(Scope::User, term)
+    }
+
+    /// Remove a memo of the active profile from the config of `scope`
+    pub fn remove_memo_in(&mut self, scope: Scope, term: &str) -> Result<bool> {
+        if term.is_empty() {
+            return Err(EmoError::InvalidInput(
+                "Cannot erase mapping for empty search term".to_string(),
+            ));
+        }
+        let profile = self.profile.clone();
+        let saved = |config: &EmojiMappings| {
+            config::own_memos(config, profile.as_deref()).is_some_and(|m| m.contains_key(term))
+        };
+        // Nothing to write, and no project config to create
+        if !self.layer(scope).is_some_and(|l| saved(&l.config)) {
+            return Ok(false);
+        }
+        self.update_layer(scope, |config| {
+            Ok(config::own_memos_mut(config, profile.as_deref()).shift_remove(term).is_some())
+        })
+    }
+
+    fn feedback_path(&self) -> PathBuf {
+        self.config_path.with_file_name("feedback.json")
+    }
+
+    /// Keep AI picks around so they can be accepted or rejected later
+    pub fn remember(&self, situation: &str, picks: &[String]) -> Result<()> {
+        FeedbackLog::update(&self.feedback_path(), |feedback| {
+            feedback.remember(situation, picks);
+            Ok(())
+        })
+    }
+
+    /// Mark emojis of the last AI suggestion, or all of it, as good or bad
+    pub fn judge(&self, emojis: &[String], accepted: bool) -> Result<Vec<FeedbackRecord>> {
+        FeedbackLog::update(&self.feedback_path(), |feedback| feedback.judge(emojis, accepted))
+    }
+}
+
+fn no_memo(term: &str) -> EmoError {
+    EmoError::InvalidInput(format!("No memo for '{}'", term))
+}
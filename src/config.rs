@@ -0,0 +1,630 @@
+// Reading, migrating and editing the config file
+//
+// The file carries a schema `version`. Older files are migrated in memory
+// when they are read and written back in the current schema on the next
+// save. Fields are checked one by one, so a typo or a wrong value names the
+// field instead of surfacing as a bare serde error.
+//
+// Settings are resolved in this order: command-line flags, then `EMO_*`
+// environment variables (`Overrides`), then the config files, then defaults.
+// The config files are layered (`Scope`): the nearest project `.emo.json`
+// beats the user config, which beats the system config.
+//
+// Memos can be grouped in named profiles. The top-level `mappings` are the
+// base profile; every other profile adds to and overrides the memos of the
+// profile it `inherits`, the base one unless it names another. Layers are
+// merged first, then the active profile's chain is applied.
+//
+// Several emo processes may share a config, so changes happen under an
+// advisory lock on a `.lock` file next to it, and the file is only ever
+// replaced whole by renaming a finished temp file over it. The config being
+// replaced is kept as a `.bak` backup when it is good, and restored when the
+// config no longer parses.
+use crate::batch::Format;
+use crate::error::{EmoError, Result};
+use crate::memos::Memo;
+use crate::EmojiMappings;
+use fs2::FileExt;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::ffi::OsString;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// The schema `save` writes
+pub const CONFIG_VERSION: u32 = 4;
+
+/// Top-level fields and what they hold
+pub const FIELDS: &[(&str, &str)] = &[
+    ("version", "schema version, managed by emo"),
+    ("mappings", "memos: search term to an emoji or a ranked list"),
+    ("model", "default AI model"),
+    ("ensemble", "models that vote on AI picks, instead of `model`"),
+    ("prompts", "prompt overrides keyed by model id"),
+    ("base_url", "server for ollama: and openai: models"),
+    ("strategy", "generator pipeline for plain queries"),
+    ("count", "default number of results"),
+    ("format", "default output format: text, json or tsv"),
+    ("profile", "memo profile to use when none is given"),
+    ("profiles", "named memo profiles"),
+];
+
+/// Parse a config file's text, migrating older schemas. `path` is only used
+/// in error messages.
+pub fn parse(text: &str, path: &Path) -> Result<EmojiMappings> {
+    let mut value: Value = serde_json::from_str(text).map_err(|source| EmoError::ConfigParse {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    migrate(&mut value, path)?;
+    from_value(value, Some(path))
+}
+
+/// Bring a config up to `CONFIG_VERSION`
+///
+/// Version 1 (no `version` field) and version 2 hold one emoji per memo, so
+/// they read as they are. Version 3 allows a list of emoji per memo, which
+/// older versions can't read. Version 4 allows emoji of several characters,
+/// like "👍🏽" or "❤️"; older versions saved "❤" without its selector, and
+/// reading adds it back. Older versions also took any character, like "λ";
+/// those memos read as they are, though new ones must be emoji.
+pub fn migrate(value: &mut Value, path: &Path) -> Result<()> {
+    let Some(object) = value.as_object_mut() else {
+        return Err(EmoError::ConfigError(format!(
+            "{} should hold a JSON object",
+            path.display()
+        )));
+    };
+
+    let version = match object.get("version") {
+        None => 1,
+        Some(v) => v.as_u64().ok_or_else(|| {
+            EmoError::ConfigError(format!("'version' in {} should be a number", path.display()))
+        })?,
+    };
+    if version > CONFIG_VERSION as u64 {
+        return Err(EmoError::ConfigError(format!(
+            "{} uses config version {}, but this emo only knows up to {}; please upgrade emo",
+            path.display(),
+            version,
+            CONFIG_VERSION
+        )));
+    }
+
+    object.insert("version".to_string(), Value::from(CONFIG_VERSION));
+    Ok(())
+}
+
+/// Where a config layer lives, lowest precedence first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Scope {
+    /// `/etc/emo/config.json`, shared by everyone on the machine
+    System,
+    /// The config in the user's config directory
+    User,
+    /// The nearest `.emo.json`, usually committed with a repository
+    Project,
+}
+
+impl std::str::FromStr for Scope {
+    type Err = EmoError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "system" => Ok(Scope::System),
+            "user" => Ok(Scope::User),
+            "project" => Ok(Scope::Project),
+            _ => Err(EmoError::InvalidInput(format!(
+                "Unknown scope '{}', expected project, user or system",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Scope::System => "system",
+            Scope::User => "user",
+            Scope::Project => "project",
+        })
+    }
+}
+
+/// The name of a project config
+pub const PROJECT_FILE: &str = ".emo.json";
+
+/// The system config: `EMO_SYSTEM_CONFIG`, or `/etc/emo/config.json`
+pub fn system_path() -> PathBuf {
+    std::env::var_os("EMO_SYSTEM_CONFIG")
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/etc/emo/config.json"))
+}
+
+/// The nearest `.emo.json` in `dir` or one of its parents
+pub fn find_project(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|d| d.join(PROJECT_FILE))
+        .find(|path| path.is_file())
+}
+
+/// Where a new project config goes: the root of the enclosing git
+/// repository, or `dir` outside of one
+pub fn new_project_path(dir: &Path) -> PathBuf {
+    dir.ancestors()
+        .find(|d| d.join(".git").exists())
+        .unwrap_or(dir)
+        .join(PROJECT_FILE)
+}
+
+/// The project config for `dir`: the nearest existing one, or where a new
+/// one would go
+pub fn project_path(dir: &Path) -> PathBuf {
+    find_project(dir).unwrap_or_else(|| new_project_path(dir))
+}
+
+/// One config file in the search path
+#[derive(Debug, Clone)]
+pub struct Layer {
+    pub scope: Scope,
+    pub path: PathBuf,
+    pub config: EmojiMappings,
+}
+
+/// Combine layers given lowest precedence first. Memos, profiles and
+/// prompts merge key by key; any other setting is taken from the highest layer that sets
+/// it.
+pub fn merge<'a>(layers: impl IntoIterator<Item = &'a EmojiMappings>) -> EmojiMappings {
+    let layers: Vec<&EmojiMappings> = layers.into_iter().collect();
+    let mut merged = EmojiMappings {
+        mappings: overlay(layers.iter().rev().map(|c| &c.mappings)),
+        ..EmojiMappings::default()
+    };
+    for config in &layers {
+        for (name, profile) in &config.profiles {
+            let target = merged.profiles.entry(name.clone()).or_default();
+            if profile.inherits.is_some() {
+                target.inherits = profile.inherits.clone();
+            }
+        }
+        merged.profile = config.profile.clone().or(merged.profile);
+        merged.prompts.extend(config.prompts.iter().map(|(k, v)| (k.clone(), v.clone())));
+        // `model` and `ensemble` are one choice, made by a single layer
+        if config.model.is_some() || !config.ensemble.is_empty() {
+            merged.model = config.model.clone();
+            merged.ensemble = config.ensemble.clone();
+        }
+        merged.base_url = config.base_url.clone().or(merged.base_url);
+        merged.strategy = config.strategy.clone().or(merged.strategy);
+        merged.count = config.count.or(merged.count);
+        merged.format = config.format.or(merged.format);
+    }
+    for (name, target) in merged.profiles.iter_mut() {
+        target.mappings = overlay(layers.iter().rev().filter_map(|c| c.profiles.get(name)).map(|p| &p.mappings));
+    }
+    merged.version = CONFIG_VERSION;
+    merged
+}
+
+/// Memos from several maps, most specific first. A term keeps the memo of
+/// the first map that has it, and patterns keep that order too, so the
+/// patterns of a more specific map are tried first.
+fn overlay<'a>(maps: impl IntoIterator<Item = &'a IndexMap<String, Memo>>) -> IndexMap<String, Memo> {
+    let mut memos = IndexMap::new();
+    for map in maps {
+        for (term, memo) in map {
+            memos.entry(term.clone()).or_insert_with(|| memo.clone());
+        }
+    }
+    memos
+}
+
+/// The name of the top-level `mappings`
+pub const BASE_PROFILE: &str = "base";
+
+/// Memos for one context, like "work", stored under `profiles.<name>`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    /// The profile this one adds to; the base profile when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherits: Option<String>,
+    #[serde(default)]
+    pub mappings: IndexMap<String, Memo>,
+}
+
+/// A profile name, or None for the base profile
+pub fn profile_name(name: Option<&str>) -> Option<&str> {
+    name.filter(|n| *n != BASE_PROFILE)
+}
+
+/// The profiles whose memos apply, most specific first, without the base
+/// profile. A profile that isn't defined has no memos of its own.
+pub fn profile_chain<'a>(config: &'a EmojiMappings, profile: Option<&'a str>) -> Result<Vec<&'a str>> {
+    let mut chain = Vec::new();
+    let mut next = profile_name(profile);
+    while let Some(name) = next {
+        if chain.contains(&name) {
+            chain.push(name);
+            return Err(EmoError::ConfigError(format!(
+                "Profiles inherit from each other in a loop: {}",
+                chain.join(" → ")
+            )));
+        }
+        chain.push(name);
+        next = profile_name(config.profiles.get(name).and_then(|p| p.inherits.as_deref()));
+    }
+    Ok(chain)
+}
+
+/// The memos in effect for a profile: the base memos, overridden along the
+/// inheritance chain
+pub fn profile_memos(config: &EmojiMappings, profile: Option<&str>) -> Result<IndexMap<String, Memo>> {
+    let chain = profile_chain(config, profile)?;
+    let own = chain.into_iter().filter_map(|name| config.profiles.get(name)).map(|p| &p.mappings);
+    Ok(overlay(own.chain([&config.mappings])))
+}
+
+/// A profile's own memos, the ones memo commands change
+pub fn own_memos<'a>(config: &'a EmojiMappings, profile: Option<&str>) -> Option<&'a IndexMap<String, Memo>> {
+    match profile_name(profile) {
+        None => Some(&config.mappings),
+        Some(name) => config.profiles.get(name).map(|p| &p.mappings),
+    }
+}
+
+/// Like `own_memos`, creating the profile if needed
+pub fn own_memos_mut<'a>(config: &'a mut EmojiMappings, profile: Option<&str>) -> &'a mut IndexMap<String, Memo> {
+    match profile_name(profile) {
+        None => &mut config.mappings,
+        Some(name) => &mut config.profiles.entry(name.to_string()).or_default().mappings,
+    }
+}
+
+/// `path` with `suffix` added, "config.json" -> "config.json.bak"
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Where the lock, backup and broken copy of a config go: next to it, but
+/// for a project's `.emo.json` in the user's cache directory, so they stay
+/// out of the repository
+fn aside(path: &Path, suffix: &str) -> PathBuf {
+    let cache = std::env::var_os("XDG_CACHE_HOME")
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from)
+        .or_else(dirs::cache_dir);
+    match cache {
+        Some(cache) if path.file_name().is_some_and(|name| name == PROJECT_FILE) => {
+            let dir = match path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => Path::new("."),
+            };
+            let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+            // One directory per project, named after its path
+            let key: String = dir
+                .to_string_lossy()
+                .chars()
+                .map(|c| if matches!(c, '/' | '\\' | ':') { '%' } else { c })
+                .collect();
+            sibling(&cache.join("emo").join("projects").join(key).join(PROJECT_FILE), suffix)
+        }
+        _ => sibling(path, suffix),
+    }
+}
+
+/// Where the last good config is kept
+pub fn backup_path(path: &Path) -> PathBuf {
+    aside(path, ".bak")
+}
+
+/// Where a config that couldn't be parsed is moved when the backup replaces it
+pub fn corrupt_path(path: &Path) -> PathBuf {
+    aside(path, ".corrupt")
+}
+
+/// An exclusive lock on a config, released when dropped
+#[derive(Debug)]
+pub struct ConfigLock {
+    file: File,
+}
+
+impl Drop for ConfigLock {
+    fn drop(&mut self) {
+        let _ = FileExt::unlock(&self.file);
+    }
+}
+
+/// Wait for the lock on the config at `path`. The lock is on a separate
+/// `.lock` file, since the config itself is replaced on every save.
+pub fn lock(path: &Path) -> Result<ConfigLock> {
+    let lock_path = aside(path, ".lock");
+    if let Some(dir) = lock_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let file = File::options().create(true).truncate(false).write(true).open(&lock_path)?;
+    file.lock_exclusive().map_err(|e| {
+        EmoError::ConfigError(format!("Could not lock {}: {}", lock_path.display(), e))
+    })?;
+    Ok(ConfigLock { file })
+}
+
+/// Replace `path` with `text` in one step: readers see the old file or the
+/// new one, never a partial write
+pub fn write_atomic(path: &Path, text: &str) -> Result<()> {
+    let tmp = sibling(path, &format!(".{}.tmp", std::process::id()));
+    let written = File::create(&tmp)
+        .and_then(|mut file| {
+            file.write_all(text.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&tmp, path));
+    if written.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    Ok(written?)
+}
+
+/// Replace the config with `text`, keeping the current one as the backup if
+/// it parses. Call with the lock held.
+pub fn store(path: &Path, text: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    if let Ok(current) = fs::read_to_string(path) {
+        if parse(&current, path).is_ok() {
+            let backup = backup_path(path);
+            if let Some(dir) = backup.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            write_atomic(&backup, &current)?;
+        }
+    }
+    write_atomic(path, text)
+}
+
+/// " in <path>", for messages about a file
+fn location(path: Option<&Path>) -> String {
+    path.map(|p| format!(" in {}", p.display())).unwrap_or_default()
+}
+
+/// Deserialize a migrated config, naming the field at fault on failure
+fn from_value(value: Value, path: Option<&Path>) -> Result<EmojiMappings> {
+    if let Value::Object(object) = &value {
+        for key in object.keys() {
+            check_key(key, path)?;
+        }
+        for (key, field) in object {
+            let single = Value::Object(Map::from_iter([(key.clone(), field.clone())]));
+            if let Err(e) = serde_json::from_value::<EmojiMappings>(single) {
+                return Err(EmoError::ConfigError(format!(
+                    "Invalid '{}'{}: {}",
+                    key,
+                    location(path),
+                    e
+                )));
+            }
+        }
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Unknown top-level keys get a suggestion
+fn check_key(key: &str, path: Option<&Path>) -> Result<()> {
+    if FIELDS.iter().any(|(field, _)| *field == key) {
+        return Ok(());
+    }
+    let closest = FIELDS
+        .iter()
+        .map(|(field, _)| (distance(key, field), *field))
+        .min()
+        .filter(|(d, _)| *d <= 2)
+        .map(|(_, field)| format!("; did you mean '{}'?", field))
+        .unwrap_or_default();
+    Err(EmoError::ConfigError(format!(
+        "Unknown config key '{}'{}{}",
+        key,
+        location(path),
+        closest
+    )))
+}
+
+/// Edit distance, for suggestions
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev + usize::from(ca != *cb);
+            prev = row[j + 1];
+            row[j + 1] = substitute.min(prev + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// "mappings.deploy" -> ["mappings", "deploy"]; the first part must be a
+/// known field
+fn split_key(key: &str) -> Result<Vec<&str>> {
+    let parts: Vec<&str> = key.split('.').collect();
+    if parts.iter().any(|p| p.is_empty()) {
+        return Err(EmoError::InvalidInput(format!("'{}' is not a config key", key)));
+    }
+    check_key(parts[0], None)?;
+    Ok(parts)
+}
+
+/// The value at a dotted key like "model" or "mappings.deploy", if set
+pub fn get(config: &EmojiMappings, key: &str) -> Result<Option<Value>> {
+    let parts = split_key(key)?;
+    let mut value = &serde_json::to_value(config)?;
+    for part in parts {
+        match value.get(part) {
+            Some(v) => value = v,
+            None => return Ok(None),
+        }
+    }
+    Ok((!value.is_null()).then(|| value.clone()))
+}
+
+/// Set a dotted key. The value is read as JSON when it parses as JSON
+/// ("3", "true", "[...]"), as a plain string otherwise.
+pub fn set(config: &EmojiMappings, key: &str, raw: &str) -> Result<EmojiMappings> {
+    let parts = split_key(key)?;
+    if parts[0] == "version" {
+        return Err(EmoError::InvalidInput("'version' is managed by emo".to_string()));
+    }
+
+    let memo = parts[0] == "mappings" || (parts[0] == "profiles" && parts.get(2) == Some(&"mappings"));
+    let string = Value::String(raw.to_string());
+    let mut candidates = match serde_json::from_str::<Value>(raw) {
+        Ok(json) if json != string => vec![json, string],
+        _ => vec![string],
+    };
+    // "🎉 🥳 🎊" sets a ranked list
+    if memo && raw.split_whitespace().count() > 1 {
+        candidates.push(raw.split_whitespace().collect::<Vec<_>>().into());
+    }
+
+    let mut first_error = None;
+    for value in candidates {
+        let mut root = serde_json::to_value(config)?;
+        insert(&mut root, &parts, value);
+        let updated = from_value(root, None).and_then(|updated| match memo {
+            true => check_memo(updated, &parts),
+            false => Ok(updated),
+        });
+        match updated {
+            Ok(updated) => return Ok(updated),
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    Err(first_error.expect("at least one candidate"))
+}
+
+/// A memo set by hand must be emoji, though older configs may hold others
+fn check_memo(mut config: EmojiMappings, parts: &[&str]) -> Result<EmojiMappings> {
+    let (profile, term) = match parts {
+        ["mappings", term] => (None, *term),
+        ["profiles", profile, "mappings", term] => (Some(*profile), *term),
+        _ => return Ok(config),
+    };
+    if let Some(memo) = own_memos_mut(&mut config, profile).get_mut(term) {
+        *memo = Memo::new(memo.emojis().to_vec())?;
+    }
+    Ok(config)
+}
+
+fn insert(root: &mut Value, parts: &[&str], value: Value) {
+    let mut node = root;
+    for part in &parts[..parts.len() - 1] {
+        if !node.get(*part).is_some_and(Value::is_object) {
+            node[*part] = Value::Object(Map::new());
+        }
+        node = &mut node[*part];
+    }
+    node[parts[parts.len() - 1]] = value;
+}
+
+/// Remove a dotted key; false if it wasn't set
+pub fn unset(config: &EmojiMappings, key: &str) -> Result<(EmojiMappings, bool)> {
+    let parts = split_key(key)?;
+    if parts[0] == "version" {
+        return Err(EmoError::InvalidInput("'version' is managed by emo".to_string()));
+    }
+
+    let mut root = serde_json::to_value(config)?;
+    let (last, parents) = parts.split_last().expect("keys have a part");
+    let mut node = &mut root;
+    for part in parents {
+        match node.get_mut(*part) {
+            Some(child) => node = child,
+            None => return Ok((from_value(root, None)?, false)),
+        }
+    }
+    let removed = node
+        .as_object_mut()
+        .and_then(|object| object.remove(*last))
+        .is_some_and(|v| !v.is_null());
+    Ok((from_value(root, None)?, removed))
+}
+
+/// Settings from `EMO_*` environment variables, which beat the config file
+/// but lose to command-line flags
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overrides {
+    /// `EMO_MODEL`
+    pub model: Option<String>,
+    /// `EMO_COUNT`
+    pub count: Option<usize>,
+    /// `EMO_FORMAT`
+    pub format: Option<Format>,
+    /// `EMO_STRATEGY`
+    pub strategy: Option<String>,
+    /// `EMO_PROFILE`
+    pub profile: Option<String>,
+}
+
+impl Overrides {
+    pub fn from_env() -> Result<Self> {
+        Self::from_vars(|name| std::env::var(name).ok().filter(|v| !v.is_empty()))
+    }
+
+    /// Overrides from a lookup, for testing without touching the process
+    /// environment
+    pub fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let count = match var("EMO_COUNT") {
+            Some(count) => Some(count.parse().map_err(|_| {
+                EmoError::ConfigError(format!("EMO_COUNT should be a number, got '{}'", count))
+            })?),
+            None => None,
+        };
+        let format = match var("EMO_FORMAT") {
+            Some(format) => Some(format.parse::<Format>().map_err(|e| {
+                EmoError::ConfigError(format!("EMO_FORMAT: {}", e))
+            })?),
+            None => None,
+        };
+        Ok(Overrides {
+            model: var("EMO_MODEL"),
+            count,
+            format,
+            strategy: var("EMO_STRATEGY"),
+            profile: var("EMO_PROFILE"),
+        })
+    }
+
+    /// The environment variable behind a config key, if it is set
+    pub fn source(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "model" if self.model.is_some() => Some("EMO_MODEL"),
+            "count" if self.count.is_some() => Some("EMO_COUNT"),
+            "format" if self.format.is_some() => Some("EMO_FORMAT"),
+            "strategy" if self.strategy.is_some() => Some("EMO_STRATEGY"),
+            "profile" if self.profile.is_some() => Some("EMO_PROFILE"),
+            _ => None,
+        }
+    }
+
+    /// The overridden value of a config key
+    pub fn get(&self, key: &str) -> Option<Value> {
+        match key {
+            "model" => self.model.clone().map(Value::from),
+            "count" => self.count.map(Value::from),
+            "format" => self.format.map(|f| Value::from(f.to_string())),
+            "strategy" => self.strategy.clone().map(Value::from),
+            "profile" => self.profile.clone().map(Value::from),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// Emoji records with their search index
+//
+// An `EmojiDatabase` owns its records and the index built over them, so two
+// databases in one process never see each other's data. The bundled data is
+// parsed per database; share one through `Arc` instead of building it again.
+use crate::error::{EmoError, Result};
+use crate::{MatchKind, EmojiRecord};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Records by the lowercased words of their names and keywords, split the
+/// way search splits them
+pub struct SearchIndex {
+    name_index: BTreeMap<String, Vec<usize>>,
+    keyword_index: BTreeMap<String, Vec<usize>>,
+}
+
+impl SearchIndex {
+    pub fn build(emojis: &[EmojiRecord]) -> Self {
+        let mut name_index = BTreeMap::new();
+        let mut keyword_index = BTreeMap::new();
+
+        for (idx, emoji) in emojis.iter().enumerate() {
+            // Index name words
+            for word in words(&emoji.name) {
+                name_index
+                    .entry(word.to_lowercase())
+                    .or_insert_with(Vec::new)
+                    .push(idx);
+            }
+
+            // Index keyword words
+            for keyword in &emoji.keywords {
+                for word in words(keyword) {
+                    keyword_index
+                        .entry(word.to_lowercase())
+                        .or_insert_with(Vec::new)
+                        .push(idx);
+                }
+            }
+        }
+
+        Self {
+            name_index,
+            keyword_index,
+        }
+    }
+
+    /// Positions of the records with `word` in their name
+    pub fn name_word(&self, word: &str) -> &[usize] {
+        self.name_index.get(&word.to_lowercase()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Positions of the records with `word` in a keyword
+    pub fn keyword_word(&self, word: &str) -> &[usize] {
+        self.keyword_index.get(&word.to_lowercase()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Positions of the records listed under every word, in order
+    pub(crate) fn all_words(&self, words: &[String], list: for<'a> fn(&'a Self, &str) -> &'a [usize]) -> Vec<usize> {
+        let mut lists = words.iter().map(|w| list(self, w));
+        let mut positions = lists.next().map(<[usize]>::to_vec).unwrap_or_default();
+        for other in lists {
+            positions.retain(|p| other.contains(p));
+        }
+        positions.dedup();
+        positions
+    }
+}
+
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty())
+}
+
+pub struct EmojiDatabase {
+    pub(crate) records: Vec<EmojiRecord>,
+    index: SearchIndex,
+}
+
+impl Default for EmojiDatabase {
+    /// The bundled data
+    fn default() -> Self {
+        let records: Vec<EmojiRecord> = serde_json::from_str(include_str!("../emojis.json"))
+            .expect("Failed to parse emoji data");
+        Self::new(records)
+    }
+}
+
+impl EmojiDatabase {
+    /// A database over `records`. Sequences like "U+1F468 U+200D U+1F4BB"
+    /// are left out, as in the bundled data.
+    pub fn new(records: Vec<EmojiRecord>) -> Self {
+        let records: Vec<EmojiRecord> = records
+            .into_iter()
+            .filter(|e| !e.unicode.contains(' '))
+            .collect();
+        let index = SearchIndex::build(&records);
+        EmojiDatabase { records, index }
+    }
+
+    /// Records in the bundled format from a JSON file
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path).map_err(|e| {
+            EmoError::ConfigError(format!("Failed to open emoji data {}: {}", path.display(), e))
+        })?;
+        Ok(Self::new(serde_json::from_reader(file)?))
+    }
+
+    /// A copy with `overlay` laid over the records: a record replaces the one
+    /// with the same `unicode`, others are added
+    pub fn with_overlay(&self, overlay: Vec<EmojiRecord>) -> Self {
+        let mut records = self.records.clone();
+        for record in overlay {
+            match records.iter_mut().find(|e| e.unicode == record.unicode) {
+                Some(existing) => *existing = record,
+                None => records.push(record),
+            }
+        }
+        Self::new(records)
+    }
+
+    pub fn records(&self) -> &[EmojiRecord] {
+        &self.records
+    }
+
+    pub fn index(&self) -> &SearchIndex {
+        &self.index
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn search(&self, search_term: &str, num_results: usize) -> Vec<(char, &EmojiRecord)> {
+        self.search_ranked(search_term, num_results)
+            .into_iter()
+            .map(|(c, emoji, _)| (c, emoji))
+            .collect()
+    }
+
+    pub fn search_ranked(&self, search_term: &str, num_results: usize) -> Vec<(char, &EmojiRecord, MatchKind)> {
+        crate::search_indexed(&self.records, Some(&self.index), search_term, num_results)
+    }
+
+    pub fn lookup(&self, emoji: &str) -> Option<(char, &EmojiRecord)> {
+        crate::lookup(&self.records, emoji)
+    }
+
+    pub fn define(&self, query: &str) -> Option<(char, &EmojiRecord)> {
+        crate::define(&self.records, query)
+    }
+}
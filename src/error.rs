@@ -1,6 +1,10 @@
 use std::error::Error;
 use std::fmt;
 use std::io;
+use std::path::PathBuf;
+
+/// The underlying error of a failure, kept for `Error::source`
+pub type Source = Box<dyn Error + Send + Sync + 'static>;
 
 #[derive(Debug)]
 pub enum EmoError {
@@ -8,6 +12,66 @@ pub enum EmoError {
     Json(serde_json::Error),
     InvalidInput(String),
     ConfigError(String),
+    /// A search, definition or pick that came up empty
+    NoResults(String),
+    /// No model with that id, or none to choose from
+    ModelNotFound(String),
+    /// A server or the model registry couldn't be reached or answered badly
+    Network { message: String, source: Option<Source> },
+    /// Fetching a model file failed
+    Download { message: String, source: Option<Source> },
+    /// The model failed to load or to generate a usable answer
+    Inference { message: String, source: Option<Source> },
+    /// A config file that isn't valid JSON for its schema
+    ConfigParse { path: PathBuf, source: serde_json::Error },
+    /// The network is needed but `EMO_OFFLINE` is set
+    Offline(String),
+}
+
+impl EmoError {
+    pub fn network(message: impl Into<String>, source: impl Into<Source>) -> Self {
+        EmoError::Network { message: message.into(), source: Some(source.into()) }
+    }
+
+    pub fn download(message: impl Into<String>, source: impl Into<Source>) -> Self {
+        EmoError::Download { message: message.into(), source: Some(source.into()) }
+    }
+
+    pub fn inference(message: impl Into<String>, source: impl Into<Source>) -> Self {
+        EmoError::Inference { message: message.into(), source: Some(source.into()) }
+    }
+
+    /// An inference failure with nothing underneath, like an empty reply
+    pub fn no_answer(message: impl Into<String>) -> Self {
+        EmoError::Inference { message: message.into(), source: None }
+    }
+
+    /// The process exit code for this error:
+    ///
+    /// | code | meaning          |
+    /// |------|------------------|
+    /// | 1    | anything else    |
+    /// | 2    | invalid input    |
+    /// | 3    | no results       |
+    /// | 4    | config error     |
+    /// | 5    | model not found  |
+    /// | 6    | network error    |
+    /// | 7    | download failed  |
+    /// | 8    | inference failed |
+    /// | 9    | offline          |
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            EmoError::Io(_) | EmoError::Json(_) => 1,
+            EmoError::InvalidInput(_) => 2,
+            EmoError::NoResults(_) => 3,
+            EmoError::ConfigError(_) | EmoError::ConfigParse { .. } => 4,
+            EmoError::ModelNotFound(_) => 5,
+            EmoError::Network { .. } => 6,
+            EmoError::Download { .. } => 7,
+            EmoError::Inference { .. } => 8,
+            EmoError::Offline(_) => 9,
+        }
+    }
 }
 
 impl fmt::Display for EmoError {
@@ -17,11 +81,51 @@ impl fmt::Display for EmoError {
             EmoError::Json(e) => write!(f, "JSON error: {}", e),
             EmoError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             EmoError::ConfigError(msg) => write!(f, "Configuration error: {}", msg),
+            EmoError::NoResults(msg) => write!(f, "{}", msg),
+            EmoError::ModelNotFound(msg) => write!(f, "Model not found: {}", msg),
+            EmoError::Network { message, .. } => write!(f, "Network error: {}", message),
+            EmoError::Download { message, .. } => write!(f, "Download failed: {}", message),
+            EmoError::Inference { message, .. } => write!(f, "Inference failed: {}", message),
+            EmoError::ConfigParse { path, .. } => write!(f, "Could not parse {}", path.display()),
+            EmoError::Offline(msg) => write!(f, "Offline: {}", msg),
+        }
+    }
+}
+
+impl Error for EmoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EmoError::Io(e) => Some(e),
+            EmoError::Json(e) => Some(e),
+            EmoError::ConfigParse { source, .. } => Some(source),
+            EmoError::Network { source, .. }
+            | EmoError::Download { source, .. }
+            | EmoError::Inference { source, .. } => source.as_deref().map(|e| e as &(dyn Error + 'static)),
+            _ => None,
         }
     }
 }
 
-impl Error for EmoError {}
+/// The error and its causes on one line, "outer: inner: innermost". Io and
+/// Json errors already show their cause.
+pub fn report(error: &EmoError) -> String {
+    let mut line = error.to_string();
+    if matches!(error, EmoError::Io(_) | EmoError::Json(_)) {
+        return line;
+    }
+    let mut cause = error.source();
+    while let Some(e) = cause {
+        line.push_str(": ");
+        line.push_str(&e.to_string());
+        cause = e.source();
+    }
+    line
+}
+
+/// Whether `EMO_OFFLINE` asks to stay off the network
+pub fn offline() -> bool {
+    std::env::var("EMO_OFFLINE").is_ok_and(|v| !v.is_empty() && v != "0")
+}
 
 impl From<io::Error> for EmoError {
     fn from(error: io::Error) -> Self {
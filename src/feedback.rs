@@ -0,0 +1,121 @@
+// Record of which AI suggestions were kept or thrown away
+use crate::config;
+use crate::error::{EmoError, Result};
+use crate::EmojiMappings;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedbackRecord {
+    pub situation: String,
+    pub emoji: String,
+    pub accepted: bool,
+}
+
+/// The most recent AI pick, waiting for `--accept` or `--reject`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub situation: String,
+    pub emojis: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FeedbackLog {
+    #[serde(default)]
+    pub records: Vec<FeedbackRecord>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last: Option<Suggestion>,
+}
+
+impl FeedbackLog {
+    /// `emo/feedback.json` in the config directory, next to the config
+    pub fn default_path() -> Result<std::path::PathBuf> {
+        Ok(EmojiMappings::get_config_dir()?.join("emo").join("feedback.json"))
+    }
+
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::default_path()?)
+    }
+
+    pub fn load_from(path: &std::path::Path) -> Result<Self> {
+        if path.exists() {
+            let file = std::fs::File::open(path)?;
+            serde_json::from_reader(file).map_err(|source| EmoError::ConfigParse {
+                path: path.to_path_buf(),
+                source,
+            })
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::default_path()?)
+    }
+
+    /// Overwrite the log at `path`. Prefer `update` for changes, which keeps
+    /// what other processes saved in the meantime.
+    pub fn save_to(&self, path: &std::path::Path) -> Result<()> {
+        let _lock = config::lock(path)?;
+        self.write_to(path)
+    }
+
+    /// Change the log at `path` the way `EmojiMappings::update` changes a
+    /// config: locked, read fresh, and replaced in one step
+    pub fn update<T>(path: &std::path::Path, change: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let _lock = config::lock(path)?;
+        let mut feedback = Self::load_from(path)?;
+        let output = change(&mut feedback)?;
+        feedback.write_to(path)?;
+        Ok(output)
+    }
+
+    fn write_to(&self, path: &std::path::Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        config::write_atomic(path, &serde_json::to_string_pretty(self)?)
+    }
+
+    /// Remember an AI pick so it can be accepted or rejected later
+    pub fn remember(&mut self, situation: &str, emojis: &[String]) {
+        self.last = Some(Suggestion {
+            situation: situation.to_string(),
+            emojis: emojis.to_vec(),
+        });
+    }
+
+    /// Mark emojis from the last suggestion as kept or not. With no emojis
+    /// given, the whole suggestion is marked.
+    pub fn judge(&mut self, emojis: &[String], accepted: bool) -> Result<Vec<FeedbackRecord>> {
+        let last = self.last.as_ref().ok_or_else(|| {
+            EmoError::InvalidInput("No AI suggestion to give feedback on yet".to_string())
+        })?;
+
+        let chosen = if emojis.is_empty() { &last.emojis } else { emojis };
+        let records: Vec<FeedbackRecord> = chosen
+            .iter()
+            .map(|emoji| FeedbackRecord {
+                situation: last.situation.clone(),
+                emoji: emoji.clone(),
+                accepted,
+            })
+            .collect();
+
+        // A later verdict on the same pick replaces the earlier one
+        self.records.retain(|r| {
+            !records
+                .iter()
+                .any(|n| n.situation == r.situation && n.emoji == r.emoji)
+        });
+        self.records.extend(records.iter().cloned());
+        Ok(records)
+    }
+
+    pub fn accepted(&self) -> impl Iterator<Item = &FeedbackRecord> {
+        self.records.iter().filter(|r| r.accepted)
+    }
+
+    pub fn rejected(&self) -> impl Iterator<Item = &FeedbackRecord> {
+        self.records.iter().filter(|r| !r.accepted)
+    }
+}
@@ -0,0 +1,113 @@
+// Few-shot examples drawn from memos and accepted AI picks, so the model
+// follows the conventions the user has already taught emo
+use crate::feedback::FeedbackLog;
+use crate::EmojiMappings;
+
+/// How many examples go into a prompt by default
+pub const DEFAULT_EXAMPLES: usize = 5;
+
+/// Rejected picks for situations at least this similar are excluded
+pub const REJECT_SIMILARITY: f64 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub situation: String,
+    pub emoji: String,
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|w| w.to_lowercase())
+        .filter(|w| w.len() >= 3)
+        .collect()
+}
+
+fn words_match(a: &str, b: &str) -> bool {
+    // Cheap stemming: "deploy" matches "deploying" and "deployed"
+    a.starts_with(b) || b.starts_with(a)
+}
+
+/// Overlap coefficient between the words of two texts, from 0.0 to 1.0.
+/// Short memo keys like "deploy" score fully against longer situations.
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let a = words(a);
+    let b = words(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let (short, long) = if a.len() <= b.len() { (&a, &b) } else { (&b, &a) };
+    let matched = short
+        .iter()
+        .filter(|w| long.iter().any(|o| words_match(w, o)))
+        .count();
+
+    matched as f64 / short.len() as f64
+}
+
+/// Every example emo knows about: memos first, then accepted AI picks
+pub fn gather(mappings: &EmojiMappings, feedback: &FeedbackLog) -> Vec<Example> {
+    let mut memos: Vec<Example> = mappings
+        .mappings
+        .iter()
+        .map(|(term, memo)| Example {
+            situation: term.clone(),
+            emoji: memo.first().to_string(),
+        })
+        .collect();
+    memos.sort_by(|a, b| a.situation.cmp(&b.situation));
+
+    let accepted = feedback.accepted().map(|r| Example {
+        situation: r.situation.clone(),
+        emoji: r.emoji.clone(),
+    });
+
+    memos.into_iter().chain(accepted).collect()
+}
+
+/// The `limit` examples most similar to the situation, best first.
+/// Examples with nothing in common with the situation are left out.
+pub fn select(situation: &str, examples: Vec<Example>, limit: usize) -> Vec<Example> {
+    let mut scored: Vec<(f64, Example)> = examples
+        .into_iter()
+        .map(|e| (similarity(situation, &e.situation), e))
+        .filter(|(score, _)| *score > 0.0)
+        .collect();
+
+    // Stable sort keeps memos ahead of feedback on ties
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut selected: Vec<Example> = Vec::new();
+    for (_, example) in scored {
+        if selected.len() >= limit {
+            break;
+        }
+        if selected.iter().any(|s| s.situation == example.situation && s.emoji == example.emoji) {
+            continue;
+        }
+        selected.push(example);
+    }
+    selected
+}
+
+/// Emojis the user rejected for situations like this one
+pub fn rejected_for(situation: &str, feedback: &FeedbackLog) -> Vec<String> {
+    let mut rejected: Vec<String> = Vec::new();
+    for record in feedback.rejected() {
+        if similarity(situation, &record.situation) >= REJECT_SIMILARITY
+            && !rejected.contains(&record.emoji)
+        {
+            rejected.push(record.emoji.clone());
+        }
+    }
+    rejected
+}
+
+/// Render examples on one line for the `{examples}` placeholder
+pub fn render(examples: &[Example]) -> String {
+    examples
+        .iter()
+        .map(|e| format!("{} → {}", e.situation, e.emoji))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
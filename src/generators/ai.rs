@@ -0,0 +1,47 @@
+use super::{Candidate, EmojiGenerator, Provenance};
+use crate::ai::AiEmojiSelector;
+use crate::error::Result;
+use std::sync::Arc;
+
+/// Asks the language model, one pick at a time so each can exclude the last
+pub struct AiGenerator {
+    selector: Arc<AiEmojiSelector>,
+}
+
+impl AiGenerator {
+    pub fn new(selector: AiEmojiSelector) -> Self {
+        Self::shared(Arc::new(selector))
+    }
+
+    /// Use a selector (and so a loaded model) that others use too
+    pub fn shared(selector: Arc<AiEmojiSelector>) -> Self {
+        AiGenerator { selector }
+    }
+}
+
+impl EmojiGenerator for AiGenerator {
+    fn generate(&self, input: &str) -> Result<String> {
+        self.selector.select_emoji_llm(input)
+    }
+
+    fn candidates(&self, input: &str, count: usize) -> Result<Vec<Candidate>> {
+        let mut picks: Vec<String> = Vec::new();
+
+        for _ in 0..count {
+            // Pass the already seen emojis to avoid duplicates
+            match self.selector.select_emoji_with_exclusions(input, &picks) {
+                Ok(emoji) => picks.push(emoji),
+                Err(e) if picks.is_empty() => return Err(e),
+                // Keep what we have rather than losing earlier picks
+                Err(_) => break,
+            }
+        }
+
+        // Earlier picks are the model's stronger preferences
+        Ok(picks
+            .into_iter()
+            .enumerate()
+            .map(|(i, emoji)| Candidate::new(emoji, 1.0 / (i + 1) as f64, Provenance::Ai))
+            .collect())
+    }
+}
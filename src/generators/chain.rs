@@ -0,0 +1,96 @@
+// Combinators for building generator pipelines
+use super::{Candidate, EmojiGenerator};
+use crate::error::{self, EmoError, Result};
+use crate::unicode;
+
+fn warn(e: &EmoError) {
+    eprintln!("Warning: {}", error::report(e));
+}
+
+fn first(input: &str, results: Vec<Candidate>) -> Result<String> {
+    results
+        .into_iter()
+        .next()
+        .map(|c| c.emoji)
+        .ok_or_else(|| EmoError::NoResults(format!("No emoji found for '{}'", input)))
+}
+
+/// Runs every generator in order and combines their results, skipping
+/// duplicates, until enough emojis are found. A failing generator is
+/// reported as a warning and skipped.
+pub struct ChainGenerator {
+    generators: Vec<Box<dyn EmojiGenerator>>,
+}
+
+impl ChainGenerator {
+    pub fn new(generators: Vec<Box<dyn EmojiGenerator>>) -> Self {
+        ChainGenerator { generators }
+    }
+}
+
+impl EmojiGenerator for ChainGenerator {
+    fn generate(&self, input: &str) -> Result<String> {
+        first(input, self.candidates(input, 1)?)
+    }
+
+    fn candidates(&self, input: &str, count: usize) -> Result<Vec<Candidate>> {
+        let mut results: Vec<Candidate> = Vec::new();
+
+        for generator in &self.generators {
+            if results.len() >= count {
+                break;
+            }
+
+            // Ask for the full count in case some are duplicates; a memo's
+            // "❤️" and the search's "❤" are the same emoji
+            match generator.candidates(input, count) {
+                Ok(candidates) => {
+                    for candidate in candidates {
+                        let emoji = unicode::strip_selectors(&candidate.emoji);
+                        if results.len() < count && !results.iter().any(|r| unicode::strip_selectors(&r.emoji) == emoji) {
+                            results.push(candidate);
+                        }
+                    }
+                }
+                Err(e) => warn(&e),
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Uses the first generator that comes up with anything. Generators that
+/// fail are reported as warnings; only if all of them fail is the last
+/// error returned instead.
+pub struct FallbackGenerator {
+    generators: Vec<Box<dyn EmojiGenerator>>,
+}
+
+impl FallbackGenerator {
+    pub fn new(generators: Vec<Box<dyn EmojiGenerator>>) -> Self {
+        FallbackGenerator { generators }
+    }
+}
+
+impl EmojiGenerator for FallbackGenerator {
+    fn generate(&self, input: &str) -> Result<String> {
+        first(input, self.candidates(input, 1)?)
+    }
+
+    fn candidates(&self, input: &str, count: usize) -> Result<Vec<Candidate>> {
+        let mut any_succeeded = false;
+
+        for (i, generator) in self.generators.iter().enumerate() {
+            match generator.candidates(input, count) {
+                Ok(candidates) if !candidates.is_empty() => return Ok(candidates),
+                Ok(_) => any_succeeded = true,
+                // Nothing left to fall back to
+                Err(e) if i + 1 == self.generators.len() && !any_succeeded => return Err(e),
+                Err(e) => warn(&e),
+            }
+        }
+
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,94 @@
+use super::{Candidate, EmojiGenerator, Provenance};
+use crate::error::{self, EmoError, Result};
+use crate::unicode;
+
+/// Each model proposes a few picks; at least this many are asked for so a
+/// single-emoji query still gets a meaningful vote
+const MIN_BALLOT: usize = 3;
+
+/// Asks several generators (usually one per model) and lets them vote.
+/// Each member's candidate adds its score to the emoji's tally, so an emoji
+/// that is the second choice of every model can beat one model's favourite.
+pub struct EnsembleGenerator {
+    members: Vec<(String, Box<dyn EmojiGenerator>)>,
+}
+
+impl EnsembleGenerator {
+    pub fn new(members: Vec<(String, Box<dyn EmojiGenerator>)>) -> Self {
+        EnsembleGenerator { members }
+    }
+}
+
+/// An emoji's running tally, with each member's share of it
+struct Tally {
+    emoji: String,
+    score: f64,
+    votes: Vec<String>,
+}
+
+impl EmojiGenerator for EnsembleGenerator {
+    fn generate(&self, input: &str) -> Result<String> {
+        self.candidates(input, 1)?
+            .into_iter()
+            .next()
+            .map(|c| c.emoji)
+            .ok_or_else(|| EmoError::NoResults(format!("No emoji found for '{}'", input)))
+    }
+
+    fn candidates(&self, input: &str, count: usize) -> Result<Vec<Candidate>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut tallies: Vec<Tally> = Vec::new();
+        let mut voters = 0;
+        let mut last_error = None;
+
+        for (name, member) in &self.members {
+            let ballot = match member.candidates(input, count.max(MIN_BALLOT)) {
+                Ok(ballot) => ballot,
+                Err(e) => {
+                    eprintln!("Warning: model '{}' failed: {}", name, error::report(&e));
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            voters += 1;
+
+            for (rank, candidate) in ballot.into_iter().enumerate() {
+                let vote = format!("{} #{} (+{:.2})", name, rank + 1, candidate.score);
+                // "❤" and "❤️" are one emoji; the first spelling seen stays
+                let emoji = unicode::strip_selectors(&candidate.emoji);
+                match tallies.iter_mut().find(|t| unicode::strip_selectors(&t.emoji) == emoji) {
+                    Some(tally) => {
+                        tally.score += candidate.score;
+                        tally.votes.push(vote);
+                    }
+                    None => tallies.push(Tally {
+                        emoji: candidate.emoji,
+                        score: candidate.score,
+                        votes: vec![vote],
+                    }),
+                }
+            }
+        }
+
+        if voters == 0 {
+            return Err(last_error.unwrap_or_else(|| {
+                EmoError::ConfigError("Ensemble has no models".to_string())
+            }));
+        }
+
+        // Stable sort keeps first-seen order on ties, favouring earlier models
+        tallies.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        Ok(tallies
+            .into_iter()
+            .take(count)
+            .map(|t| {
+                Candidate::new(t.emoji, t.score / voters as f64, Provenance::Ai)
+                    .explained(t.votes.join(", "))
+            })
+            .collect())
+    }
+}
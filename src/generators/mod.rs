@@ -1,55 +1,159 @@
 use crate::error::{Result, EmoError};
-use crate::{load_emojis, search};
-use std::collections::HashMap;
+use crate::memos::{Memo, MemoIndex};
+use crate::EmojiDatabase;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+pub mod ai;
+pub mod chain;
+pub mod ensemble;
+pub mod strategy;
+
+pub use ai::AiGenerator;
+pub use chain::{ChainGenerator, FallbackGenerator};
+pub use ensemble::EnsembleGenerator;
+
+/// Where a candidate came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Provenance {
+    Memo,
+    Search,
+    Ai,
+    /// A generator outside this crate that only implements `generate`
+    Custom,
+}
+
+/// One ranked suggestion from a generator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Candidate {
+    pub emoji: String,
+    /// Relevance from 0.0 to 1.0, comparable within one generator's results
+    pub score: f64,
+    pub provenance: Provenance,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+}
+
+impl Candidate {
+    pub fn new(emoji: impl Into<String>, score: f64, provenance: Provenance) -> Self {
+        Candidate {
+            emoji: emoji.into(),
+            score,
+            provenance,
+            explanation: None,
+        }
+    }
+
+    pub fn explained(mut self, explanation: impl Into<String>) -> Self {
+        self.explanation = Some(explanation.into());
+        self
+    }
+}
 
 pub trait EmojiGenerator {
     fn generate(&self, input: &str) -> Result<String>;
+
+    /// Up to `count` ranked candidates for the input, best first. An empty
+    /// list means the generator had nothing to offer, which lets a fallback
+    /// take over.
+    fn candidates(&self, input: &str, count: usize) -> Result<Vec<Candidate>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        self.generate(input)
+            .map(|emoji| vec![Candidate::new(emoji, 1.0, Provenance::Custom)])
+    }
+
+    /// Just the emojis of `candidates`
+    fn generate_n(&self, input: &str, count: usize) -> Result<Vec<String>> {
+        Ok(self
+            .candidates(input, count)?
+            .into_iter()
+            .map(|c| c.emoji)
+            .collect())
+    }
 }
 
-pub struct SearchGenerator;
+#[derive(Default)]
+pub struct SearchGenerator {
+    database: Arc<EmojiDatabase>,
+}
 
 impl SearchGenerator {
+    /// Searches the bundled data
     pub fn new() -> Self {
-        SearchGenerator
+        Self::default()
+    }
+
+    pub fn with_database(database: Arc<EmojiDatabase>) -> Self {
+        SearchGenerator { database }
     }
 }
 
 impl EmojiGenerator for SearchGenerator {
     fn generate(&self, input: &str) -> Result<String> {
-        let emojis = load_emojis()?;
-        let results = search(emojis, input, 1);
-
-        if results.is_empty() {
-            return Err(EmoError::InvalidInput(format!("No emoji found for '{}'", input)));
-        }
+        self.generate_n(input, 1)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| EmoError::NoResults(format!("No emoji found for '{}'", input)))
+    }
 
-        Ok(results[0].0.to_string())
+    fn candidates(&self, input: &str, count: usize) -> Result<Vec<Candidate>> {
+        Ok(self.database.search_ranked(input, count)
+            .into_iter()
+            .map(|(emoji, record, kind)| {
+                Candidate::new(emoji.to_string(), kind.score(), Provenance::Search)
+                    .explained(format!("{}: {}", record.name, kind.describe()))
+            })
+            .collect())
     }
 }
 
-// Minimal MemoGenerator to pass existence test
+/// Saved memos: the query's own, or the pattern that matches it
 pub struct MemoGenerator {
-    mappings: HashMap<String, char>,
+    mappings: MemoIndex,
 }
 
 impl MemoGenerator {
     pub fn new() -> Self {
         MemoGenerator {
-            mappings: HashMap::new(),
+            mappings: MemoIndex::default(),
         }
     }
 
-    pub fn with_mappings(mappings: HashMap<String, char>) -> Self {
-        MemoGenerator { mappings }
+    /// Memos by term or pattern, each a single emoji or a ranked `Memo`
+    pub fn with_mappings<M: Into<Memo>>(mappings: impl IntoIterator<Item = (String, M)>) -> Self {
+        MemoGenerator {
+            mappings: MemoIndex::new(mappings),
+        }
+    }
+
+    pub fn with_index(index: MemoIndex) -> Self {
+        MemoGenerator { mappings: index }
     }
 }
 
 impl EmojiGenerator for MemoGenerator {
     fn generate(&self, input: &str) -> Result<String> {
-        // Now use actual mappings
         match self.mappings.get(input) {
-            Some(emoji) => Ok(emoji.to_string()),
-            None => Err(EmoError::InvalidInput("No memo found".to_string())),
+            Some((_, memo)) => Ok(memo.first().to_string()),
+            None => Err(EmoError::NoResults("No memo found".to_string())),
         }
     }
-}
\ No newline at end of file
+
+    fn candidates(&self, input: &str, count: usize) -> Result<Vec<Candidate>> {
+        Ok(self
+            .mappings
+            .get(input)
+            .into_iter()
+            .flat_map(|(key, memo)| memo.emojis().iter().enumerate().map(move |(rank, emoji)| (key, rank, emoji)))
+            .take(count)
+            .map(|(key, rank, emoji)| {
+                // Later picks in the list rank a little lower
+                Candidate::new(emoji.to_string(), 1.0 / (rank + 1) as f64, Provenance::Memo)
+                    .explained(format!("saved memo for '{}'", key))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,152 @@
+// Generator pipelines described as strings, e.g. in the `strategy` config field
+//
+// `,` chains generators, so their results are combined in order.
+// `|` falls back, so the next generator only runs if the previous one came up
+// empty or failed. `|` binds tighter than `,`:
+//
+//   "memo, search"       memo first, then fill up with search results
+//   "memo, search | ai"  memo first, then search, asking the AI only if
+//                        search finds nothing
+use super::{
+    AiGenerator, ChainGenerator, EmojiGenerator, EnsembleGenerator, FallbackGenerator, MemoGenerator,
+    SearchGenerator,
+};
+use crate::ai::AiEmojiSelector;
+use crate::error::{EmoError, Result};
+use crate::memos::MemoIndex;
+use crate::models::ModelRegistry;
+use crate::{EmojiDatabase, EmojiMappings};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Used for plain queries when the config has no `strategy`
+pub const DEFAULT_STRATEGY: &str = "memo, search";
+
+/// Used with `--ai`: memos are ignored and search covers for AI failures
+pub const AI_STRATEGY: &str = "ai | search";
+
+/// What the generators of a pipeline draw on
+pub struct Sources<'a> {
+    pub config: &'a EmojiMappings,
+    /// Models for `ai` steps, overriding the config; more than one votes
+    pub models: &'a [String],
+    /// Records to search, instead of the bundled ones
+    pub database: Option<Arc<EmojiDatabase>>,
+    /// Used for `ai` steps with a single model, so the model stays loaded
+    pub selector: Option<Arc<AiEmojiSelector>>,
+    /// `selector` has a backend the caller injected, which then answers
+    /// every `ai` step, even for an ensemble
+    pub injected_backend: bool,
+    /// Config file and model directory for selectors the pipeline creates
+    pub paths: Option<(PathBuf, PathBuf)>,
+    /// Layered config files those selectors read settings from
+    pub layers: Vec<PathBuf>,
+    /// The memo profile those selectors learn from
+    pub profile: Option<String>,
+    /// `config`'s memos, already indexed
+    pub memo_index: Option<&'a MemoIndex>,
+}
+
+impl<'a> Sources<'a> {
+    pub fn new(config: &'a EmojiMappings, models: &'a [String]) -> Self {
+        Sources {
+            config,
+            models,
+            database: None,
+            selector: None,
+            injected_backend: false,
+            paths: None,
+            layers: Vec::new(),
+            profile: None,
+            memo_index: None,
+        }
+    }
+
+    fn selector(&self, selector: AiEmojiSelector) -> AiEmojiSelector {
+        let selector = match &self.paths {
+            Some((config_path, model_dir)) => selector.with_paths(config_path.clone(), model_dir.clone()),
+            None => selector,
+        };
+        selector
+            .with_layers(self.layers.clone())
+            .with_profile(self.profile.clone())
+            .with_config(self.config.clone())
+    }
+}
+
+/// One model asks it directly, several models vote as an ensemble that
+/// shares a single registry and model cache
+fn ai(sources: &Sources) -> Box<dyn EmojiGenerator> {
+    let config = sources.config;
+    let models = match (sources.models, config.ensemble.as_slice()) {
+        ([], []) => config.model.iter().cloned().collect(),
+        ([], ensemble) => ensemble.to_vec(),
+        (models, _) => models.to_vec(),
+    };
+
+    if let Some(selector) = sources.selector.as_ref().filter(|_| models.len() <= 1 || sources.injected_backend) {
+        return Box::new(AiGenerator::shared(selector.clone()));
+    }
+    match models.as_slice() {
+        [] => Box::new(AiGenerator::new(sources.selector(AiEmojiSelector::new()))),
+        [model] => Box::new(AiGenerator::new(sources.selector(AiEmojiSelector::with_model(model.clone())))),
+        models => {
+            let registry = Arc::new(ModelRegistry::new());
+            let members = models
+                .iter()
+                .map(|model| {
+                    let selector = sources.selector(AiEmojiSelector::with_registry(Some(model.clone()), registry.clone()));
+                    (model.clone(), Box::new(AiGenerator::new(selector)) as Box<dyn EmojiGenerator>)
+                })
+                .collect();
+            Box::new(EnsembleGenerator::new(members))
+        }
+    }
+}
+
+fn single(name: &str, sources: &Sources) -> Result<Box<dyn EmojiGenerator>> {
+    match name {
+        "memo" => Ok(Box::new(match sources.memo_index {
+            Some(index) => MemoGenerator::with_index(index.clone()),
+            None => MemoGenerator::with_mappings(sources.config.mappings.clone()),
+        })),
+        "search" => Ok(Box::new(match &sources.database {
+            Some(database) => SearchGenerator::with_database(database.clone()),
+            None => SearchGenerator::new(),
+        })),
+        "ai" => Ok(ai(sources)),
+        _ => Err(EmoError::ConfigError(format!(
+            "Unknown generator '{}' in strategy, expected memo, search or ai",
+            name
+        ))),
+    }
+}
+
+/// Build the pipeline for a strategy string. `models` overrides the model or
+/// ensemble from config for any `ai` step; more than one model votes.
+pub fn build(strategy: &str, config: &EmojiMappings, models: &[String]) -> Result<Box<dyn EmojiGenerator>> {
+    build_from(strategy, &Sources::new(config, models))
+}
+
+/// Like `build`, with explicit data, selector and paths
+pub fn build_from(strategy: &str, sources: &Sources) -> Result<Box<dyn EmojiGenerator>> {
+    let mut chain = strategy
+        .split(',')
+        .map(|part| {
+            let mut fallback = part
+                .split('|')
+                .map(|name| single(name.trim(), sources))
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(match fallback.len() {
+                1 => fallback.remove(0),
+                _ => Box::new(FallbackGenerator::new(fallback)) as Box<dyn EmojiGenerator>,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(match chain.len() {
+        1 => chain.remove(0),
+        _ => Box::new(ChainGenerator::new(chain)),
+    })
+}
@@ -0,0 +1,310 @@
+// Gitmoji for commit messages
+//
+// `gitmojis.json` is the official gitmoji table. A message is matched
+// against it in three steps: a conventional-commit type ("fix:", "feat!:")
+// decides on its own; otherwise the words of the message are scored against
+// each gitmoji's description, rarer words counting for more; when that leaves
+// a tie, the model picks among the tied gitmoji, or without a model the first
+// of them is used.
+use crate::ai::AiEmojiSelector;
+use crate::error::{EmoError, Result};
+use crate::unicode;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+#[derive(Debug, Deserialize)]
+pub struct Gitmoji {
+    pub emoji: String,
+    pub code: String,
+    pub description: String,
+    pub name: String,
+    pub semver: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GitmojiTable {
+    gitmojis: Vec<Gitmoji>,
+}
+
+static GITMOJIS: OnceLock<Vec<Gitmoji>> = OnceLock::new();
+
+pub fn load() -> &'static [Gitmoji] {
+    GITMOJIS.get_or_init(|| {
+        let table: GitmojiTable = serde_json::from_str(include_str!("../gitmojis.json"))
+            .expect("Failed to parse gitmoji data");
+        table.gitmojis
+    })
+}
+
+/// The gitmoji with the given code (":bug:" or "bug") or emoji
+pub fn find(code_or_emoji: &str) -> Option<&'static Gitmoji> {
+    let code = code_or_emoji.trim_matches(':');
+    let emoji = unicode::strip_selectors(code_or_emoji);
+    load()
+        .iter()
+        .find(|g| g.code.trim_matches(':') == code || unicode::strip_selectors(&g.emoji) == emoji)
+}
+
+/// Conventional-commit types and their gitmoji
+const COMMIT_TYPES: &[(&str, &str)] = &[
+    ("feat", ":sparkles:"),
+    ("fix", ":bug:"),
+    ("docs", ":memo:"),
+    ("style", ":art:"),
+    ("refactor", ":recycle:"),
+    ("perf", ":zap:"),
+    ("test", ":white_check_mark:"),
+    ("build", ":package:"),
+    ("ci", ":construction_worker:"),
+    ("chore", ":wrench:"),
+    ("revert", ":rewind:"),
+];
+
+/// The gitmoji for a conventional-commit type like "feat"
+pub fn for_type(commit_type: &str) -> Option<&'static Gitmoji> {
+    COMMIT_TYPES
+        .iter()
+        .find(|(t, _)| t.eq_ignore_ascii_case(commit_type))
+        .and_then(|(_, code)| find(code))
+}
+
+/// A conventional-commit subject like "feat(parser)!: drop v1 syntax"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conventional<'a> {
+    pub commit_type: &'a str,
+    pub scope: Option<&'a str>,
+    pub breaking: bool,
+    pub description: &'a str,
+}
+
+pub fn conventional(subject: &str) -> Option<Conventional<'_>> {
+    let (head, description) = subject.split_once(": ")?;
+    let (head, breaking) = match head.strip_suffix('!') {
+        Some(head) => (head, true),
+        None => (head, false),
+    };
+    let (commit_type, scope) = match head.split_once('(') {
+        Some((commit_type, scope)) => (commit_type, Some(scope.strip_suffix(')')?)),
+        None => (head, None),
+    };
+    if commit_type.is_empty() || !commit_type.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some(Conventional {
+        commit_type,
+        scope,
+        breaking,
+        description: description.trim(),
+    })
+}
+
+/// Whether the subject already starts with a gitmoji, as emoji or as code
+pub fn has_prefix(subject: &str) -> bool {
+    split_prefix(subject).is_some()
+}
+
+/// The leading emoji or gitmoji `:code:` of a subject, and the rest of it
+pub fn split_prefix(subject: &str) -> Option<(&str, &str)> {
+    let subject = subject.trim_start();
+    if let Some(emoji) = unicode::graphemes(subject).next().filter(|g| unicode::is_emoji_cluster(g)) {
+        return Some((emoji, subject[emoji.len()..].trim_start()));
+    }
+    let (code, _) = subject.strip_prefix(':')?.split_once(':')?;
+    find(code)?;
+    let end = code.len() + 2;
+    Some((&subject[..end], subject[end..].trim_start()))
+}
+
+const STOPWORDS: &[&str] = &["a", "an", "and", "the", "or", "to", "of", "for", "in", "on", "e", "g", "that", "be", "is", "due", "with", "up", "down"];
+
+/// Lowercased words with plural and tense endings trimmed, so "fixes",
+/// "fixed" and "fix" compare equal
+fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|w| !w.is_empty() && !STOPWORDS.contains(&w.as_str()))
+        .map(|w| stem(&w))
+        .collect()
+}
+
+fn stem(word: &str) -> String {
+    if let Some(base) = word.strip_suffix("ies").filter(|b| b.len() > 2) {
+        return format!("{}y", base);
+    }
+    let suffixes = ["ing", "ed", "es", "s", "e"];
+    let Some(base) = suffixes.iter().find_map(|s| word.strip_suffix(s).filter(|b| b.len() > 2)) else {
+        return word.to_string();
+    };
+
+    // "logging" and "logged" -> "log"
+    match base.as_bytes() {
+        [.., a, b] if a == b && b.is_ascii_alphabetic() && !b"aeiou".contains(b) && word.len() - base.len() > 1 => {
+            base[..base.len() - 1].to_string()
+        }
+        _ => base.to_string(),
+    }
+}
+
+/// Gitmoji ranked by how well their description matches the message. A
+/// matching word counts 1/n when n gitmoji mention it, so "bug" outweighs
+/// "fix". A leading conventional type word ("fix null pointer") adds a half.
+pub fn rank(message: &str) -> Vec<(&'static Gitmoji, f32)> {
+    let table = load();
+    let described: Vec<Vec<String>> = table
+        .iter()
+        .map(|g| terms(&format!("{} {}", g.description, g.name.replace('-', " "))))
+        .collect();
+
+    let mut words = terms(message);
+    words.dedup();
+    let hinted = message.split_whitespace().next().and_then(for_type);
+
+    let mut ranked: Vec<(&Gitmoji, f32)> = table
+        .iter()
+        .zip(&described)
+        .map(|(gitmoji, description)| {
+            let mut score: f32 = words
+                .iter()
+                .filter(|w| description.contains(w))
+                .map(|w| 1.0 / described.iter().filter(|d| d.contains(w)).count() as f32)
+                .sum();
+            if hinted.is_some_and(|h| std::ptr::eq(h, gitmoji)) {
+                score += 0.5;
+            }
+            (gitmoji, score)
+        })
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+}
+
+/// A word shared by five gitmoji
+const MIN_SCORE: f32 = 0.2;
+
+/// The gitmoji for a commit message. Without a clear keyword match the
+/// selector, if given, chooses among the closest gitmoji.
+pub fn suggest(message: &str, selector: Option<&AiEmojiSelector>) -> Result<&'static Gitmoji> {
+    if let Some(subject) = conventional(message) {
+        if subject.breaking {
+            return Ok(find(":boom:").expect("gitmoji table has :boom:"));
+        }
+        if let Some(gitmoji) = for_type(subject.commit_type) {
+            return Ok(gitmoji);
+        }
+    }
+
+    let ranked = rank(message);
+    let best = ranked.first().map_or(0.0, |(_, score)| *score);
+    // Anything within a third of the best is a contender, as long as it
+    // matched more than words most gitmoji share, like "add" or "update"
+    let contenders: Vec<&Gitmoji> = ranked
+        .iter()
+        .take_while(|(_, score)| *score * 3.0 > best * 2.0 && *score >= MIN_SCORE)
+        .map(|(gitmoji, _)| *gitmoji)
+        .collect();
+
+    if let [gitmoji] = contenders.as_slice() {
+        return Ok(gitmoji);
+    }
+
+    if let Some(selector) = selector {
+        let options: Vec<&Gitmoji> = if contenders.is_empty() {
+            load().iter().collect()
+        } else {
+            contenders.clone()
+        };
+        match selector.choose_gitmoji(message, &options) {
+            Ok(gitmoji) => return Ok(gitmoji),
+            Err(e) if !contenders.is_empty() => eprintln!("Warning: {}", crate::error::report(&e)),
+            Err(e) => return Err(e),
+        }
+    }
+
+    contenders.first().copied().ok_or_else(|| {
+        EmoError::NoResults(format!(
+            "No gitmoji matches '{}'; try --ai or a conventional type like 'fix: ...'",
+            message
+        ))
+    })
+}
+
+/// Prefix the subject (first line that isn't empty or a comment) of a
+/// commit message. Returns `None` when there is nothing to do: no subject
+/// yet, or one that already has a gitmoji.
+pub fn prefix_message(message: &str, code: bool, selector: Option<&AiEmojiSelector>) -> Result<Option<String>> {
+    let mut offset = 0;
+    for line in message.split_inclusive('\n') {
+        let subject = line.trim_end();
+        if subject.trim().is_empty() || subject.starts_with('#') {
+            offset += line.len();
+            continue;
+        }
+        if has_prefix(subject) {
+            return Ok(None);
+        }
+
+        let gitmoji = suggest(subject, selector)?;
+        let prefix = if code { &gitmoji.code } else { &gitmoji.emoji };
+        let indent = subject.len() - subject.trim_start().len();
+        let at = offset + indent;
+        return Ok(Some(format!("{}{} {}", &message[..at], prefix, &message[at..])));
+    }
+    Ok(None)
+}
+
+/// Marks hooks written by `install_hook`, so they can be replaced safely
+const HOOK_MARKER: &str = "# Installed by emo gitmoji";
+
+/// A `prepare-commit-msg` hook running this binary with the given
+/// arguments, each quoted for the shell
+pub fn hook_script(emo: &Path, args: &[&str]) -> String {
+    let mut command = format!("{} gitmoji", shell_quote(&emo.display().to_string()));
+    for arg in args {
+        command.push(' ');
+        command.push_str(&shell_quote(arg));
+    }
+    format!("#!/bin/sh\n{}\nexec {} --hook \"$@\"\n", HOOK_MARKER, command)
+}
+
+/// `text` as one single-quoted shell word
+fn shell_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', r"'\''"))
+}
+
+/// Write the hook into the hooks directory of the repository at `repo`. A
+/// hook that emo didn't write is only replaced with `force`.
+pub fn install_hook(repo: &Path, script: &str, force: bool) -> Result<PathBuf> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--git-path", "hooks"])
+        .current_dir(repo)
+        .output()?;
+    if !output.status.success() {
+        return Err(EmoError::InvalidInput(format!(
+            "'{}' is not a git repository",
+            repo.display()
+        )));
+    }
+
+    let hooks = repo.join(String::from_utf8_lossy(&output.stdout).trim());
+    std::fs::create_dir_all(&hooks)?;
+    let path = hooks.join("prepare-commit-msg");
+
+    if let Ok(existing) = std::fs::read_to_string(&path) {
+        if !existing.contains(HOOK_MARKER) && !force {
+            return Err(EmoError::InvalidInput(format!(
+                "{} already exists; use --force to replace it",
+                path.display()
+            )));
+        }
+    }
+
+    std::fs::write(&path, script)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(path)
+}
@@ -1,13 +1,31 @@
 pub mod ai;
+pub mod annotate;
+pub mod backend;
+pub mod batch;
+pub mod changelog;
+pub mod client;
+pub mod config;
+pub mod database;
 pub mod error;
+pub mod feedback;
+pub mod fewshot;
 pub mod generators;
+pub mod gitmoji;
+pub mod memos;
 pub mod models;
+pub mod prompts;
+pub mod unicode;
+
+pub use client::{Emo, EmoBuilder};
+pub use database::{EmojiDatabase, SearchIndex};
 
 use error::{EmoError, Result};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmojiRecord {
     pub keywords: Vec<String>,
     pub unicode: String,
@@ -16,10 +34,30 @@ pub struct EmojiRecord {
     pub definition: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmojiMappings {
-    pub mappings: HashMap<String, char>,
+    #[serde(default)]
+    pub version: u32,  // Schema version, see `config::CONFIG_VERSION`
+    #[serde(default)]
+    pub mappings: IndexMap<String, memos::Memo>,
+    #[serde(default)]
     pub model: Option<String>,  // Optional model in llama/ollama format
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ensemble: Vec<String>,  // Models that vote on AI picks, used instead of `model`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub prompts: HashMap<String, prompts::ModelPrompts>,  // Prompt overrides keyed by model id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,  // Server for ollama:/openai: models
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,  // Generator pipeline for plain queries, e.g. "memo, search | ai"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,  // Default number of results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<batch::Format>,  // Default output format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,  // Memo profile used when none is given
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, config::Profile>,  // Named memo profiles
 }
 
 impl Default for EmojiMappings {
@@ -31,7 +69,7 @@ impl Default for EmojiMappings {
 }
 
 impl EmojiMappings {
-    fn get_config_dir() -> Result<std::path::PathBuf> {
+    pub(crate) fn get_config_dir() -> Result<std::path::PathBuf> {
         // Check for XDG_CONFIG_HOME first (for testing and custom configs)
         if let Ok(xdg_config) = std::env::var("XDG_CONFIG_HOME") {
             return Ok(std::path::PathBuf::from(xdg_config));
@@ -43,42 +81,112 @@ impl EmojiMappings {
         })
     }
 
+    /// Where `load` and `save` keep the config: `emo/config.json` in the
+    /// config directory
+    pub fn default_path() -> Result<std::path::PathBuf> {
+        Ok(Self::get_config_dir()?.join("emo").join("config.json"))
+    }
+
     pub fn load() -> Result<Self> {
-        let config_dir = Self::get_config_dir()?;
-        let path = config_dir.join("emo").join("config.json");
-
-        if path.exists() {
-            let file = std::fs::File::open(path)?;
-            Ok(serde_json::from_reader(file)?)
-        } else {
-            Ok(Self::default())
+        Self::load_from(&Self::default_path()?)
+    }
+
+    /// Load the config at `path`, or the defaults if there is none yet. A
+    /// config that doesn't parse is replaced by its backup, if there is one.
+    pub fn load_from(path: &std::path::Path) -> Result<Self> {
+        match Self::read_from(path) {
+            Err(EmoError::ConfigParse { .. }) => {
+                let _lock = config::lock(path)?;
+                Self::read_or_restore(path)
+            }
+            result => result,
         }
     }
 
+    fn read_from(path: &std::path::Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => config::parse(&text, path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Read the config, restoring the backup if it doesn't parse. The broken
+    /// file is kept next to it. Call with the lock held.
+    fn read_or_restore(path: &std::path::Path) -> Result<Self> {
+        let error = match Self::read_from(path) {
+            Err(e @ EmoError::ConfigParse { .. }) => e,
+            result => return result,
+        };
+        let backup = config::backup_path(path);
+        let Some((text, restored)) = std::fs::read_to_string(&backup)
+            .ok()
+            .and_then(|text| Some((text.clone(), config::parse(&text, &backup).ok()?)))
+        else {
+            return Err(error);
+        };
+
+        let corrupt = config::corrupt_path(path);
+        std::fs::copy(path, &corrupt)?;
+        config::write_atomic(path, &text)?;
+        eprintln!(
+            "Warning: {}; restored the last good config from {} (the broken file is {})",
+            error::report(&error),
+            backup.display(),
+            corrupt.display()
+        );
+        Ok(restored)
+    }
+
+    /// Change the config at `path` without losing changes made by other
+    /// processes: it is locked, read fresh, changed by `change` and saved.
+    /// Returns the saved config and what `change` returned.
+    pub fn update<T>(
+        path: &std::path::Path,
+        change: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<(Self, T)> {
+        let _lock = config::lock(path)?;
+        let mut config = Self::read_or_restore(path)?;
+        let output = change(&mut config)?;
+        config.write_to(path)?;
+        Ok((config, output))
+    }
+
     pub fn save(&self) -> Result<()> {
-        let config_dir = Self::get_config_dir()?;
-        let emo_dir = config_dir.join("emo");
-        std::fs::create_dir_all(&emo_dir)?;
-        let path = emo_dir.join("config.json");
-        let file = std::fs::File::create(path)?;
-        serde_json::to_writer_pretty(file, self)?;
-        Ok(())
+        self.save_to(&Self::default_path()?)
+    }
+
+    /// Overwrite the config at `path` with this one. Prefer `update` for
+    /// changes, which keeps what other processes saved in the meantime.
+    pub fn save_to(&self, path: &std::path::Path) -> Result<()> {
+        let _lock = config::lock(path)?;
+        self.write_to(path)
+    }
+
+    fn write_to(&self, path: &std::path::Path) -> Result<()> {
+        // Saving always writes the current schema
+        let mut value = serde_json::to_value(self)?;
+        value["version"] = config::CONFIG_VERSION.into();
+        config::store(path, &serde_json::to_string_pretty(&value)?)
     }
 }
 
-use std::sync::OnceLock;
+static DATABASE: OnceLock<EmojiDatabase> = OnceLock::new();
 
-static EMOJIS: OnceLock<Vec<EmojiRecord>> = OnceLock::new();
+fn shared_database() -> &'static EmojiDatabase {
+    DATABASE.get_or_init(EmojiDatabase::default)
+}
 
+/// The bundled emoji records, shared by the whole process
+#[deprecated(note = "use an `EmojiDatabase`, or `Emo::emojis`")]
 pub fn load_emojis() -> Result<&'static Vec<EmojiRecord>> {
-    Ok(EMOJIS.get_or_init(|| {
-        let emojis: Vec<EmojiRecord> = serde_json::from_str(include_str!("../emojis.json"))
-            .expect("Failed to parse emoji data");
-        emojis
-            .into_iter()
-            .filter(|e| !e.unicode.contains(' '))
-            .collect()
-    }))
+    Ok(&shared_database().records)
+}
+
+/// The index over the bundled records; `emojis` is ignored
+#[deprecated(note = "use `EmojiDatabase::index`")]
+pub fn get_search_index(_emojis: &[EmojiRecord]) -> &'static SearchIndex {
+    shared_database().index()
 }
 
 pub fn to_char(emoji: &EmojiRecord) -> Result<char> {
@@ -96,54 +204,97 @@ pub fn to_char(emoji: &EmojiRecord) -> Result<char> {
         .ok_or_else(|| EmoError::InvalidInput(format!("Invalid code point: {}", code_point)))
 }
 
-fn is_exact_word_match(text: &str, search: &str) -> bool {
-    text.split(|c: char| !c.is_alphanumeric())
-        .any(|word| word.to_lowercase() == search)
+/// The database record for an emoji, ignoring presentation selectors so
+/// "❤️" finds ❤. Only single-character emoji have records.
+pub fn lookup<'a>(emojis: &'a [EmojiRecord], emoji: &str) -> Option<(char, &'a EmojiRecord)> {
+    let target = unicode::strip_selectors(emoji);
+    emojis.iter().find_map(|record| {
+        let ch = to_char(record).ok()?;
+        target.chars().eq([ch]).then_some((ch, record))
+    })
 }
 
-use std::collections::BTreeMap;
-
-pub struct SearchIndex {
-    name_index: BTreeMap<String, Vec<usize>>,
-    keyword_index: BTreeMap<String, Vec<usize>>,
+/// The emoji a define query is about: the first emoji in it, or else the
+/// best search result for it
+pub fn define<'a>(emojis: &'a [EmojiRecord], query: &str) -> Option<(char, &'a EmojiRecord)> {
+    unicode::emojis(query)
+        .next()
+        .and_then(|emoji| lookup(emojis, emoji))
+        .or_else(|| search(emojis, query, 1).into_iter().next())
 }
 
-impl SearchIndex {
-    pub fn build(emojis: &[EmojiRecord]) -> Self {
-        let mut name_index = BTreeMap::new();
-        let mut keyword_index = BTreeMap::new();
-
-        for (idx, emoji) in emojis.iter().enumerate() {
-            // Index name words
-            for word in emoji.name.split_whitespace() {
-                name_index
-                    .entry(word.to_lowercase())
-                    .or_insert_with(Vec::new)
-                    .push(idx);
-            }
+/// The emoji a memo for `search_term` should hold: `emoji_or_index` itself,
+/// or the search result at that 1-based index
+pub fn memo_emoji(emojis: &[EmojiRecord], emoji_or_index: &str, search_term: &str) -> Result<String> {
+    if search_term.is_empty() || emoji_or_index.is_empty() {
+        return Err(EmoError::InvalidInput(
+            "Cannot save mapping for empty search term or emoji".to_string(),
+        ));
+    }
 
-            // Index keyword words
-            for keyword in &emoji.keywords {
-                for word in keyword.split_whitespace() {
-                    keyword_index
-                        .entry(word.to_lowercase())
-                        .or_insert_with(Vec::new)
-                        .push(idx);
-                }
-            }
+    if let Ok(index) = emoji_or_index.parse::<usize>() {
+        if index == 0 {
+            return Err(EmoError::InvalidInput(
+                "Index must be greater than 0".to_string(),
+            ));
         }
 
-        Self {
-            name_index,
-            keyword_index,
+        let results = search(emojis, search_term, index);
+        if results.len() < index {
+            return Err(EmoError::InvalidInput(format!(
+                "Only {} results found, cannot select index {}",
+                results.len(),
+                index
+            )));
         }
+        return memos::memo_entry(&results[index - 1].0.to_string());
     }
+
+    memos::memo_entry(emoji_or_index)
 }
 
-static SEARCH_INDEX: OnceLock<SearchIndex> = OnceLock::new();
+fn is_exact_word_match(text: &str, search: &str) -> bool {
+    text.split(|c: char| !c.is_alphanumeric())
+        .any(|word| word.to_lowercase() == search)
+}
 
-pub fn get_search_index(emojis: &[EmojiRecord]) -> &'static SearchIndex {
-    SEARCH_INDEX.get_or_init(|| SearchIndex::build(emojis))
+/// How a search result matched, best first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchKind {
+    ExactName,
+    NameWord,
+    KeywordWord,
+    NameContains,
+    KeywordContains,
+    Definition,
+}
+
+impl MatchKind {
+    const ALL: [MatchKind; 6] = [
+        MatchKind::ExactName,
+        MatchKind::NameWord,
+        MatchKind::KeywordWord,
+        MatchKind::NameContains,
+        MatchKind::KeywordContains,
+        MatchKind::Definition,
+    ];
+
+    /// Relevance from 1.0 (exact name) down towards 0.0
+    pub fn score(self) -> f64 {
+        let tiers = Self::ALL.len() as f64;
+        (tiers - self as usize as f64) / tiers
+    }
+
+    pub fn describe(self) -> &'static str {
+        match self {
+            MatchKind::ExactName => "name matches exactly",
+            MatchKind::NameWord => "name contains the words",
+            MatchKind::KeywordWord => "keyword contains the words",
+            MatchKind::NameContains => "name partially matches",
+            MatchKind::KeywordContains => "keyword partially matches",
+            MatchKind::Definition => "definition mentions it",
+        }
+    }
 }
 
 pub fn search<'a>(
@@ -151,6 +302,29 @@ pub fn search<'a>(
     search_term: &str,
     num_results: usize,
 ) -> Vec<(char, &'a EmojiRecord)> {
+    search_ranked(emojis, search_term, num_results)
+        .into_iter()
+        .map(|(c, emoji, _)| (c, emoji))
+        .collect()
+}
+
+/// Like `search`, but also says how each result matched
+pub fn search_ranked<'a>(
+    emojis: &'a [EmojiRecord],
+    search_term: &str,
+    num_results: usize,
+) -> Vec<(char, &'a EmojiRecord, MatchKind)> {
+    search_indexed(emojis, None, search_term, num_results)
+}
+
+/// `search_ranked`, where the word tiers only check the records `index`
+/// lists for every search word
+pub(crate) fn search_indexed<'a>(
+    emojis: &'a [EmojiRecord],
+    index: Option<&SearchIndex>,
+    search_term: &str,
+    num_results: usize,
+) -> Vec<(char, &'a EmojiRecord, MatchKind)> {
     let search_words: Vec<String> = search_term
         .split_whitespace()
         .map(|s| s.to_lowercase())
@@ -182,8 +356,21 @@ pub fn search<'a>(
         }),
     ];
 
-    for predicate in predicates {
-        for emoji in emojis {
+    for (predicate, kind) in predicates.iter().zip(MatchKind::ALL) {
+        let listed = match (index, kind) {
+            (Some(index), MatchKind::NameWord) if !search_words.is_empty() => {
+                Some(index.all_words(&search_words, SearchIndex::name_word))
+            }
+            (Some(index), MatchKind::KeywordWord) if !search_words.is_empty() => {
+                Some(index.all_words(&search_words, SearchIndex::keyword_word))
+            }
+            _ => None,
+        };
+        let candidates: Box<dyn Iterator<Item = &EmojiRecord>> = match listed {
+            Some(positions) => Box::new(positions.into_iter().map(|p| &emojis[p])),
+            None => Box::new(emojis.iter()),
+        };
+        for emoji in candidates {
             if !predicate(emoji) {
                 continue;
             }
@@ -194,7 +381,7 @@ pub fn search<'a>(
             if !seen.insert(c) {
                 continue;
             }
-            results.push((c, emoji));
+            results.push((c, emoji, kind));
             if results.len() >= num_results {
                 return results;
             }
@@ -1,20 +1,30 @@
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use emo::{
-    ai::AiEmojiSelector,
-    error::{EmoError, Result},
-    load_emojis, models::ModelRegistry, search, to_char, EmojiMappings, EmojiRecord,
+    ai::{self, AiEmojiSelector, SentenceOptions},
+    annotate::{self, Density},
+    batch::{Batch, Format, Mode},
+    changelog::{self, Pick},
+    config::{self, Overrides, Scope},
+    error::{self, EmoError, Result},
+    generators::Provenance,
+    gitmoji,
+    memos::{self, Memo, Merge, MemoFormat},
+    models::ModelRegistry, unicode, Emo, EmojiMappings,
 };
-use std::io::Write;
+use std::io::{BufRead, Read, Write};
+use std::sync::OnceLock;
 
 fn try_print(s: &str) {
     let _ = writeln!(std::io::stdout(), "{}", s);
 }
 
+/// The flags before subcommands existed, kept as aliases: `emo -d fire` is
+/// `emo define fire`, `emo --ai fire` is `emo ai fire` and so on
 #[derive(Parser)]
-#[command(author = "redaphid", about = "CLI for finding emojis", version)]
+#[command(author = "redaphid", about = "CLI for finding emojis", version, args_conflicts_with_subcommands = true)]
 struct Cli {
-    #[arg(short, long, default_value_t = 1, help = "number of results to show")]
-    count: usize,
+    #[arg(short, long, help = "number of results to show [default: 1]")]
+    count: Option<usize>,
     #[arg(
         short,
         long,
@@ -25,7 +35,7 @@ struct Cli {
     #[arg(
         short = 'm',
         long = "memo",
-        help = "save a mapping for the search term to a specific emoji or index"
+        help = "save a mapping for the search term to a specific emoji or index; several, space-separated, make a ranked list"
     )]
     save: Option<String>,
     #[arg(
@@ -35,6 +45,10 @@ struct Cli {
         help = "erase the mapping for the specified search term"
     )]
     erase: bool,
+    #[arg(long, default_value = "user", help = "config that -m and -e change: project (.emo.json) or user")]
+    scope: Scope,
+    #[arg(long, global = true, help = "memo profile to use, e.g. work; \"base\" is the top-level memos")]
+    profile: Option<String>,
     #[arg(short = 'n', long, help = "display the number of a given emoji result")]
     number: bool,
     #[arg(short = 'l', long, help = "list all saved mappings")]
@@ -43,205 +57,410 @@ struct Cli {
     random: bool,
     #[arg(long, help = "use AI to select the best emoji for your situation")]
     ai: bool,
-    #[arg(long, help = "specify the AI model to use")]
-    model: Option<String>,
+    #[arg(long, value_delimiter = ',', help = "specify the AI model to use (several comma-separated models vote)")]
+    model: Vec<String>,
     #[arg(long, help = "list available AI models")]
     list_models: bool,
+    #[arg(long, requires = "ai", conflicts_with = "reject", help = "mark the last AI suggestion (or the given emojis) as a good pick")]
+    accept: bool,
+    #[arg(long, requires = "ai", help = "mark the last AI suggestion (or the given emojis) as a bad pick")]
+    reject: bool,
+    #[arg(short = 'v', long, help = "show how each AI pick was chosen")]
+    verbose: bool,
+    #[arg(long, requires = "ai", conflicts_with = "sentence", help = "explain what an emoji or emoji message means")]
+    explain_emoji: bool,
     #[arg(short = 's', long = "sentence", help = "length of each emoji sentence (use with -c for multiple sentences)")]
     sentence: Option<usize>,
+    #[arg(long, requires = "sentence", help = "use each emoji at most once in a sentence")]
+    no_repeat: bool,
+    #[arg(long, requires = "sentence", value_delimiter = ',', help = "emoji that must appear in the sentence")]
+    include: Vec<String>,
+    #[arg(long, requires = "sentence", help = "emoji the sentence must start with")]
+    start_with: Option<String>,
+    #[arg(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        default_missing_value = "-",
+        conflicts_with_all = ["erase", "explain_emoji", "accept", "reject"],
+        help = "answer one query per line of FILE (or stdin), one result line each"
+    )]
+    batch: Option<String>,
+    #[arg(long, conflicts_with_all = ["erase", "explain_emoji"], help = "output format: text, json or tsv [default: text]")]
+    format: Option<Format>,
     #[arg(trailing_var_arg = true)]
     search_terms: Vec<String>,
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-fn print(results: &[(char, &EmojiRecord)], show_number: bool) {
-    for (i, (emoji_char, _)) in results.iter().enumerate() {
-        let prefix = if show_number {
-            format!("{}. ", i + 1)
-        } else {
-            String::new()
-        };
+#[derive(Args, Clone, Default)]
+struct SentenceArgs {
+    #[arg(short = 's', long = "sentence", help = "length of each emoji sentence (use with -c for multiple sentences)")]
+    sentence: Option<usize>,
+    #[arg(long, requires = "sentence", help = "use each emoji at most once in a sentence")]
+    no_repeat: bool,
+    #[arg(long, requires = "sentence", value_delimiter = ',', help = "emoji that must appear in the sentence")]
+    include: Vec<String>,
+    #[arg(long, requires = "sentence", help = "emoji the sentence must start with")]
+    start_with: Option<String>,
+}
 
-        try_print(&format!("{}{}", prefix, emoji_char));
+impl SentenceArgs {
+    fn options(&self) -> Option<SentenceOptions> {
+        Some(SentenceOptions {
+            length: self.sentence?,
+            no_repeat: self.no_repeat,
+            include: self.include.clone(),
+            start_with: self.start_with.clone(),
+        })
     }
 }
 
-fn get_custom_emoji(search_term: &str) -> Result<Option<char>> {
-    let mappings = EmojiMappings::load()?;
-    Ok(mappings.mappings.get(search_term).cloned())
+#[derive(Args, Clone, Default)]
+struct OutputArgs {
+    #[arg(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        default_missing_value = "-",
+        help = "answer one query per line of FILE (or stdin), one result line each"
+    )]
+    batch: Option<String>,
+    #[arg(long, help = "output format: text, json or tsv [default: text]")]
+    format: Option<Format>,
 }
 
-fn handle_search(search_term: &str, num_results: usize, show_number: bool) -> Result<()> {
-    let emojis = load_emojis()?;
+#[derive(Subcommand)]
+enum Command {
+    /// Find emoji by name, keyword or memo
+    Search {
+        #[arg(short, long, help = "number of results to show [default: 1]")]
+        count: Option<usize>,
+        #[arg(short = 'n', long, help = "display the number of a given emoji result")]
+        number: bool,
+        #[command(flatten)]
+        output: OutputArgs,
+        terms: Vec<String>,
+    },
+    /// Show the name and meaning of an emoji
+    Define {
+        #[command(flatten)]
+        output: OutputArgs,
+        terms: Vec<String>,
+    },
+    /// Save, remove and list memos, the emoji a search term always gives
+    Memo {
+        #[command(subcommand)]
+        action: MemoAction,
+    },
+    /// Let the AI pick emoji for a situation
+    Ai {
+        #[arg(short, long, help = "number of emoji or sentences [default: 1]")]
+        count: Option<usize>,
+        #[arg(long, value_delimiter = ',', help = "the AI model for this run (several comma-separated models vote)")]
+        model: Vec<String>,
+        #[arg(short = 'v', long, help = "show how each AI pick was chosen")]
+        verbose: bool,
+        #[command(flatten)]
+        sentence: SentenceArgs,
+        #[arg(long, conflicts_with_all = ["sentence", "batch"], help = "explain what an emoji or emoji message means")]
+        explain: bool,
+        #[arg(long, conflicts_with_all = ["reject", "batch"], help = "mark the last AI suggestion (or the given emojis) as a good pick")]
+        accept: bool,
+        #[arg(long, conflicts_with = "batch", help = "mark the last AI suggestion (or the given emojis) as a bad pick")]
+        reject: bool,
+        #[command(flatten)]
+        output: OutputArgs,
+        situation: Vec<String>,
+    },
+    /// List AI models or choose the default one
+    Models {
+        #[command(subcommand)]
+        action: Option<ModelsAction>,
+    },
+    /// Show, query and change settings
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// A random emoji
+    Random,
+    /// Add fitting emoji to text, read from stdin when no text is given
+    Annotate {
+        #[arg(long, default_value = "medium", help = "how many emoji to add: low, medium or high")]
+        density: Density,
+        #[arg(long, help = "specify the AI model to use")]
+        model: Option<String>,
+        text: Vec<String>,
+    },
+    /// Pick the gitmoji for a commit message
+    Gitmoji {
+        #[arg(long, help = "let the AI decide when the message is ambiguous")]
+        ai: bool,
+        #[arg(long, help = "specify the AI model to use")]
+        model: Option<String>,
+        #[arg(long, help = "use the :code: instead of the emoji")]
+        code: bool,
+        #[arg(long, value_name = "MSG_FILE", conflicts_with = "install", help = "prefix the subject in a commit message file (prepare-commit-msg hook mode)")]
+        hook: Option<std::path::PathBuf>,
+        #[arg(long, help = "install the prepare-commit-msg hook in the current repository")]
+        install: bool,
+        #[arg(long, requires = "install", help = "replace an existing hook")]
+        force: bool,
+        /// The commit message; in hook mode, git's extra hook arguments
+        message: Vec<String>,
+    },
+    /// Write a Markdown changelog from commit subjects, read from stdin or `git log`
+    Changelog {
+        #[arg(long, default_value = "gitmoji", help = "how entries get their emoji: gitmoji, search or ai")]
+        pick: Pick,
+        #[arg(long, help = "let the AI settle ambiguous gitmoji")]
+        ai: bool,
+        #[arg(long, help = "specify the AI model to use")]
+        model: Option<String>,
+        /// Revision range for git log, e.g. v2.4.0..HEAD; without one,
+        /// subjects are read from stdin when it isn't a terminal
+        range: Option<String>,
+    },
+}
 
-    // Check for custom emoji mapping first
-    if let Some(custom_emoji) = get_custom_emoji(search_term)? {
-        if num_results == 1 {
-            // Just return the memo if only 1 result requested
-            if show_number {
-                try_print(&format!("1. {}", custom_emoji));
-            } else {
-                try_print(&format!("{}", custom_emoji));
-            }
-            return Ok(());
-        } else {
-            // Return memo first, then search results (excluding the memo if it appears)
-            if show_number {
-                try_print(&format!("1. {}", custom_emoji));
-            } else {
-                try_print(&format!("{}", custom_emoji));
-            }
+#[derive(Subcommand)]
+enum MemoAction {
+    /// Save a memo: `memo add 🚀 deploy`, `memo add 2 fire` for the second search result,
+    /// or `memo add "🎉 🥳 🎊" party` for a ranked list
+    Add {
+        #[arg(long, default_value = "user", help = "where to save it: project (.emo.json) or user")]
+        scope: Scope,
+        #[command(flatten)]
+        output: OutputArgs,
+        /// The emoji, or the index of a search result; several, space-separated, make a list
+        emoji: String,
+        /// The search term; with --batch, one per line instead
+        terms: Vec<String>,
+    },
+    /// Add an emoji to the end of a memo's list: `memo append 🥳 party`
+    Append {
+        #[arg(long, default_value = "user", help = "where to save it: project (.emo.json) or user")]
+        scope: Scope,
+        /// The emoji, or the index of a search result
+        emoji: String,
+        terms: Vec<String>,
+    },
+    /// Move an emoji within a memo's list: `memo move 🥳 --to 1 party`
+    Move {
+        #[arg(long, default_value = "user", help = "where to save it: project (.emo.json) or user")]
+        scope: Scope,
+        #[arg(long, help = "the new position, starting at 1")]
+        to: usize,
+        emoji: String,
+        terms: Vec<String>,
+    },
+    /// Remove the memo for a search term, or one emoji of it with --emoji
+    Rm {
+        #[arg(long, default_value = "user", help = "where to remove it from: project (.emo.json) or user")]
+        scope: Scope,
+        #[arg(long, help = "remove only this emoji from the memo's list")]
+        emoji: Option<String>,
+        terms: Vec<String>,
+    },
+    /// List saved memos
+    Ls,
+    /// Print memos as JSON, CSV, YAML or an espanso match file
+    Export {
+        #[arg(long, default_value = "json", help = "json, csv, yaml or espanso")]
+        format: MemoFormat,
+        #[arg(long, help = "only the memos of one config: project, user or system [default: all]")]
+        scope: Option<Scope>,
+    },
+    /// Add memos from a JSON, CSV, YAML or espanso file, or another emo config
+    Import {
+        #[arg(long, help = "json, csv, yaml or espanso [default: from the file extension]")]
+        format: Option<MemoFormat>,
+        #[arg(long, default_value = "keep", help = "when a term is already saved: keep it, overwrite it, or report and change nothing")]
+        merge: Merge,
+        #[arg(long, default_value = "user", help = "where to save them: project (.emo.json) or user")]
+        scope: Scope,
+        /// The file, or - for stdin
+        file: String,
+    },
+}
 
-            // Get additional results from search (get extra in case some match the memo)
-            let search_results = search(&emojis, search_term, num_results + 5);
-            let mut printed_count = 1; // We already printed the memo
-            for (emoji_char, _) in search_results.iter() {
-                if *emoji_char != custom_emoji {
-                    printed_count += 1;
-                    if show_number {
-                        try_print(&format!("{}. {}", printed_count, emoji_char));
-                    } else {
-                        try_print(&format!("{}", emoji_char));
-                    }
-                    if printed_count >= num_results {
-                        break;
-                    }
-                }
-            }
-            return Ok(());
-        }
-    }
+#[derive(Subcommand)]
+enum ModelsAction {
+    /// List available AI models (the default)
+    Ls,
+    /// Save the default model; several comma-separated models vote
+    Use {
+        #[arg(required = true, value_delimiter = ',')]
+        models: Vec<String>,
+    },
+}
 
-    // No memo, just regular search
-    let results = search(&emojis, search_term, num_results);
-    print(&results, show_number);
-    Ok(())
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Print where the config file is
+    Path,
+    /// Print the config
+    Show,
+    /// Print a setting, e.g. `model` or `mappings.deploy`, as it applies to this run
+    Get { key: String },
+    /// Change a setting; the value is read as JSON when it parses as JSON
+    Set { key: String, value: String },
+    /// Remove a setting, going back to its default
+    Unset { key: String },
+    /// Open the config in $VISUAL or $EDITOR, checking it before it is saved
+    Edit,
 }
 
-// Function to handle the define mode
-fn handle_define(search_term: &str) -> Result<()> {
-    if search_term.is_empty() {
-        return Ok(());
-    }
+impl Cli {
+    /// The subcommand the old-style flags stand for, checked in their old
+    /// order of priority
+    fn legacy(self) -> Command {
+        let output = OutputArgs { batch: self.batch, format: self.format };
+        let terms = self.search_terms;
 
-    let emojis = load_emojis()?;
-    let first_char = search_term
-        .chars()
-        .next()
-        .ok_or_else(|| EmoError::InvalidInput("Empty search term".to_string()))?;
+        match () {
+            _ if self.list_models => Command::Models { action: Some(ModelsAction::Ls) },
+            _ if self.list_mappings => Command::Memo { action: MemoAction::Ls },
+            _ if self.random => Command::Random,
+            _ if self.ai || !self.model.is_empty() => Command::Ai {
+                count: self.count,
+                model: self.model,
+                verbose: self.verbose,
+                sentence: SentenceArgs {
+                    sentence: self.sentence,
+                    no_repeat: self.no_repeat,
+                    include: self.include,
+                    start_with: self.start_with,
+                },
+                explain: self.explain_emoji,
+                accept: self.accept,
+                reject: self.reject,
+                output,
+                situation: terms,
+            },
+            _ if self.erase => Command::Memo { action: MemoAction::Rm { scope: self.scope, emoji: None, terms } },
+            _ => match self.save {
+                Some(emoji) => Command::Memo { action: MemoAction::Add { scope: self.scope, output, emoji, terms } },
+                None if self.define => Command::Define { output, terms },
+                None => Command::Search { count: self.count, number: self.number, output, terms },
+            },
+        }
+    }
+}
 
-    // Try direct lookup first
-    for emoji in emojis {
-        let emoji_char = match to_char(emoji) {
-            Ok(ch) => ch,
-            Err(_) => continue,
+fn print(results: &[String], show_number: bool) {
+    for (i, emoji) in results.iter().enumerate() {
+        let prefix = if show_number {
+            format!("{}. ", i + 1)
+        } else {
+            String::new()
         };
-        if emoji_char != first_char {
-            continue;
-        }
-        let name = &emoji.name;
-        let description = emoji.definition.as_deref().unwrap_or("");
-        try_print(&format!("{} - {} {}", emoji_char, name, description));
-        return Ok(());
+
+        try_print(&format!("{}{}", prefix, emoji));
     }
+}
 
-    // If exact emoji not found, fall back to search
-    let results = search(&emojis, search_term, 1);
+fn handle_search(emo: &Emo, search_term: &str, num_results: usize, show_number: bool) -> Result<()> {
+    // Memo first, then search results (excluding the memo if it appears), unless configured otherwise
+    let results = emo.search(search_term, num_results)?;
     if results.is_empty() {
-        return Ok(());
+        return Err(no_results(search_term));
     }
-
-    let (emoji_char, emoji) = &results[0];
-    let name = &emoji.name;
-    let description = emoji.definition.as_deref().unwrap_or("");
-    try_print(&format!("{} - {} {}", emoji_char, name, description));
+    print(&results, show_number);
     Ok(())
 }
 
-fn is_number(s: &str) -> bool {
-    s.parse::<usize>().is_ok()
+/// Scripts tell "nothing found" apart from failures by the exit code
+fn no_results(query: &str) -> EmoError {
+    EmoError::NoResults(format!("No emoji found for '{}'", query))
 }
 
-fn handle_save(emoji_to_save: &str, search_term: &str) -> Result<()> {
-    if search_term.is_empty() || emoji_to_save.is_empty() {
-        return Err(EmoError::InvalidInput(
-            "Cannot save mapping for empty search term or emoji".to_string(),
-        ));
+// Function to handle the define mode
+fn handle_define(emo: &Emo, search_term: &str) -> Result<()> {
+    if search_term.is_empty() {
+        return Ok(());
     }
+    if !print_definition(emo, search_term) {
+        return Err(no_results(search_term));
+    }
+    Ok(())
+}
 
-    let mut mappings = EmojiMappings::load()?;
-
-    if is_number(emoji_to_save) {
-        let index = emoji_to_save
-            .parse::<usize>()
-            .map_err(|_| EmoError::InvalidInput(format!("Invalid index: {}", emoji_to_save)))?;
+/// Print the entry for the emoji a query is about, if there is one
+fn print_definition(emo: &Emo, query: &str) -> bool {
+    // Direct lookup first, ignoring presentation selectors like in "❤️", then search
+    let Some((emoji_char, emoji)) = emo.define(query) else {
+        return false;
+    };
+    let description = emoji.definition.as_deref().unwrap_or("");
+    try_print(&format!("{} - {} {}", emoji_char, emoji.name, description));
+    true
+}
 
-        if index == 0 {
-            return Err(EmoError::InvalidInput(
-                "Index must be greater than 0".to_string(),
-            ));
-        }
+fn handle_save(emo: &mut Emo, scope: Scope, emoji_to_save: &str, search_term: &str) -> Result<()> {
+    let memo = emo.set_memo_in(scope, search_term, emoji_to_save)?;
+    show_memo(emo, scope, search_term, &memo);
+    Ok(())
+}
 
-        let emojis = load_emojis()?;
-        let results = search(&emojis, search_term, index.max(1));
+/// Confirm a saved memo, best emoji first
+fn show_memo(emo: &Emo, scope: Scope, search_term: &str, memo: &Memo) {
+    try_print(&format!("{} ➡ {} ✅", search_term, memo));
+    note_shadowed(emo, scope, search_term);
+}
 
-        if results.len() < index {
-            return Err(EmoError::InvalidInput(format!(
-                "Only {} results found, cannot select index {}",
-                results.len(),
-                index
-            )));
+fn handle_erase(emo: &mut Emo, scope: Scope, search_term: &str) -> Result<()> {
+    if emo.remove_memo_in(scope, search_term)? {
+        try_print(&format!("Mapping for '{}' erased ✅", search_term));
+        note_shadowed(emo, scope, search_term);
+    } else {
+        try_print(&format!("No mapping found for '{}'", search_term));
+        if let Some((other_scope, other_profile)) = emo.memo_origin(search_term) {
+            let mut flags = Vec::new();
+            if other_scope != scope {
+                flags.push(format!("--scope {}", other_scope));
+            }
+            if other_profile != emo.profile() {
+                flags.push(format!("--profile {}", other_profile.unwrap_or(config::BASE_PROFILE)));
+            }
+            eprintln!(
+                "Note: '{}' comes from the {} memos; use {} to remove it",
+                search_term,
+                origin_label(other_scope, other_profile),
+                flags.join(" ")
+            );
         }
-
-        let (emoji_char, _) = results[index - 1];
-
-        mappings
-            .mappings
-            .insert(search_term.to_string(), emoji_char);
-        mappings.save()?;
-
-        try_print(&format!("{} ➡ {} ✅", search_term, emoji_char));
-        return Ok(());
     }
-
-    // Extract just the first character as the emoji
-    let emoji_char = emoji_to_save
-        .chars()
-        .next()
-        .ok_or_else(|| EmoError::InvalidInput("Empty emoji".to_string()))?;
-
-    mappings
-        .mappings
-        .insert(search_term.to_string(), emoji_char);
-    mappings.save()?;
-
-    try_print(&format!("{} ➡ {} ✅", search_term, emoji_char));
     Ok(())
 }
 
-fn handle_erase(search_term: &str) -> Result<()> {
-    if search_term.is_empty() {
-        return Err(EmoError::InvalidInput(
-            "Cannot erase mapping for empty search term".to_string(),
-        ));
+/// Say so when a memo in a higher layer hides the one just changed
+fn note_shadowed(emo: &Emo, scope: Scope, search_term: &str) {
+    let Some((other, profile)) = emo.memo_origin(search_term) else {
+        return;
+    };
+    if other > scope && profile == emo.profile() {
+        let path = emo.layer(other).map(|l| l.path.display().to_string()).unwrap_or_default();
+        eprintln!("Note: the {} memo in {} takes precedence for '{}'", other, path, search_term);
     }
+}
 
-    let mut mappings = EmojiMappings::load()?;
-    if mappings.mappings.remove(search_term).is_none() {
-        try_print(&format!("No mapping found for '{}'", search_term));
-        return Ok(());
+/// "user", or "user, work" for a profile
+fn origin_label(scope: Scope, profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("{}, {}", scope, profile),
+        None => scope.to_string(),
     }
-
-    mappings.save()?;
-    try_print(&format!("Mapping for '{}' erased ✅", search_term));
-    Ok(())
 }
 
 fn main() {
     let result = run();
 
     if let Err(e) = result {
-        eprintln!("Error: {}", e);
-        std::process::exit(1);
+        eprintln!("Error: {}", error::report(&e));
+        std::process::exit(e.exit_code());
     }
 }
 
@@ -273,131 +492,555 @@ fn handle_list_models() -> Result<()> {
     Ok(())
 }
 
-fn handle_list_mappings() -> Result<()> {
-    let mappings = EmojiMappings::load()?;
-    if mappings.mappings.is_empty() {
+fn handle_list_mappings(emo: &Emo) -> Result<()> {
+    let memos = emo.memos();
+    if memos.is_empty() {
         try_print("No saved mappings.");
         return Ok(());
     }
 
-    try_print("Saved mappings:");
-    let mut entries: Vec<_> = mappings.mappings.iter().collect();
-    entries.sort_by_key(|e| e.0);
-    for (term, emoji) in entries {
-        try_print(&format!("  {} → {}", term, emoji));
+    if let Some(profile) = emo.profile().filter(|p| !emo.config().profiles.contains_key(*p)) {
+        eprintln!("Note: profile '{}' has no memos of its own yet; saving one creates it", profile);
+    }
+    match emo.profile() {
+        Some(profile) => try_print(&format!("Saved mappings (profile {}):", profile)),
+        None => try_print("Saved mappings:"),
+    }
+    for (term, emoji) in memos {
+        // With a profile active, base memos are labeled too
+        let origin = match emo.memo_origin(term) {
+            Some((scope, None)) if emo.profile().is_some() => origin_label(scope, Some(config::BASE_PROFILE)),
+            Some((scope, profile)) => origin_label(scope, profile),
+            None => String::new(),
+        };
+        try_print(&format!("  {} → {}  ({})", term, emoji, origin));
     }
     Ok(())
 }
 
-fn handle_random() -> Result<()> {
-    use std::collections::hash_map::RandomState;
-    use std::hash::{BuildHasher, Hash, Hasher};
+fn handle_memo_export(emo: &Emo, format: MemoFormat, scope: Option<Scope>) -> Result<()> {
+    let memos: Vec<(&str, &Memo)> = match scope {
+        None => emo.memos(),
+        Some(scope) => emo
+            .layer(scope)
+            .and_then(|l| config::own_memos(&l.config, emo.profile()))
+            .map(|memos| memos.iter().map(|(t, m)| (t.as_str(), m)).collect())
+            .unwrap_or_default(),
+    };
+    let exported = memos::export(&memos, format)?;
+    let _ = write!(std::io::stdout(), "{}", exported);
 
-    let emojis = load_emojis()?;
-    if emojis.is_empty() {
-        return Err(EmoError::InvalidInput("No emojis available".to_string()));
+    // An espanso match expands to one emoji
+    if format == MemoFormat::Espanso {
+        let cut: Vec<&str> = memos.iter().filter(|(_, m)| m.len() > 1).map(|(t, _)| *t).collect();
+        if !cut.is_empty() {
+            eprintln!("Warning: espanso only gets the top emoji of the lists for: {}", cut.join(", "));
+        }
     }
+    Ok(())
+}
 
-    // Use system time as a simple random seed
-    let seed = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_nanos();
+fn handle_memo_import(emo: &mut Emo, file: &str, format: Option<MemoFormat>, merge: Merge, scope: Scope) -> Result<()> {
+    let text = if file == "-" {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        std::fs::read_to_string(file)
+            .map_err(|e| EmoError::InvalidInput(format!("Could not read '{}': {}", file, e)))?
+    };
+    let format = format
+        .or_else(|| MemoFormat::from_path(std::path::Path::new(file)))
+        .ok_or_else(|| {
+            EmoError::InvalidInput(format!("Can't tell the format of '{}'; pass --format", file))
+        })?;
 
-    let mut hasher = RandomState::new().build_hasher();
-    seed.hash(&mut hasher);
-    let index = (hasher.finish() as usize) % emojis.len();
+    let summary = emo.import_memos(scope, memos::parse(&text, format)?, merge)?;
+    for (term, reason) in &summary.skipped {
+        eprintln!("Warning: skipped '{}': {}", term, reason);
+    }
+    for conflict in &summary.replaced {
+        try_print(&format!("  {}: {} → {} (overwritten)", conflict.term, conflict.saved, conflict.imported));
+    }
+    for conflict in &summary.conflicts {
+        try_print(&format!("  {}: {} saved, {} in the file", conflict.term, conflict.saved, conflict.imported));
+    }
 
-    let emoji = &emojis[index];
-    let emoji_char = to_char(emoji)?;
+    let counts = format!(
+        "{} added, {} replaced, {} conflicting, {} unchanged",
+        summary.added.len(),
+        summary.replaced.len(),
+        summary.conflicts.len(),
+        summary.unchanged
+    );
+    if merge == Merge::Report {
+        for (term, emoji) in &summary.added {
+            try_print(&format!("  {} → {} (new)", term, emoji));
+        }
+        try_print(&format!("Nothing changed; importing would give: {}", counts));
+    } else {
+        try_print(&format!("Imported into the {} config: {} ✅", scope, counts));
+    }
+    Ok(())
+}
+
+fn handle_random(emo: &Emo) -> Result<()> {
+    let (emoji_char, emoji) = emo.random()?;
     try_print(&format!("{} - {}", emoji_char, emoji.name));
     Ok(())
 }
 
-fn handle_ai_emoji(situation: &str, model: Option<String>, count: usize) -> Result<()> {
-    // Check config for default model if none specified
-    let model_to_use = if let Some(model_name) = model {
-        Some(model_name)
+fn handle_ai_emoji(emo: &Emo, situation: &str, count: usize, verbose: bool) -> Result<()> {
+    // Falls back to search with a warning if the model can't be used
+    let candidates = emo.ai(situation, count)?;
+    if verbose {
+        for candidate in &candidates {
+            eprintln!("{} {:.2} {}", candidate.emoji, candidate.score,
+                candidate.explanation.as_deref().unwrap_or(""));
+        }
+    }
+
+    if candidates.is_empty() {
+        return Err(no_results(situation));
+    }
+    let picks: Vec<String> = candidates.iter().map(|c| c.emoji.clone()).collect();
+    print(&picks, false);
+
+    // Keep the model's picks around so they can be accepted or rejected
+    // later; search results standing in for it are not AI suggestions
+    let ai_picks: Vec<String> = candidates
+        .into_iter()
+        .filter(|c| c.provenance == Provenance::Ai)
+        .map(|c| c.emoji)
+        .collect();
+    if ai_picks.is_empty() {
+        return Ok(());
+    }
+    emo.remember(situation, &ai_picks)
+}
+
+fn handle_feedback(emo: &Emo, emojis: &[String], accepted: bool) -> Result<()> {
+    let records = emo.judge(emojis, accepted)?;
+
+    let verdict = if accepted { "accepted ✅" } else { "rejected ❌" };
+    for record in records {
+        try_print(&format!("{} ➡ {} {}", record.situation, record.emoji, verdict));
+    }
+    Ok(())
+}
+
+fn handle_annotate(emo: &Emo, text: &[String], density: Density) -> Result<()> {
+    // Arguments are one line of text; stdin is a whole document
+    let document = if text.is_empty() {
+        let mut document = String::new();
+        std::io::stdin().read_to_string(&mut document)?;
+        document
     } else {
-        EmojiMappings::load().ok().and_then(|config| config.model)
+        format!("{}\n", text.join(" "))
     };
 
-    let ai_selector = if let Some(model_name) = model_to_use {
-        AiEmojiSelector::with_model(model_name)
+    // One selector for the whole document, so the model is loaded once
+    let annotated = annotate::annotate(&document, density, emo.selector())?;
+    let _ = write!(std::io::stdout(), "{}", annotated);
+    Ok(())
+}
+
+/// `--profile`, set once before any client is built
+static PROFILE: OnceLock<Option<String>> = OnceLock::new();
+
+/// The client for the given models, or the model from `EMO_MODEL` or
+/// config, with the system and project configs layered around the user's
+fn client(models: Vec<String>) -> Result<Emo> {
+    let cwd = std::env::current_dir()?;
+    let mut builder = Emo::builder()
+        .models(models)
+        .overrides(Overrides::from_env()?)
+        .system_config(config::system_path())
+        .project_config(config::project_path(&cwd));
+    if let Some(profile) = PROFILE.get().cloned().flatten() {
+        builder = builder.profile(profile);
+    }
+    builder.build()
+}
+
+fn handle_gitmoji(message: &str, code: bool, selector: Option<&AiEmojiSelector>) -> Result<()> {
+    if message.trim().is_empty() {
+        return Err(EmoError::InvalidInput("Please provide a commit message".to_string()));
+    }
+
+    let gitmoji = gitmoji::suggest(message, selector)?;
+    if code {
+        try_print(&gitmoji.code);
     } else {
-        AiEmojiSelector::new()
-    };
+        try_print(&format!("{} {}", gitmoji.emoji, gitmoji.code));
+    }
+    Ok(())
+}
 
-    // Generate multiple different emojis
-    let mut seen_emojis = Vec::new();
+fn handle_gitmoji_hook(file: &std::path::Path, source: Option<&str>, code: bool, selector: Option<&AiEmojiSelector>) -> Result<()> {
+    // Merge and squash messages are written by git
+    if matches!(source, Some("merge" | "squash")) {
+        return Ok(());
+    }
+
+    let message = std::fs::read_to_string(file)?;
+    match gitmoji::prefix_message(&message, code, selector) {
+        Ok(Some(prefixed)) => std::fs::write(file, prefixed)?,
+        Ok(None) => {}
+        // Never block a commit over a missing emoji
+        Err(e) => eprintln!("Warning: {}", error::report(&e)),
+    }
+    Ok(())
+}
 
-    for _ in 0..count {
-        // Pass the already seen emojis to avoid duplicates
-        let emoji = ai_selector.select_emoji_with_exclusions(situation, &seen_emojis)?;
-        try_print(&emoji);
-        seen_emojis.push(emoji);
+fn handle_gitmoji_install(ai: bool, model: Option<&str>, code: bool, force: bool) -> Result<()> {
+    let mut args = Vec::new();
+    if ai {
+        args.push("--ai");
+    }
+    if let Some(model) = model {
+        args.extend(["--model", model]);
+    }
+    if code {
+        args.push("--code");
     }
 
+    let emo = std::env::current_exe()?;
+    let script = gitmoji::hook_script(&emo, &args);
+    let path = gitmoji::install_hook(&std::env::current_dir()?, &script, force)?;
+    try_print(&format!("Installed {} ✅", path.display()));
     Ok(())
 }
 
-fn handle_ai_sentence(situation: &str, model: Option<String>, length: usize) -> Result<()> {
-    // Check config for default model if none specified
-    let model_to_use = if let Some(model_name) = model {
-        Some(model_name)
-    } else {
-        EmojiMappings::load().ok().and_then(|config| config.model)
-    };
+fn handle_changelog(emo: &Emo, range: Option<&str>, pick: Pick, ai: bool) -> Result<()> {
+    use std::io::IsTerminal;
 
-    let ai_selector = if let Some(model_name) = model_to_use {
-        AiEmojiSelector::with_model(model_name)
+    let subjects = if range.is_none() && !std::io::stdin().is_terminal() {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        changelog::subjects(&text)
     } else {
-        AiEmojiSelector::new()
+        changelog::git_log(range)?
     };
 
-    // Generate an emoji sentence describing the situation
-    let sentence = ai_selector.generate_emoji_sentence(situation, length)?;
-    try_print(&sentence);
+    // Memos named after a commit type override its section emoji
+    let selector = (ai || pick == Pick::Ai).then(|| emo.selector());
+    let rendered = changelog::render(&subjects, pick, emo.memo_index(), emo.database(), selector)?;
+    let _ = write!(std::io::stdout(), "{}", rendered);
     Ok(())
 }
 
-fn run() -> Result<()> {
-    let cmd = Cli::parse();
-
-    // Early return for simple info commands
-    if cmd.list_models { return handle_list_models() }
-    if cmd.list_mappings { return handle_list_mappings() }
-    if cmd.random { return handle_random() }
-
-    // Save model to config once if specified
-    if let Some(ref model_name) = cmd.model {
-        let mut mappings = EmojiMappings::load()?;
-        mappings.model = Some(model_name.clone());
-        mappings.save()?;
-    }
+/// Queries from FILE, or stdin for "-"
+fn queries(input: &str) -> Result<Box<dyn BufRead>> {
+    Ok(match input {
+        "-" => Box::new(std::io::stdin().lock()),
+        path => Box::new(std::io::BufReader::new(std::fs::File::open(path)?)),
+    })
+}
 
-    // Require search terms for all remaining operations
-    if cmd.search_terms.is_empty() {
+/// The search terms as one query; every mode but batch needs one
+fn required(terms: &[String]) -> Result<String> {
+    if terms.is_empty() {
         return Err(EmoError::InvalidInput(
             "Please provide a search term or situation".to_string(),
         ));
     }
+    Ok(terms.join(" "))
+}
+
+/// Answer a batch, or the query itself: structured formats answer it like a
+/// one-line batch, plain text goes to `plain`
+fn answer(
+    emo: Emo,
+    output: &OutputArgs,
+    terms: &[String],
+    mode: Mode,
+    plain: impl FnOnce(Emo, &str) -> Result<()>,
+) -> Result<()> {
+    let format = output.format.unwrap_or(emo.format());
+    if let Some(input) = &output.batch {
+        return handle_batch(emo, queries(input)?, mode, format);
+    }
+    let query = required(terms)?;
+    if format != Format::Text {
+        return handle_batch(emo, std::io::Cursor::new(query), mode, format);
+    }
+    plain(emo, &query)
+}
+
+fn handle_batch(emo: Emo, queries: impl BufRead, mode: Mode, format: Format) -> Result<()> {
+    // One index, pipeline and model for every line
+    let mut batch = Batch::new(mode, emo)?;
+    let mut stdout = std::io::stdout().lock();
+
+    for query in queries.lines() {
+        let record = batch.run(&query?);
+        if let Some(error) = &record.error {
+            eprintln!("Warning: '{}': {}", record.query, error);
+        }
+        // Stop quietly when the reader goes away, as with `| head`
+        if writeln!(stdout, "{}", record.render(format)).and_then(|_| stdout.flush()).is_err() {
+            break;
+        }
+    }
+    batch.finish()
+}
+
+fn handle_explain(emo: &Emo, message: &str) -> Result<()> {
+    // A single emoji gets its dictionary entry first
+    let emojis: Vec<&str> = unicode::emojis(message).collect();
+    if emojis.len() == 1 {
+        print_definition(emo, emojis[0]);
+    }
+
+    match emo.explain(message) {
+        Ok(explanation) => try_print(&explanation),
+        Err(EmoError::InvalidInput(msg)) => return Err(EmoError::InvalidInput(msg)),
+        // Without a model, the dictionary is the best we can do
+        Err(e) => {
+            eprintln!("Warning: {}", error::report(&e));
+            if emojis.len() > 1 {
+                try_print(&ai::definitions(emo.database(), &emojis));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn handle_ai_sentence(emo: &Emo, situation: &str, options: &SentenceOptions) -> Result<()> {
+    // Generate an emoji sentence describing the situation
+    let sentence = emo.sentence(situation, options)?;
+    try_print(&sentence);
+    Ok(())
+}
 
-    let search_term = &cmd.search_terms.join(" ");
+/// Save the default model; several models become the ensemble
+fn handle_use_models(models: &[String]) -> Result<()> {
+    if models.is_empty() {
+        return Ok(());
+    }
+    client(Vec::new())?.update_config(|config| {
+        match models {
+            [model_name] => {
+                config.model = Some(model_name.clone());
+                config.ensemble.clear();
+            }
+            models => config.ensemble = models.to_vec(),
+        }
+        Ok(())
+    })
+}
 
-    match () {
-        _ if cmd.ai || cmd.model.is_some() => {
-            match cmd.sentence {
-                Some(len) => (0..cmd.count).try_for_each(|_|
-                    handle_ai_sentence(search_term, cmd.model.clone(), len))?,
-                None => handle_ai_emoji(search_term, cmd.model, cmd.count)?,
+fn handle_config(mut emo: Emo, action: ConfigAction) -> Result<()> {
+    match action {
+        ConfigAction::Path => try_print(&emo.config_path().display().to_string()),
+        ConfigAction::Show => try_print(&serde_json::to_string_pretty(emo.config())?),
+        ConfigAction::Get { key } => {
+            // Environment overrides apply to this run, so they are what `get` shows
+            let saved = config::get(emo.config(), &key)?;
+            let value = match emo.overrides().get(&key) {
+                Some(value) => {
+                    eprintln!("(from {})", emo.overrides().source(&key).unwrap_or_default());
+                    value
+                }
+                None => saved.ok_or_else(|| EmoError::NoResults(format!("'{}' is not set", key)))?,
+            };
+            match value {
+                serde_json::Value::String(s) => try_print(&s),
+                value => try_print(&serde_json::to_string_pretty(&value)?),
             }
         }
-        _ if cmd.erase => handle_erase(search_term)?,
-        _ if cmd.save.is_some() => handle_save(cmd.save.as_ref().unwrap(), search_term)?,
-        _ if cmd.define => handle_define(search_term)?,
-        _ => handle_search(search_term, cmd.count, cmd.number)?,
+        ConfigAction::Set { key, value } => {
+            emo.update_config(|current| {
+                *current = config::set(current, &key, &value)?;
+                Ok(())
+            })?;
+            let saved = config::get(emo.config(), &key)?.unwrap_or_default();
+            try_print(&format!("{} = {} ✅", key, saved));
+            if let Some(var) = emo.overrides().source(&key) {
+                eprintln!("Note: {} is set and takes precedence", var);
+            }
+        }
+        ConfigAction::Unset { key } => {
+            let removed = emo.update_config(|current| {
+                let (updated, removed) = config::unset(current, &key)?;
+                *current = updated;
+                Ok(removed)
+            })?;
+            if removed {
+                try_print(&format!("'{}' unset ✅", key));
+            } else {
+                try_print(&format!("'{}' was not set", key));
+            }
+        }
+        ConfigAction::Edit => handle_config_edit(emo.config_path())?,
+    }
+    Ok(())
+}
+
+/// Edit a copy of the config and only replace the file once the copy parses
+fn handle_config_edit(path: &std::path::Path) -> Result<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.trim().is_empty()))
+        .unwrap_or_else(|| "vi".to_string());
+
+    let draft = path.with_extension("edit.json");
+    if path.exists() {
+        std::fs::copy(path, &draft)?;
+    } else {
+        // Nothing saved yet, so the edit starts from the defaults
+        if let Some(dir) = draft.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut value = serde_json::to_value(EmojiMappings::load_from(path)?)?;
+        value["version"] = config::CONFIG_VERSION.into();
+        std::fs::write(&draft, serde_json::to_string_pretty(&value)? + "\n")?;
+    }
+
+    // The editor may come with arguments, like "code --wait"
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+    let status = std::process::Command::new(program)
+        .args(words)
+        .arg(&draft)
+        .status()
+        .map_err(|e| EmoError::ConfigError(format!("Could not run editor '{}': {}", editor, e)))?;
+    if !status.success() {
+        return Err(EmoError::ConfigError(format!(
+            "Editor '{}' failed; your changes are in {}",
+            editor,
+            draft.display()
+        )));
     }
 
+    let text = std::fs::read_to_string(&draft)?;
+    if let Err(e) = config::parse(&text, path) {
+        return Err(EmoError::ConfigError(format!(
+            "{}; the config was not changed and your edit is in {}",
+            error::report(&e),
+            draft.display()
+        )));
+    }
+    let _lock = config::lock(path)?;
+    config::store(path, &text)?;
+    std::fs::remove_file(&draft)?;
+    try_print(&format!("Saved {} ✅", path.display()));
     Ok(())
 }
+
+fn run() -> Result<()> {
+    let mut cli = Cli::parse();
+    let _ = PROFILE.set(cli.profile.take());
+    let command = match cli.command.take() {
+        Some(command) => command,
+        None => {
+            // `--model` with a query also made the model the default
+            if !(cli.list_models || cli.list_mappings || cli.random || cli.accept || cli.reject) {
+                handle_use_models(&cli.model)?;
+            }
+            cli.legacy()
+        }
+    };
+
+    match command {
+        Command::Search { count, number, output, terms } => {
+            let emo = client(Vec::new())?;
+            let count = count.unwrap_or(emo.count());
+            answer(emo, &output, &terms, Mode::Search { count }, |emo, query| {
+                handle_search(&emo, query, count, number)
+            })
+        }
+        Command::Define { output, terms } => {
+            answer(client(Vec::new())?, &output, &terms, Mode::Define, |emo, query| handle_define(&emo, query))
+        }
+        Command::Memo { action } => match action {
+            MemoAction::Add { scope, output, emoji, terms } => {
+                let mode = Mode::Memo { emoji: emoji.clone(), scope };
+                answer(client(Vec::new())?, &output, &terms, mode, |mut emo, query| {
+                    handle_save(&mut emo, scope, &emoji, query)
+                })
+            }
+            MemoAction::Append { scope, emoji, terms } => {
+                let mut emo = client(Vec::new())?;
+                let term = required(&terms)?;
+                let memo = emo.append_memo(scope, &term, &emoji)?;
+                show_memo(&emo, scope, &term, &memo);
+                Ok(())
+            }
+            MemoAction::Move { scope, to, emoji, terms } => {
+                let mut emo = client(Vec::new())?;
+                let term = required(&terms)?;
+                let memo = emo.move_memo(scope, &term, &emoji, to)?;
+                show_memo(&emo, scope, &term, &memo);
+                Ok(())
+            }
+            MemoAction::Rm { scope, emoji: Some(emoji), terms } => {
+                let mut emo = client(Vec::new())?;
+                let term = required(&terms)?;
+                match emo.remove_memo_emoji(scope, &term, &emoji)? {
+                    Some(memo) => show_memo(&emo, scope, &term, &memo),
+                    None => try_print(&format!("Mapping for '{}' erased ✅", term)),
+                }
+                Ok(())
+            }
+            MemoAction::Rm { scope, emoji: None, terms } => {
+                handle_erase(&mut client(Vec::new())?, scope, &required(&terms)?)
+            }
+            MemoAction::Ls => handle_list_mappings(&client(Vec::new())?),
+            MemoAction::Export { format, scope } => handle_memo_export(&client(Vec::new())?, format, scope),
+            MemoAction::Import { format, merge, scope, file } => {
+                handle_memo_import(&mut client(Vec::new())?, &file, format, merge, scope)
+            }
+        },
+        Command::Ai { count, model, verbose, sentence, explain, accept, reject, output, situation } => {
+            let emo = client(model)?;
+            let count = count.unwrap_or(emo.count());
+            // Feedback applies to the last AI suggestion; any terms are the emojis to mark
+            if accept || reject {
+                return handle_feedback(&emo, &situation, accept);
+            }
+            if explain {
+                return handle_explain(&emo, &required(&situation)?);
+            }
+
+            // Sentences come from a single model, the first one given
+            let options = sentence.options();
+            let mode = match &options {
+                Some(options) => Mode::Sentence { count, options: options.clone() },
+                None => Mode::Ai { count },
+            };
+            answer(emo, &output, &situation, mode, |emo, query| match &options {
+                Some(options) => (0..count).try_for_each(|_| handle_ai_sentence(&emo, query, options)),
+                None => handle_ai_emoji(&emo, query, count, verbose),
+            })
+        }
+        Command::Models { action } => match action.unwrap_or(ModelsAction::Ls) {
+            ModelsAction::Ls => handle_list_models(),
+            ModelsAction::Use { models } => {
+                handle_use_models(&models)?;
+                try_print(&format!("Default model: {} ✅", models.join(", ")));
+                Ok(())
+            }
+        },
+        Command::Config { action } => handle_config(client(Vec::new())?, action),
+        Command::Random => handle_random(&client(Vec::new())?),
+        Command::Annotate { text, density, model } => {
+            handle_annotate(&client(model.into_iter().collect())?, &text, density)
+        }
+        Command::Gitmoji { ai, model, code, hook, install, force, message } => {
+            // Only an explicit model or --ai loads one
+            let emo = client(model.iter().cloned().collect())?;
+            let selector = (ai || model.is_some()).then(|| emo.selector());
+            match (hook, install) {
+                (Some(file), _) => handle_gitmoji_hook(&file, message.first().map(String::as_str), code, selector),
+                (None, true) => handle_gitmoji_install(ai, model.as_deref(), code, force),
+                (None, false) => handle_gitmoji(&message.join(" "), code, selector),
+            }
+        }
+        Command::Changelog { pick, ai, model, range } => {
+            let ai = ai || model.is_some();
+            handle_changelog(&client(model.into_iter().collect())?, range.as_deref(), pick, ai)
+        }
+    }
+}
@@ -0,0 +1,632 @@
+// Memos, and moving them in and out of emo
+//
+// A memo is the emoji saved for a search term, best first: "🚀", or a
+// ranked list like ["🎉", "🥳", "🎊"] that searches give in that order. A
+// key is a search term, or a pattern that covers many: `deploy*`,
+// `*-failed` or `re:^v\d+`, looked up through a `MemoIndex`.
+//
+// Memos export as JSON, CSV, YAML or an espanso match file, and import from
+// any of those, or from another emo config. An import merges into a config
+// with a `Merge` strategy and returns an `ImportSummary` of what changed, so
+// conflicts are reported instead of asked about.
+use crate::error::{EmoError, Result};
+use crate::unicode;
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The emoji saved for a term, best first; never empty. Each is one
+/// grapheme cluster, so "👩🏽‍💻", "#️⃣" and "🇯🇵" fit as well as "🚀".
+#[derive(Debug, Clone)]
+pub struct Memo(Vec<String>);
+
+/// `text` as an emoji a memo can hold: one grapheme cluster that shows as
+/// an emoji. A text-style symbol like "❤" gets VS16, making "❤️".
+pub fn memo_entry(text: &str) -> Result<String> {
+    let text = text.trim();
+    let mut graphemes = unicode::graphemes(text);
+    if let (Some(emoji), None) = (graphemes.next(), graphemes.next()) {
+        if unicode::is_emoji_cluster(emoji) {
+            return Ok(emoji.to_string());
+        }
+        let presented = format!("{}\u{FE0F}", emoji);
+        if unicode::is_emoji_cluster(&presented) {
+            return Ok(presented);
+        }
+    }
+    Err(EmoError::InvalidInput(format!("'{}' is not a single emoji", text)))
+}
+
+/// An entry read from a config. Older versions took any single character,
+/// like "λ", so those still load as they are; only new memos must be emoji.
+fn saved_entry(text: &str) -> Result<String> {
+    memo_entry(text).or_else(|e| {
+        let mut graphemes = unicode::graphemes(text.trim());
+        match (graphemes.next(), graphemes.next()) {
+            (Some(character), None) => Ok(character.to_string()),
+            _ => Err(e),
+        }
+    })
+}
+
+/// "❤️" and "❤" are the same entry
+fn same(a: &str, b: &str) -> bool {
+    unicode::strip_selectors(a) == unicode::strip_selectors(b)
+}
+
+impl Memo {
+    /// A ranked list; an error if it is empty or holds something that isn't
+    /// an emoji. Repeats are dropped.
+    pub fn new<S: Into<String>>(emojis: impl IntoIterator<Item = S>) -> Result<Self> {
+        Self::collect(emojis, memo_entry)
+    }
+
+    fn collect<S: Into<String>>(
+        emojis: impl IntoIterator<Item = S>,
+        entry: fn(&str) -> Result<String>,
+    ) -> Result<Self> {
+        let mut list: Vec<String> = Vec::new();
+        for emoji in emojis {
+            let emoji = entry(&emoji.into())?;
+            if !list.iter().any(|e| same(e, &emoji)) {
+                list.push(emoji);
+            }
+        }
+        if list.is_empty() {
+            return Err(EmoError::InvalidInput("A memo needs at least one emoji".to_string()));
+        }
+        Ok(Memo(list))
+    }
+
+    /// The top choice
+    pub fn first(&self) -> &str {
+        &self.0[0]
+    }
+
+    pub fn emojis(&self) -> &[String] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, emoji: &str) -> bool {
+        self.0.iter().any(|e| same(e, emoji))
+    }
+
+    /// Add an emoji at the end; an error if it is already in the list
+    pub fn push(&mut self, emoji: &str) -> Result<()> {
+        let emoji = memo_entry(emoji)?;
+        if self.contains(&emoji) {
+            return Err(EmoError::InvalidInput(format!("{} is already in the list", emoji)));
+        }
+        self.0.push(emoji);
+        Ok(())
+    }
+
+    /// Move an emoji to a 1-based position, or the end if past it
+    pub fn move_to(&mut self, emoji: &str, position: usize) -> Result<()> {
+        let from = self.position(emoji)?;
+        if position == 0 {
+            return Err(EmoError::InvalidInput("Positions start at 1".to_string()));
+        }
+        let emoji = self.0.remove(from);
+        self.0.insert((position - 1).min(self.0.len()), emoji);
+        Ok(())
+    }
+
+    /// Take an emoji out; None if it was the only one
+    pub fn without(mut self, emoji: &str) -> Result<Option<Self>> {
+        let index = self.position(emoji)?;
+        self.0.remove(index);
+        Ok((!self.0.is_empty()).then_some(self))
+    }
+
+    fn position(&self, emoji: &str) -> Result<usize> {
+        self.0
+            .iter()
+            .position(|e| same(e, emoji))
+            .ok_or_else(|| EmoError::InvalidInput(format!("{} is not in the list", emoji)))
+    }
+}
+
+/// Lists are equal when they differ only in selectors, like "🛳" and "🛳️"
+impl PartialEq for Memo {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| same(a, b))
+    }
+}
+
+impl Eq for Memo {}
+
+impl From<char> for Memo {
+    fn from(emoji: char) -> Self {
+        Memo(vec![emoji.to_string()])
+    }
+}
+
+/// A memo equals an emoji when that is all it holds
+impl PartialEq<char> for Memo {
+    fn eq(&self, other: &char) -> bool {
+        self.0.len() == 1 && same(&self.0[0], &other.to_string())
+    }
+}
+
+impl PartialEq<&str> for Memo {
+    fn eq(&self, other: &&str) -> bool {
+        self.0.len() == 1 && same(&self.0[0], other)
+    }
+}
+
+/// Space-separated, best first
+impl fmt::Display for Memo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.join(" "))
+    }
+}
+
+/// One emoji is saved as a string, several as a list
+impl Serialize for Memo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self.0.as_slice() {
+            [emoji] => emoji.serialize(serializer),
+            list => list.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Memo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Saved {
+            One(String),
+            Many(Vec<String>),
+        }
+        let list = match Saved::deserialize(deserializer)
+            .map_err(|_| serde::de::Error::custom("expected an emoji or a list of emoji"))?
+        {
+            Saved::One(emoji) => vec![emoji],
+            Saved::Many(list) => list,
+        };
+        Memo::collect(list, saved_entry).map_err(|e| serde::de::Error::custom(e.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MemoFormat {
+    /// `{"term": "emoji"}`; an emo config works too
+    #[default]
+    Json,
+    /// `term,emoji` rows under a header
+    Csv,
+    /// `term: emoji` lines
+    Yaml,
+    /// An espanso match file, `:term` expanding to the emoji
+    Espanso,
+}
+
+impl FromStr for MemoFormat {
+    type Err = EmoError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "json" => Ok(MemoFormat::Json),
+            "csv" => Ok(MemoFormat::Csv),
+            "yaml" | "yml" => Ok(MemoFormat::Yaml),
+            "espanso" => Ok(MemoFormat::Espanso),
+            _ => Err(EmoError::InvalidInput(format!(
+                "Unknown memo format '{}', expected json, csv, yaml or espanso",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for MemoFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoFormat::Json => "json",
+            MemoFormat::Csv => "csv",
+            MemoFormat::Yaml => "yaml",
+            MemoFormat::Espanso => "espanso",
+        })
+    }
+}
+
+impl MemoFormat {
+    /// The format a file's extension stands for. YAML files are read as
+    /// espanso when they have `matches`, so both come out as `Yaml` here.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()?.to_str()?.to_lowercase().parse().ok()
+    }
+}
+
+/// What to do when an imported memo has a different emoji than a saved one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Merge {
+    /// Saved memos stay; only new terms are added
+    #[default]
+    Keep,
+    /// Imported memos replace saved ones
+    Overwrite,
+    /// Change nothing, only say what an import would do
+    Report,
+}
+
+impl FromStr for Merge {
+    type Err = EmoError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "keep" => Ok(Merge::Keep),
+            "overwrite" => Ok(Merge::Overwrite),
+            "report" => Ok(Merge::Report),
+            _ => Err(EmoError::InvalidInput(format!(
+                "Unknown merge strategy '{}', expected keep, overwrite or report",
+                s
+            ))),
+        }
+    }
+}
+
+/// A term saved with some emoji and imported with others
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub term: String,
+    pub saved: Memo,
+    pub imported: Memo,
+}
+
+/// The outcome of an import; with `Merge::Report`, what it would be
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// New terms
+    pub added: Vec<(String, Memo)>,
+    /// Conflicts the imported emoji won
+    pub replaced: Vec<Conflict>,
+    /// Conflicts the saved emoji kept; with `Merge::Report`, every conflict
+    pub conflicts: Vec<Conflict>,
+    /// Memos that were already saved as they are
+    pub unchanged: usize,
+    /// Entries that aren't emoji, with the reason
+    pub skipped: Vec<(String, String)>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EspansoFile {
+    matches: Vec<EspansoMatch>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EspansoMatch {
+    trigger: String,
+    replace: String,
+}
+
+/// A memo in a file: one string, which may hold several space-separated
+/// emoji, or a list
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Entry {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Entry {
+    fn emojis(self) -> Vec<String> {
+        match self {
+            Entry::One(text) => text.split_whitespace().map(str::to_string).collect(),
+            Entry::Many(list) => list,
+        }
+    }
+}
+
+fn entries(memos: BTreeMap<String, Entry>) -> Vec<(String, Vec<String>)> {
+    memos.into_iter().map(|(term, entry)| (term, entry.emojis())).collect()
+}
+
+/// Espanso triggers start with ':', like `:deploy`
+const TRIGGER_PREFIX: &str = ":";
+
+/// Memos as a file in `format`, sorted by term. Lists stay lists in JSON and
+/// YAML, are space-separated in CSV, and give their top choice to espanso.
+pub fn export(memos: &[(&str, &Memo)], format: MemoFormat) -> Result<String> {
+    let sorted: BTreeMap<&str, &Memo> = memos.iter().map(|(t, m)| (*t, *m)).collect();
+    match format {
+        MemoFormat::Json => Ok(serde_json::to_string_pretty(&sorted)? + "\n"),
+        MemoFormat::Yaml => serde_yaml::to_string(&sorted).map_err(yaml_error),
+        MemoFormat::Espanso => {
+            let file = EspansoFile {
+                matches: sorted
+                    .into_iter()
+                    .map(|(term, memo)| EspansoMatch {
+                        trigger: format!("{}{}", TRIGGER_PREFIX, term),
+                        replace: memo.first().to_string(),
+                    })
+                    .collect(),
+            };
+            serde_yaml::to_string(&file).map_err(yaml_error)
+        }
+        MemoFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(["term", "emoji"]).map_err(csv_error)?;
+            for (term, memo) in sorted {
+                writer.write_record([term, &memo.to_string()]).map_err(csv_error)?;
+            }
+            let bytes = writer.into_inner().map_err(|e| EmoError::InvalidInput(e.to_string()))?;
+            Ok(String::from_utf8_lossy(&bytes).into_owned())
+        }
+    }
+}
+
+/// Terms and their emoji from a file in `format`, in file order
+pub fn parse(text: &str, format: MemoFormat) -> Result<Vec<(String, Vec<String>)>> {
+    match format {
+        MemoFormat::Json => {
+            let value: serde_json::Value = serde_json::from_str(text)?;
+            // A whole config brings its memos
+            let memos = value.get("mappings").cloned().unwrap_or(value);
+            let memos: BTreeMap<String, Entry> = serde_json::from_value(memos).map_err(|e| {
+                EmoError::InvalidInput(format!("Expected an object of terms and emoji: {}", e))
+            })?;
+            Ok(entries(memos))
+        }
+        MemoFormat::Yaml | MemoFormat::Espanso => {
+            let value: serde_yaml::Value = serde_yaml::from_str(text).map_err(yaml_error)?;
+            if value.get("matches").is_some() {
+                let file: EspansoFile = serde_yaml::from_value(value).map_err(yaml_error)?;
+                return Ok(file
+                    .matches
+                    .into_iter()
+                    .map(|m| (m.trigger.trim_start_matches(TRIGGER_PREFIX).to_string(), Entry::One(m.replace).emojis()))
+                    .collect());
+            }
+            if format == MemoFormat::Espanso {
+                return Err(EmoError::InvalidInput("Expected an espanso file with 'matches'".to_string()));
+            }
+            let memos: BTreeMap<String, Entry> = serde_yaml::from_value(value).map_err(yaml_error)?;
+            Ok(entries(memos))
+        }
+        MemoFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .has_headers(false)
+                .flexible(true)
+                .from_reader(text.as_bytes());
+            let mut memos = Vec::new();
+            for (i, record) in reader.records().enumerate() {
+                let record = record.map_err(csv_error)?;
+                let (Some(term), Some(emoji)) = (record.get(0), record.get(1)) else {
+                    return Err(EmoError::InvalidInput(format!(
+                        "Line {} should be term,emoji",
+                        i + 1
+                    )));
+                };
+                if i == 0 && term == "term" && emoji == "emoji" {
+                    continue;
+                }
+                memos.push((term.to_string(), Entry::One(emoji.to_string()).emojis()));
+            }
+            Ok(memos)
+        }
+    }
+}
+
+/// Merge imported memos into `mappings`. With `Merge::Report` the summary
+/// says what would change and `mappings` is left alone.
+pub fn merge(mappings: &mut IndexMap<String, Memo>, imported: Vec<(String, Vec<String>)>, strategy: Merge) -> ImportSummary {
+    let mut summary = ImportSummary::default();
+    let mut merged = mappings.clone();
+
+    for (term, emojis) in imported {
+        let term = term.trim().to_string();
+        if term.is_empty() {
+            summary.skipped.push((term, "empty term".to_string()));
+            continue;
+        }
+        if let Err(e) = check_key(&term) {
+            summary.skipped.push((term, e.to_string()));
+            continue;
+        }
+        let imported = match to_memo(&emojis) {
+            Ok(memo) => memo,
+            Err(reason) => {
+                summary.skipped.push((term, reason));
+                continue;
+            }
+        };
+
+        match merged.get(&term).cloned() {
+            None => {
+                merged.insert(term.clone(), imported.clone());
+                summary.added.push((term, imported));
+            }
+            Some(saved) if saved == imported => summary.unchanged += 1,
+            Some(saved) => {
+                let conflict = Conflict { term: term.clone(), saved, imported: imported.clone() };
+                if strategy == Merge::Overwrite {
+                    merged.insert(term, imported);
+                    summary.replaced.push(conflict);
+                } else {
+                    summary.conflicts.push(conflict);
+                }
+            }
+        }
+    }
+
+    if strategy != Merge::Report {
+        *mappings = merged;
+    }
+    summary
+}
+
+/// Memo keys starting with this are regular expressions, like `re:^v\d+`
+pub const REGEX_PREFIX: &str = "re:";
+
+/// A memo key that matches more than one query
+#[derive(Debug, Clone)]
+enum Pattern {
+    /// `*` matches any text, as in `deploy*` or `*-failed`
+    Glob(String),
+    Regex(Regex),
+}
+
+impl Pattern {
+    /// The pattern a key stands for, or None for a plain term
+    fn parse(key: &str) -> Option<Result<Self>> {
+        if let Some(expression) = key.strip_prefix(REGEX_PREFIX) {
+            return Some(Regex::new(expression).map(Pattern::Regex).map_err(|e| {
+                EmoError::InvalidInput(format!("'{}' is not a valid regular expression: {}", expression, e))
+            }));
+        }
+        key.contains('*').then(|| Ok(Pattern::Glob(key.to_string())))
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        match self {
+            Pattern::Glob(glob) => glob_matches(glob, query),
+            Pattern::Regex(regex) => regex.is_match(query),
+        }
+    }
+
+    /// How many characters every match starts with; more says more
+    fn literal_prefix(&self) -> usize {
+        match self {
+            Pattern::Glob(glob) => glob.split('*').next().unwrap_or("").chars().count(),
+            Pattern::Regex(regex) => {
+                // Only anchored expressions without alternatives have one
+                let Some(rest) = regex.as_str().strip_prefix('^') else { return 0 };
+                if rest.contains('|') {
+                    return 0;
+                }
+                let mut chars = rest.chars().peekable();
+                let mut prefix = 0;
+                while let Some(c) = chars.next() {
+                    if "\\.+*?()[]{}^$".contains(c) || matches!(chars.peek(), Some('?' | '*' | '{')) {
+                        break;
+                    }
+                    prefix += 1;
+                }
+                prefix
+            }
+        }
+    }
+}
+
+fn glob_matches(glob: &str, query: &str) -> bool {
+    let mut parts = glob.split('*');
+    let first = parts.next().unwrap_or("");
+    let Some(mut rest) = query.strip_prefix(first) else { return false };
+    let mut parts: Vec<&str> = parts.collect();
+    let last = parts.pop().unwrap_or("");
+    for part in parts {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// An error if a memo key is a pattern that doesn't parse
+pub fn check_key(key: &str) -> Result<()> {
+    match Pattern::parse(key) {
+        Some(Err(e)) => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Memos ready to answer queries. A term's own memo comes first; otherwise
+/// the matching pattern from the most specific layer, then the one with the
+/// longest literal prefix, the earliest one on a tie. Patterns that don't
+/// parse never match.
+#[derive(Debug, Clone, Default)]
+pub struct MemoIndex {
+    exact: HashMap<String, Memo>,
+    /// With the rank of their layer, most specific first
+    patterns: Vec<(usize, String, Pattern, Memo)>,
+}
+
+impl MemoIndex {
+    /// Memos in the order their patterns are tried
+    pub fn new<M: Into<Memo>>(memos: impl IntoIterator<Item = (String, M)>) -> Self {
+        let mut index = MemoIndex::default();
+        for (key, memo) in memos {
+            index.insert(0, key, memo.into());
+        }
+        index
+    }
+
+    /// Memos from several layers, most specific first. A key keeps the memo
+    /// of the first layer that has it, and a pattern from an earlier layer
+    /// beats any pattern from a later one.
+    pub fn layered<'a>(layers: impl IntoIterator<Item = &'a IndexMap<String, Memo>>) -> Self {
+        let mut index = MemoIndex::default();
+        let mut seen = HashSet::new();
+        for (rank, memos) in layers.into_iter().enumerate() {
+            for (key, memo) in memos {
+                if seen.insert(key.as_str()) {
+                    index.insert(rank, key.clone(), memo.clone());
+                }
+            }
+        }
+        index
+    }
+
+    fn insert(&mut self, rank: usize, key: String, memo: Memo) {
+        match Pattern::parse(&key) {
+            None => {
+                self.exact.insert(key, memo);
+            }
+            Some(Ok(pattern)) => self.patterns.push((rank, key, pattern, memo)),
+            Some(Err(_)) => {}
+        }
+    }
+
+    /// The key that answers `query`, and its memo
+    pub fn get(&self, query: &str) -> Option<(&str, &Memo)> {
+        if let Some((key, memo)) = self.exact.get_key_value(query) {
+            return Some((key, memo));
+        }
+        let mut best: Option<(usize, usize, &str, &Memo)> = None;
+        for (rank, key, pattern, memo) in &self.patterns {
+            if best.is_some_and(|(best_rank, ..)| best_rank < *rank) {
+                break;
+            }
+            if !pattern.matches(query) {
+                continue;
+            }
+            let prefix = pattern.literal_prefix();
+            if best.is_some_and(|(_, longest, ..)| prefix <= longest) {
+                continue;
+            }
+            best = Some((*rank, prefix, key, memo));
+        }
+        best.map(|(_, _, key, memo)| (key, memo))
+    }
+}
+
+/// Each entry must be a single emoji
+fn to_memo(emojis: &[String]) -> std::result::Result<Memo, String> {
+    Memo::new(emojis).map_err(|e| match e {
+        EmoError::InvalidInput(reason) => reason,
+        e => e.to_string(),
+    })
+}
+
+fn yaml_error(e: serde_yaml::Error) -> EmoError {
+    EmoError::InvalidInput(format!("YAML: {}", e))
+}
+
+fn csv_error(e: csv::Error) -> EmoError {
+    EmoError::InvalidInput(format!("CSV: {}", e))
+}
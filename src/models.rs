@@ -1,6 +1,12 @@
 // Model definitions for AI emoji selection
 use serde::{Deserialize, Serialize};
 use crate::error::{Result, EmoError};
+use anyhow::Result as AnyhowResult;
+use hf_hub::api::sync::ApiBuilder;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,16 +40,72 @@ impl ModelInfo {
     }
 }
 
-pub struct ModelRegistry;
+/// Resolves model ids and manages the shared model cache. The model list is
+/// fetched once per registry, so share one registry across several models.
+pub struct ModelRegistry {
+    models: OnceLock<Vec<ModelInfo>>,
+}
 
 impl ModelRegistry {
     pub fn new() -> Self {
-        ModelRegistry
+        ModelRegistry { models: OnceLock::new() }
     }
 
     pub fn fetch_models(&self) -> Result<Vec<ModelInfo>> {
         // Always fetch from remote, no fallbacks
-        self.fetch_from_api()
+        if let Some(models) = self.models.get() {
+            return Ok(models.clone());
+        }
+        let models = self.fetch_from_api()?;
+        Ok(self.models.get_or_init(|| models).clone())
+    }
+
+    /// Look up a model by id, or take the first available one
+    pub fn resolve(&self, model_id: Option<&str>) -> Result<ModelInfo> {
+        let models = self.fetch_models()?;
+
+        match model_id {
+            Some(id) => models.into_iter()
+                .find(|m| m.id == id)
+                .ok_or_else(|| EmoError::ModelNotFound(format!("no model '{}'; see --list-models", id))),
+            None => models.into_iter()
+                .next()
+                .ok_or_else(|| EmoError::ModelNotFound("no models available from HuggingFace".to_string())),
+        }
+    }
+
+    /// Path to a model, from the cache directory when it is already there,
+    /// otherwise resolved through HuggingFace and downloaded
+    pub fn locate(&self, model_id: Option<&str>, model_dir: &Path) -> Result<PathBuf> {
+        if let Some((_, path)) = find_cached(model_id, model_dir) {
+            return Ok(path);
+        }
+        if crate::error::offline() {
+            return Err(EmoError::Offline(match model_id {
+                Some(id) => format!("{} is not downloaded yet", id),
+                None => format!("no models downloaded in {}", model_dir.display()),
+            }));
+        }
+        let model = self.resolve(model_id)?;
+        self.ensure_downloaded(&model, model_dir)
+    }
+
+    /// Path to the model in the cache directory, downloading it first if needed
+    pub fn ensure_downloaded(&self, model: &ModelInfo, model_dir: &Path) -> Result<PathBuf> {
+        let cached = model_dir.join(model.filename());
+        if crate::error::offline() && !cached.exists() {
+            return Err(EmoError::Offline(format!("{} is not downloaded yet", model.id)));
+        }
+        let path = self
+            .download(model, model_dir)
+            .map_err(|e| EmoError::download(format!("Failed to download {}", model.id), e))?;
+        // Remember where it went, since HuggingFace keeps files in its own cache
+        let mut index = read_index(model_dir);
+        index.insert(model.id.clone(), CachedModel { model: model.clone(), path: path.clone() });
+        if let Ok(text) = serde_json::to_string_pretty(&index) {
+            let _ = std::fs::write(model_dir.join(INDEX_FILE), text);
+        }
+        Ok(path)
     }
 
     pub fn fetch_from_api(&self) -> Result<Vec<ModelInfo>> {
@@ -52,29 +114,33 @@ impl ModelRegistry {
     }
 
     fn fetch_remote_models(&self) -> Result<Vec<ModelInfo>> {
+        if crate::error::offline() {
+            return Err(EmoError::Offline("the model list needs HuggingFace".to_string()));
+        }
+
         // Fetch from HuggingFace API for small instruct GGUF models
         let url = "https://huggingface.co/api/models?search=GGUF+Q4_K_M&limit=10&sort=downloads";
 
         let client = reqwest::blocking::Client::builder()
             .timeout(Duration::from_secs(10))
             .build()
-            .map_err(|e| EmoError::ConfigError(format!("Failed to create HTTP client: {}", e)))?;
+            .map_err(|e| EmoError::network("Failed to create HTTP client", e))?;
 
         let response = client.get(url).send()
-            .map_err(|e| EmoError::ConfigError(format!("Failed to fetch model list: {}", e)))?;
+            .map_err(|e| EmoError::network("Failed to fetch model list", e))?;
 
         if !response.status().is_success() {
-            return Err(EmoError::ConfigError(format!(
-                "Failed to fetch models: HTTP {}",
-                response.status()
-            )));
+            return Err(EmoError::Network {
+                message: format!("Failed to fetch models: HTTP {}", response.status()),
+                source: None,
+            });
         }
 
         let hf_models: Vec<HFModelResponse> = response.json()
-            .map_err(|e| EmoError::ConfigError(format!("Failed to parse model list: {}", e)))?;
+            .map_err(|e| EmoError::network("Failed to parse model list", e))?;
 
         if hf_models.is_empty() {
-            return Err(EmoError::ConfigError("No models found from HuggingFace".to_string()));
+            return Err(EmoError::ModelNotFound("no models found on HuggingFace".to_string()));
         }
 
         let mut models = Vec::new();
@@ -117,13 +183,7 @@ impl ModelRegistry {
 
                                     // Create a short ID from the repo name
                                     // Take meaningful parts, limit length
-                                    let id_parts: Vec<&str> = repo_name
-                                        .split(|c: char| c == '-' || c == '_')
-                                        .filter(|s| !s.is_empty() && s != &"GGUF" && s != &"Q4" && s != &"K" && s != &"M")
-                                        .take(3)
-                                        .collect();
-
-                                    let id = id_parts.join("-").to_lowercase();
+                                    let id = short_id(repo_name);
 
                                     models.push(ModelInfo {
                                         id,
@@ -145,10 +205,148 @@ impl ModelRegistry {
         }
 
         if models.is_empty() {
-            return Err(EmoError::ConfigError("No compatible GGUF models found".to_string()));
+            return Err(EmoError::ModelNotFound("no compatible GGUF models on HuggingFace".to_string()));
         }
 
         Ok(models)
     }
 
-}
\ No newline at end of file
+    fn download(&self, model: &ModelInfo, model_dir: &Path) -> AnyhowResult<PathBuf> {
+        // Create models directory in ~/.config/emo/models if it doesn't exist
+        std::fs::create_dir_all(model_dir)?;
+
+        let url = &model.url;
+        let filename = model.filename();
+
+        let model_file = model_dir.join(filename);
+
+        // Check if model already exists (silently return if it does)
+        if model_file.exists() {
+            return Ok(model_file);
+        }
+
+        // Try HuggingFace Hub API first
+        if let Ok(api) = ApiBuilder::new().with_progress(true).build() {
+            // Parse the URL to get repo info
+            let url_parts: Vec<&str> = model.url.split('/').collect();
+            if url_parts.len() > 5 {
+                let owner = url_parts[3];
+                let repo_name = url_parts[4];
+                let repo_id = format!("{}/{}", owner, repo_name);
+
+                // The HF API will use cached version if available, only print messages if actually downloading
+                // We can't easily detect if it's cached beforehand, so we'll rely on the progress bar
+                if let Ok(path) = api.model(repo_id).get(&model.filename()) {
+                    // Model was either cached or downloaded - the progress bar handles the feedback
+                    return Ok(path);
+                }
+            }
+        }
+
+        // Fallback to direct download
+        eprintln!("📥 Downloading {} model ({})...", model.name, model.id);
+        eprintln!("This is a one-time download for AI-powered emoji selection.");
+
+        // Create progress bar
+        let client = reqwest::blocking::Client::new();
+        let mut response = client.get(url).send()?;
+
+        let total_size = response
+            .content_length()
+            .unwrap_or(1_600_000_000); // Default to ~1.6GB if unknown
+
+        let pb = ProgressBar::new(total_size);
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template("{spinner:.green} [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
+                .expect("Failed to set progress bar template")
+                .progress_chars("#>-")
+        );
+
+        // Download with progress
+        let mut file = std::fs::File::create(&model_file)?;
+        let mut downloaded = 0u64;
+        let mut buffer = [0; 8192];
+
+        loop {
+            use std::io::Read;
+            let bytes_read = response.read(&mut buffer)?;
+            if bytes_read == 0 {
+                break;
+            }
+            file.write_all(&buffer[..bytes_read])?;
+            downloaded += bytes_read as u64;
+            pb.set_position(downloaded);
+        }
+
+        pb.finish_with_message("✅ Model downloaded successfully!");
+        Ok(model_file)
+    }
+}
+
+/// A short model id from a repo or file name, "Qwen2.5-0.5B-Instruct-GGUF"
+/// -> "qwen2.5-0.5b-instruct"
+fn short_id(name: &str) -> String {
+    let id_parts: Vec<&str> = name
+        .split(['-', '_'])
+        .filter(|s| !s.is_empty() && !["gguf", "q4", "k", "m"].contains(&s.to_lowercase().as_str()))
+        .take(3)
+        .collect();
+    id_parts.join("-").to_lowercase()
+}
+
+/// Downloaded models by id, kept next to them
+const INDEX_FILE: &str = "models.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedModel {
+    model: ModelInfo,
+    path: PathBuf,
+}
+
+fn read_index(model_dir: &Path) -> std::collections::BTreeMap<String, CachedModel> {
+    std::fs::read_to_string(model_dir.join(INDEX_FILE))
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+/// Models already on disk: the ones emo downloaded, then any other GGUF
+/// files in the cache directory, named after their file
+pub fn cached_models(model_dir: &Path) -> Vec<(ModelInfo, PathBuf)> {
+    let mut models: Vec<(ModelInfo, PathBuf)> = read_index(model_dir)
+        .into_values()
+        .filter(|c| c.path.exists())
+        .map(|c| (c.model, c.path))
+        .collect();
+
+    let mut files: Vec<PathBuf> = std::fs::read_dir(model_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("gguf")))
+        .collect();
+    files.sort();
+    for path in files {
+        if models.iter().any(|(_, p)| *p == path) {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else { continue };
+        let model = ModelInfo {
+            id: short_id(stem),
+            name: stem.to_string(),
+            url: path.display().to_string(),
+            size_mb: path.metadata().map(|m| (m.len() / 1_000_000) as usize).unwrap_or(0),
+            description: "downloaded GGUF file".to_string(),
+        };
+        models.push((model, path));
+    }
+    models
+}
+
+/// A downloaded model by id, or the first one when no id is given
+pub fn find_cached(model_id: Option<&str>, model_dir: &Path) -> Option<(ModelInfo, PathBuf)> {
+    cached_models(model_dir)
+        .into_iter()
+        .find(|(model, _)| model_id.is_none_or(|id| model.id == id))
+}
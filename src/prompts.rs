@@ -0,0 +1,191 @@
+// Prompt templates for AI emoji selection
+//
+// Templates are plain strings with `{situation}`, `{count}`, `{length}`,
+// `{exclude}`, `{include}`, `{start}`, `{examples}`, `{emojis}`,
+// `{definitions}` and `{options}` placeholders. A line whose placeholders all render empty is
+// dropped, so optional parts like "Do not use: {exclude}." disappear cleanly.
+use crate::fewshot::{self, Example};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Key in the `prompts` config table that applies to every model
+pub const ANY_MODEL: &str = "*";
+
+const EMOJI_SYSTEM: &str = "You are an emoji picker. You reply with emoji only, never with words.";
+const EMOJI_USER: &str = "Select ONE emoji that best represents: {situation}.\n\
+                          Our usual picks: {examples}.\n\
+                          Do not use: {exclude}.\n\
+                          Reply with only the emoji, nothing else.";
+const SENTENCE_SYSTEM: &str = "You tell short stories using only emoji, never with words.";
+const SENTENCE_USER: &str = "Create a sequence of exactly {length} emojis that tells a story about: {situation}.\n\
+                             Start with: {start}\n\
+                             Include: {include}.\n\
+                             Our usual picks: {examples}.\n\
+                             Do not use: {exclude}.\n\
+                             Use only emojis, no text. Reply with the emoji sequence.";
+const EXPLAIN_SYSTEM: &str = "You read emoji messages and explain them in plain words.";
+const EXPLAIN_USER: &str = "Explain in one or two short sentences what this emoji message means: {emojis}\n\
+                            Dictionary meanings: {definitions}.\n\
+                            Context: {situation}.\n\
+                            Reply with the explanation only.";
+const GITMOJI_SYSTEM: &str = "You label commit messages with gitmoji.";
+const GITMOJI_USER: &str = "Pick the gitmoji that fits this commit message: {situation}\n\
+                            Options:\n{options}\n\
+                            Reply with the gitmoji code only, like :bug:.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptKind {
+    Emoji,
+    Sentence,
+    Explain,
+    Gitmoji,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+/// Per-model overrides, stored under `prompts.<model id>` in the config
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrompts {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<PromptTemplate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sentence: Option<PromptTemplate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explain: Option<PromptTemplate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gitmoji: Option<PromptTemplate>,
+}
+
+impl ModelPrompts {
+    fn template(&self, kind: PromptKind) -> Option<&PromptTemplate> {
+        match kind {
+            PromptKind::Emoji => self.emoji.as_ref(),
+            PromptKind::Sentence => self.sentence.as_ref(),
+            PromptKind::Explain => self.explain.as_ref(),
+            PromptKind::Gitmoji => self.gitmoji.as_ref(),
+        }
+    }
+}
+
+/// Values substituted into the placeholders
+#[derive(Default)]
+pub struct PromptVars<'a> {
+    pub situation: &'a str,
+    pub count: usize,
+    pub length: usize,
+    pub exclude: &'a [String],
+    /// Emojis a sentence must contain
+    pub include: &'a [String],
+    /// Emojis a sentence must begin with
+    pub start: &'a str,
+    pub examples: &'a [Example],
+    /// The emoji message to explain
+    pub emojis: &'a str,
+    /// Database definitions of those emojis, e.g. "🔥 fire; ☕ hot beverage"
+    pub definitions: &'a str,
+    /// Gitmoji to choose from, one per line
+    pub options: &'a str,
+}
+
+/// A rendered prompt, ready for a chat template or a raw completion
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatPrompt {
+    pub system: String,
+    pub user: String,
+    /// Completion cue appended when the model has no chat template
+    pub cue: &'static str,
+}
+
+impl ChatPrompt {
+    pub fn build(kind: PromptKind, overrides: Option<&ModelPrompts>, vars: &PromptVars) -> Self {
+        let custom = overrides.and_then(|o| o.template(kind));
+        let (system, user, cue) = match kind {
+            PromptKind::Emoji => (EMOJI_SYSTEM, EMOJI_USER, "Emoji:"),
+            PromptKind::Sentence => (SENTENCE_SYSTEM, SENTENCE_USER, "Emoji sequence:"),
+            PromptKind::Explain => (EXPLAIN_SYSTEM, EXPLAIN_USER, "Meaning:"),
+            PromptKind::Gitmoji => (GITMOJI_SYSTEM, GITMOJI_USER, "Gitmoji:"),
+        };
+
+        let system = custom.and_then(|t| t.system.as_deref()).unwrap_or(system);
+        let user = custom.and_then(|t| t.user.as_deref()).unwrap_or(user);
+
+        ChatPrompt {
+            system: render(system, vars),
+            user: render(user, vars),
+            cue,
+        }
+    }
+
+    /// Plain "Task: ... Emoji:" prompt for models without a chat template
+    pub fn to_completion(&self) -> String {
+        let task = self.user.lines().collect::<Vec<_>>().join(" ");
+        format!("Task: {}\n{}", task, self.cue)
+    }
+}
+
+/// Find the overrides for a model id, falling back to the `*` entry
+pub fn lookup<'a>(
+    prompts: &'a HashMap<String, ModelPrompts>,
+    model_id: Option<&str>,
+) -> Option<&'a ModelPrompts> {
+    model_id
+        .and_then(|id| prompts.get(id))
+        .or_else(|| prompts.get(ANY_MODEL))
+}
+
+pub fn render(template: &str, vars: &PromptVars) -> String {
+    let exclude = vars.exclude.join(", ");
+    let values = [
+        ("{situation}", vars.situation.to_string()),
+        ("{count}", vars.count.to_string()),
+        ("{length}", vars.length.to_string()),
+        ("{exclude}", exclude),
+        ("{include}", vars.include.join(", ")),
+        ("{start}", vars.start.to_string()),
+        ("{examples}", fewshot::render(vars.examples)),
+        ("{emojis}", vars.emojis.to_string()),
+        ("{definitions}", vars.definitions.to_string()),
+        ("{options}", vars.options.to_string()),
+    ];
+
+    template
+        .lines()
+        .filter_map(|line| {
+            let placeholders: Vec<_> = values.iter().filter(|(p, _)| line.contains(p)).collect();
+            if !placeholders.is_empty() && placeholders.iter().all(|(_, v)| v.is_empty()) {
+                return None;
+            }
+            Some(substitute(line, &values))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Replace placeholders in one left-to-right pass, so values that contain
+/// something like "{exclude}" are left as they are
+fn substitute(line: &str, values: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match values.iter().find(|(p, _)| rest.starts_with(p)) {
+            Some((placeholder, value)) => {
+                out.push_str(value);
+                rest = &rest[placeholder.len()..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
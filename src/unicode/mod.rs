@@ -0,0 +1,139 @@
+// Emoji classification from the Unicode emoji data
+//
+// Property lookups, grapheme-aware emoji segmentation, RGI validation and
+// terminal display width. `tables.rs` is generated from emoji-data.txt,
+// emoji-sequences.txt and emoji-zwj-sequences.txt by
+// `scripts/gen_unicode_tables.py`.
+mod tables;
+
+use std::cmp::Ordering;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub use tables::UNICODE_VERSION;
+
+/// Variation selector asking for text presentation
+pub const TEXT_SELECTOR: char = '\u{FE0E}';
+/// Variation selector asking for emoji presentation
+pub const EMOJI_SELECTOR: char = '\u{FE0F}';
+const KEYCAP: char = '\u{20E3}';
+/// Where the emoji blocks start; below it, text-default symbols need VS16
+const PICTOGRAPH_PLANE: char = '\u{1F000}';
+
+fn lookup(table: &[(u32, u32)], ch: char) -> bool {
+    let cp = ch as u32;
+    table
+        .binary_search_by(|&(lo, hi)| {
+            if hi < cp {
+                Ordering::Less
+            } else if lo > cp {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// `Emoji`: the character can be shown as an emoji, possibly only with VS16
+pub fn is_emoji(ch: char) -> bool {
+    lookup(tables::EMOJI, ch)
+}
+
+/// `Emoji_Presentation`: the character is shown as an emoji by default
+pub fn is_emoji_presentation(ch: char) -> bool {
+    lookup(tables::EMOJI_PRESENTATION, ch)
+}
+
+/// `Extended_Pictographic`: pictographs, including ones not yet assigned
+pub fn is_extended_pictographic(ch: char) -> bool {
+    lookup(tables::EXTENDED_PICTOGRAPHIC, ch)
+}
+
+/// `Emoji_Modifier`: the five skin tones
+pub fn is_emoji_modifier(ch: char) -> bool {
+    lookup(tables::EMOJI_MODIFIER, ch)
+}
+
+/// `Emoji_Modifier_Base`: characters a skin tone can follow
+pub fn is_emoji_modifier_base(ch: char) -> bool {
+    lookup(tables::EMOJI_MODIFIER_BASE, ch)
+}
+
+/// `Emoji_Component`: parts of sequences, like keycap bases, ZWJ and tags
+pub fn is_emoji_component(ch: char) -> bool {
+    lookup(tables::EMOJI_COMPONENT, ch)
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch)
+}
+
+/// Whether `s` is exactly one recommended (RGI) emoji, e.g. "🚀", "❤️",
+/// "#️⃣" or "👩🏽‍💻"
+pub fn is_rgi(s: &str) -> bool {
+    tables::RGI_EMOJI.binary_search(&s).is_ok()
+}
+
+/// Whether a grapheme cluster displays as an emoji. Besides RGI sequences
+/// this accepts what keyboards and models produce in practice, like
+/// unqualified keycaps, ZWJ sequences that aren't recommended, or "🌧"
+/// without VS16 (terminals draw pictographs past U+1F000 as emoji anyway),
+/// but not text-presentation symbols such as a bare "©", "↔" or a digit.
+pub fn is_emoji_cluster(grapheme: &str) -> bool {
+    if is_rgi(grapheme) {
+        return true;
+    }
+
+    let mut chars = grapheme.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let second = chars.next();
+
+    match first {
+        _ if second == Some(TEXT_SELECTOR) => false,
+        '0'..='9' | '#' | '*' => grapheme.contains(KEYCAP),
+        _ if is_regional_indicator(first) => second.is_some_and(is_regional_indicator),
+        _ => {
+            is_emoji(first)
+                && (is_emoji_presentation(first)
+                    || first >= PICTOGRAPH_PLANE && is_extended_pictographic(first)
+                    || second.is_some_and(|ch| ch == EMOJI_SELECTOR || is_emoji_modifier(ch)))
+        }
+    }
+}
+
+/// The grapheme clusters of `s`, i.e. what a reader sees as one character
+pub fn graphemes(s: &str) -> impl Iterator<Item = &str> {
+    s.graphemes(true)
+}
+
+/// The emoji in `s` with their byte offsets, one grapheme cluster each, so
+/// "👩🏽‍💻" is a single emoji rather than four
+pub fn emoji_indices(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.grapheme_indices(true)
+        .filter(|(_, grapheme)| is_emoji_cluster(grapheme))
+}
+
+/// The emoji in `s`, one grapheme cluster each
+pub fn emojis(s: &str) -> impl Iterator<Item = &str> {
+    emoji_indices(s).map(|(_, emoji)| emoji)
+}
+
+/// Columns `s` takes up in a terminal. Emoji are two columns wide however
+/// many code points they are made of.
+pub fn display_width(s: &str) -> usize {
+    s.graphemes(true)
+        .map(|grapheme| if is_emoji_cluster(grapheme) { 2 } else { grapheme.width() })
+        .sum()
+}
+
+/// The emoji with any presentation selector removed, for comparing "❤️"
+/// with "❤"
+pub fn strip_selectors(emoji: &str) -> String {
+    emoji
+        .chars()
+        .filter(|&ch| ch != EMOJI_SELECTOR && ch != TEXT_SELECTOR)
+        .collect()
+}
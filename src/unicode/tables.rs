@@ -0,0 +1,4418 @@
+// Generated by scripts/gen_unicode_tables.py from the Unicode emoji data
+// files. Do not edit by hand; rerun the script to update.
+
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+pub const EMOJI: &[(u32, u32)] = &[
+    (0x23, 0x23),
+    (0x2a, 0x2a),
+    (0x30, 0x39),
+    (0xa9, 0xa9),
+    (0xae, 0xae),
+    (0x203c, 0x203c),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21a9, 0x21aa),
+    (0x231a, 0x231b),
+    (0x2328, 0x2328),
+    (0x23cf, 0x23cf),
+    (0x23e9, 0x23f3),
+    (0x23f8, 0x23fa),
+    (0x24c2, 0x24c2),
+    (0x25aa, 0x25ab),
+    (0x25b6, 0x25b6),
+    (0x25c0, 0x25c0),
+    (0x25fb, 0x25fe),
+    (0x2600, 0x2604),
+    (0x260e, 0x260e),
+    (0x2611, 0x2611),
+    (0x2614, 0x2615),
+    (0x2618, 0x2618),
+    (0x261d, 0x261d),
+    (0x2620, 0x2620),
+    (0x2622, 0x2623),
+    (0x2626, 0x2626),
+    (0x262a, 0x262a),
+    (0x262e, 0x262f),
+    (0x2638, 0x263a),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2648, 0x2653),
+    (0x265f, 0x2660),
+    (0x2663, 0x2663),
+    (0x2665, 0x2666),
+    (0x2668, 0x2668),
+    (0x267b, 0x267b),
+    (0x267e, 0x267f),
+    (0x2692, 0x2697),
+    (0x2699, 0x2699),
+    (0x269b, 0x269c),
+    (0x26a0, 0x26a1),
+    (0x26a7, 0x26a7),
+    (0x26aa, 0x26ab),
+    (0x26b0, 0x26b1),
+    (0x26bd, 0x26be),
+    (0x26c4, 0x26c5),
+    (0x26c8, 0x26c8),
+    (0x26ce, 0x26cf),
+    (0x26d1, 0x26d1),
+    (0x26d3, 0x26d4),
+    (0x26e9, 0x26ea),
+    (0x26f0, 0x26f5),
+    (0x26f7, 0x26fa),
+    (0x26fd, 0x26fd),
+    (0x2702, 0x2702),
+    (0x2705, 0x2705),
+    (0x2708, 0x270d),
+    (0x270f, 0x270f),
+    (0x2712, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271d, 0x271d),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274c, 0x274c),
+    (0x274e, 0x274e),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2764),
+    (0x2795, 0x2797),
+    (0x27a1, 0x27a1),
+    (0x27b0, 0x27b0),
+    (0x27bf, 0x27bf),
+    (0x2934, 0x2935),
+    (0x2b05, 0x2b07),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x3030, 0x3030),
+    (0x303d, 0x303d),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1f004, 0x1f004),
+    (0x1f0cf, 0x1f0cf),
+    (0x1f170, 0x1f171),
+    (0x1f17e, 0x1f17f),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f1e6, 0x1f1ff),
+    (0x1f201, 0x1f202),
+    (0x1f21a, 0x1f21a),
+    (0x1f22f, 0x1f22f),
+    (0x1f232, 0x1f23a),
+    (0x1f250, 0x1f251),
+    (0x1f300, 0x1f321),
+    (0x1f324, 0x1f393),
+    (0x1f396, 0x1f397),
+    (0x1f399, 0x1f39b),
+    (0x1f39e, 0x1f3f0),
+    (0x1f3f3, 0x1f3f5),
+    (0x1f3f7, 0x1f4fd),
+    (0x1f4ff, 0x1f53d),
+    (0x1f549, 0x1f54e),
+    (0x1f550, 0x1f567),
+    (0x1f56f, 0x1f570),
+    (0x1f573, 0x1f57a),
+    (0x1f587, 0x1f587),
+    (0x1f58a, 0x1f58d),
+    (0x1f590, 0x1f590),
+    (0x1f595, 0x1f596),
+    (0x1f5a4, 0x1f5a5),
+    (0x1f5a8, 0x1f5a8),
+    (0x1f5b1, 0x1f5b2),
+    (0x1f5bc, 0x1f5bc),
+    (0x1f5c2, 0x1f5c4),
+    (0x1f5d1, 0x1f5d3),
+    (0x1f5dc, 0x1f5de),
+    (0x1f5e1, 0x1f5e1),
+    (0x1f5e3, 0x1f5e3),
+    (0x1f5e8, 0x1f5e8),
+    (0x1f5ef, 0x1f5ef),
+    (0x1f5f3, 0x1f5f3),
+    (0x1f5fa, 0x1f64f),
+    (0x1f680, 0x1f6c5),
+    (0x1f6cb, 0x1f6d2),
+    (0x1f6d5, 0x1f6d8),
+    (0x1f6dc, 0x1f6e5),
+    (0x1f6e9, 0x1f6e9),
+    (0x1f6eb, 0x1f6ec),
+    (0x1f6f0, 0x1f6f0),
+    (0x1f6f3, 0x1f6fc),
+    (0x1f7e0, 0x1f7eb),
+    (0x1f7f0, 0x1f7f0),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1f9ff),
+    (0x1fa70, 0x1fa7c),
+    (0x1fa80, 0x1fa8a),
+    (0x1fa8e, 0x1fac6),
+    (0x1fac8, 0x1fac8),
+    (0x1facd, 0x1fadc),
+    (0x1fadf, 0x1faea),
+    (0x1faef, 0x1faf8),
+];
+
+pub const EMOJI_PRESENTATION: &[(u32, u32)] = &[
+    (0x231a, 0x231b),
+    (0x23e9, 0x23ec),
+    (0x23f0, 0x23f0),
+    (0x23f3, 0x23f3),
+    (0x25fd, 0x25fe),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267f, 0x267f),
+    (0x2693, 0x2693),
+    (0x26a1, 0x26a1),
+    (0x26aa, 0x26ab),
+    (0x26bd, 0x26be),
+    (0x26c4, 0x26c5),
+    (0x26ce, 0x26ce),
+    (0x26d4, 0x26d4),
+    (0x26ea, 0x26ea),
+    (0x26f2, 0x26f3),
+    (0x26f5, 0x26f5),
+    (0x26fa, 0x26fa),
+    (0x26fd, 0x26fd),
+    (0x2705, 0x2705),
+    (0x270a, 0x270b),
+    (0x2728, 0x2728),
+    (0x274c, 0x274c),
+    (0x274e, 0x274e),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27b0, 0x27b0),
+    (0x27bf, 0x27bf),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x1f004, 0x1f004),
+    (0x1f0cf, 0x1f0cf),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f1e6, 0x1f1ff),
+    (0x1f201, 0x1f201),
+    (0x1f21a, 0x1f21a),
+    (0x1f22f, 0x1f22f),
+    (0x1f232, 0x1f236),
+    (0x1f238, 0x1f23a),
+    (0x1f250, 0x1f251),
+    (0x1f300, 0x1f320),
+    (0x1f32d, 0x1f335),
+    (0x1f337, 0x1f37c),
+    (0x1f37e, 0x1f393),
+    (0x1f3a0, 0x1f3ca),
+    (0x1f3cf, 0x1f3d3),
+    (0x1f3e0, 0x1f3f0),
+    (0x1f3f4, 0x1f3f4),
+    (0x1f3f8, 0x1f43e),
+    (0x1f440, 0x1f440),
+    (0x1f442, 0x1f4fc),
+    (0x1f4ff, 0x1f53d),
+    (0x1f54b, 0x1f54e),
+    (0x1f550, 0x1f567),
+    (0x1f57a, 0x1f57a),
+    (0x1f595, 0x1f596),
+    (0x1f5a4, 0x1f5a4),
+    (0x1f5fb, 0x1f64f),
+    (0x1f680, 0x1f6c5),
+    (0x1f6cc, 0x1f6cc),
+    (0x1f6d0, 0x1f6d2),
+    (0x1f6d5, 0x1f6d8),
+    (0x1f6dc, 0x1f6df),
+    (0x1f6eb, 0x1f6ec),
+    (0x1f6f4, 0x1f6fc),
+    (0x1f7e0, 0x1f7eb),
+    (0x1f7f0, 0x1f7f0),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1f9ff),
+    (0x1fa70, 0x1fa7c),
+    (0x1fa80, 0x1fa8a),
+    (0x1fa8e, 0x1fac6),
+    (0x1fac8, 0x1fac8),
+    (0x1facd, 0x1fadc),
+    (0x1fadf, 0x1faea),
+    (0x1faef, 0x1faf8),
+];
+
+pub const EMOJI_MODIFIER: &[(u32, u32)] = &[
+    (0x1f3fb, 0x1f3ff),
+];
+
+pub const EMOJI_MODIFIER_BASE: &[(u32, u32)] = &[
+    (0x261d, 0x261d),
+    (0x26f9, 0x26f9),
+    (0x270a, 0x270d),
+    (0x1f385, 0x1f385),
+    (0x1f3c2, 0x1f3c4),
+    (0x1f3c7, 0x1f3c7),
+    (0x1f3ca, 0x1f3cc),
+    (0x1f442, 0x1f443),
+    (0x1f446, 0x1f450),
+    (0x1f466, 0x1f478),
+    (0x1f47c, 0x1f47c),
+    (0x1f481, 0x1f483),
+    (0x1f485, 0x1f487),
+    (0x1f48f, 0x1f48f),
+    (0x1f491, 0x1f491),
+    (0x1f4aa, 0x1f4aa),
+    (0x1f574, 0x1f575),
+    (0x1f57a, 0x1f57a),
+    (0x1f590, 0x1f590),
+    (0x1f595, 0x1f596),
+    (0x1f645, 0x1f647),
+    (0x1f64b, 0x1f64f),
+    (0x1f6a3, 0x1f6a3),
+    (0x1f6b4, 0x1f6b6),
+    (0x1f6c0, 0x1f6c0),
+    (0x1f6cc, 0x1f6cc),
+    (0x1f90c, 0x1f90c),
+    (0x1f90f, 0x1f90f),
+    (0x1f918, 0x1f91f),
+    (0x1f926, 0x1f926),
+    (0x1f930, 0x1f939),
+    (0x1f93c, 0x1f93e),
+    (0x1f977, 0x1f977),
+    (0x1f9b5, 0x1f9b6),
+    (0x1f9b8, 0x1f9b9),
+    (0x1f9bb, 0x1f9bb),
+    (0x1f9cd, 0x1f9cf),
+    (0x1f9d1, 0x1f9dd),
+    (0x1fac3, 0x1fac5),
+    (0x1faf0, 0x1faf8),
+];
+
+pub const EMOJI_COMPONENT: &[(u32, u32)] = &[
+    (0x23, 0x23),
+    (0x2a, 0x2a),
+    (0x30, 0x39),
+    (0x200d, 0x200d),
+    (0x20e3, 0x20e3),
+    (0xfe0f, 0xfe0f),
+    (0x1f1e6, 0x1f1ff),
+    (0x1f3fb, 0x1f3ff),
+    (0x1f9b0, 0x1f9b3),
+    (0xe0020, 0xe007f),
+];
+
+pub const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0xa9, 0xa9),
+    (0xae, 0xae),
+    (0x203c, 0x203c),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21a9, 0x21aa),
+    (0x231a, 0x231b),
+    (0x2328, 0x2328),
+    (0x23cf, 0x23cf),
+    (0x23e9, 0x23f3),
+    (0x23f8, 0x23fa),
+    (0x24c2, 0x24c2),
+    (0x25aa, 0x25ab),
+    (0x25b6, 0x25b6),
+    (0x25c0, 0x25c0),
+    (0x25fb, 0x25fe),
+    (0x2600, 0x2604),
+    (0x260e, 0x260e),
+    (0x2611, 0x2611),
+    (0x2614, 0x2615),
+    (0x2618, 0x2618),
+    (0x261d, 0x261d),
+    (0x2620, 0x2620),
+    (0x2622, 0x2623),
+    (0x2626, 0x2626),
+    (0x262a, 0x262a),
+    (0x262e, 0x262f),
+    (0x2638, 0x263a),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2648, 0x2653),
+    (0x265f, 0x2660),
+    (0x2663, 0x2663),
+    (0x2665, 0x2666),
+    (0x2668, 0x2668),
+    (0x267b, 0x267b),
+    (0x267e, 0x267f),
+    (0x2692, 0x2697),
+    (0x2699, 0x2699),
+    (0x269b, 0x269c),
+    (0x26a0, 0x26a1),
+    (0x26a7, 0x26a7),
+    (0x26aa, 0x26ab),
+    (0x26b0, 0x26b1),
+    (0x26bd, 0x26be),
+    (0x26c4, 0x26c5),
+    (0x26c8, 0x26c8),
+    (0x26ce, 0x26cf),
+    (0x26d1, 0x26d1),
+    (0x26d3, 0x26d4),
+    (0x26e9, 0x26ea),
+    (0x26f0, 0x26f5),
+    (0x26f7, 0x26fa),
+    (0x26fd, 0x26fd),
+    (0x2702, 0x2702),
+    (0x2705, 0x2705),
+    (0x2708, 0x270d),
+    (0x270f, 0x270f),
+    (0x2712, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271d, 0x271d),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274c, 0x274c),
+    (0x274e, 0x274e),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2764),
+    (0x2795, 0x2797),
+    (0x27a1, 0x27a1),
+    (0x27b0, 0x27b0),
+    (0x27bf, 0x27bf),
+    (0x2934, 0x2935),
+    (0x2b05, 0x2b07),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x3030, 0x3030),
+    (0x303d, 0x303d),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1f004, 0x1f004),
+    (0x1f02c, 0x1f02f),
+    (0x1f094, 0x1f09f),
+    (0x1f0af, 0x1f0b0),
+    (0x1f0c0, 0x1f0c0),
+    (0x1f0cf, 0x1f0d0),
+    (0x1f0f6, 0x1f0ff),
+    (0x1f170, 0x1f171),
+    (0x1f17e, 0x1f17f),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f1ae, 0x1f1e5),
+    (0x1f201, 0x1f20f),
+    (0x1f21a, 0x1f21a),
+    (0x1f22f, 0x1f22f),
+    (0x1f232, 0x1f23a),
+    (0x1f23c, 0x1f23f),
+    (0x1f249, 0x1f25f),
+    (0x1f266, 0x1f321),
+    (0x1f324, 0x1f393),
+    (0x1f396, 0x1f397),
+    (0x1f399, 0x1f39b),
+    (0x1f39e, 0x1f3f0),
+    (0x1f3f3, 0x1f3f5),
+    (0x1f3f7, 0x1f3fa),
+    (0x1f400, 0x1f4fd),
+    (0x1f4ff, 0x1f53d),
+    (0x1f549, 0x1f54e),
+    (0x1f550, 0x1f567),
+    (0x1f56f, 0x1f570),
+    (0x1f573, 0x1f57a),
+    (0x1f587, 0x1f587),
+    (0x1f58a, 0x1f58d),
+    (0x1f590, 0x1f590),
+    (0x1f595, 0x1f596),
+    (0x1f5a4, 0x1f5a5),
+    (0x1f5a8, 0x1f5a8),
+    (0x1f5b1, 0x1f5b2),
+    (0x1f5bc, 0x1f5bc),
+    (0x1f5c2, 0x1f5c4),
+    (0x1f5d1, 0x1f5d3),
+    (0x1f5dc, 0x1f5de),
+    (0x1f5e1, 0x1f5e1),
+    (0x1f5e3, 0x1f5e3),
+    (0x1f5e8, 0x1f5e8),
+    (0x1f5ef, 0x1f5ef),
+    (0x1f5f3, 0x1f5f3),
+    (0x1f5fa, 0x1f64f),
+    (0x1f680, 0x1f6c5),
+    (0x1f6cb, 0x1f6d2),
+    (0x1f6d5, 0x1f6e5),
+    (0x1f6e9, 0x1f6e9),
+    (0x1f6eb, 0x1f6f0),
+    (0x1f6f3, 0x1f6ff),
+    (0x1f7da, 0x1f7ff),
+    (0x1f80c, 0x1f80f),
+    (0x1f848, 0x1f84f),
+    (0x1f85a, 0x1f85f),
+    (0x1f888, 0x1f88f),
+    (0x1f8ae, 0x1f8af),
+    (0x1f8bc, 0x1f8bf),
+    (0x1f8c2, 0x1f8cf),
+    (0x1f8d9, 0x1f8ff),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1f9ff),
+    (0x1fa58, 0x1fa5f),
+    (0x1fa6e, 0x1faff),
+    (0x1fc00, 0x1fffd),
+];
+
+/// Every RGI emoji sequence, sorted by bytes
+pub const RGI_EMOJI: &[&str] = &[
+    "\u{23}\u{fe0f}\u{20e3}",
+    "\u{2a}\u{fe0f}\u{20e3}",
+    "\u{30}\u{fe0f}\u{20e3}",
+    "\u{31}\u{fe0f}\u{20e3}",
+    "\u{32}\u{fe0f}\u{20e3}",
+    "\u{33}\u{fe0f}\u{20e3}",
+    "\u{34}\u{fe0f}\u{20e3}",
+    "\u{35}\u{fe0f}\u{20e3}",
+    "\u{36}\u{fe0f}\u{20e3}",
+    "\u{37}\u{fe0f}\u{20e3}",
+    "\u{38}\u{fe0f}\u{20e3}",
+    "\u{39}\u{fe0f}\u{20e3}",
+    "\u{a9}\u{fe0f}",
+    "\u{ae}\u{fe0f}",
+    "\u{203c}\u{fe0f}",
+    "\u{2049}\u{fe0f}",
+    "\u{2122}\u{fe0f}",
+    "\u{2139}\u{fe0f}",
+    "\u{2194}\u{fe0f}",
+    "\u{2195}\u{fe0f}",
+    "\u{2196}\u{fe0f}",
+    "\u{2197}\u{fe0f}",
+    "\u{2198}\u{fe0f}",
+    "\u{2199}\u{fe0f}",
+    "\u{21a9}\u{fe0f}",
+    "\u{21aa}\u{fe0f}",
+    "\u{231a}",
+    "\u{231b}",
+    "\u{2328}\u{fe0f}",
+    "\u{23cf}\u{fe0f}",
+    "\u{23e9}",
+    "\u{23ea}",
+    "\u{23eb}",
+    "\u{23ec}",
+    "\u{23ed}\u{fe0f}",
+    "\u{23ee}\u{fe0f}",
+    "\u{23ef}\u{fe0f}",
+    "\u{23f0}",
+    "\u{23f1}\u{fe0f}",
+    "\u{23f2}\u{fe0f}",
+    "\u{23f3}",
+    "\u{23f8}\u{fe0f}",
+    "\u{23f9}\u{fe0f}",
+    "\u{23fa}\u{fe0f}",
+    "\u{24c2}\u{fe0f}",
+    "\u{25aa}\u{fe0f}",
+    "\u{25ab}\u{fe0f}",
+    "\u{25b6}\u{fe0f}",
+    "\u{25c0}\u{fe0f}",
+    "\u{25fb}\u{fe0f}",
+    "\u{25fc}\u{fe0f}",
+    "\u{25fd}",
+    "\u{25fe}",
+    "\u{2600}\u{fe0f}",
+    "\u{2601}\u{fe0f}",
+    "\u{2602}\u{fe0f}",
+    "\u{2603}\u{fe0f}",
+    "\u{2604}\u{fe0f}",
+    "\u{260e}\u{fe0f}",
+    "\u{2611}\u{fe0f}",
+    "\u{2614}",
+    "\u{2615}",
+    "\u{2618}\u{fe0f}",
+    "\u{261d}\u{fe0f}",
+    "\u{261d}\u{1f3fb}",
+    "\u{261d}\u{1f3fc}",
+    "\u{261d}\u{1f3fd}",
+    "\u{261d}\u{1f3fe}",
+    "\u{261d}\u{1f3ff}",
+    "\u{2620}\u{fe0f}",
+    "\u{2622}\u{fe0f}",
+    "\u{2623}\u{fe0f}",
+    "\u{2626}\u{fe0f}",
+    "\u{262a}\u{fe0f}",
+    "\u{262e}\u{fe0f}",
+    "\u{262f}\u{fe0f}",
+    "\u{2638}\u{fe0f}",
+    "\u{2639}\u{fe0f}",
+    "\u{263a}\u{fe0f}",
+    "\u{2640}\u{fe0f}",
+    "\u{2642}\u{fe0f}",
+    "\u{2648}",
+    "\u{2649}",
+    "\u{264a}",
+    "\u{264b}",
+    "\u{264c}",
+    "\u{264d}",
+    "\u{264e}",
+    "\u{264f}",
+    "\u{2650}",
+    "\u{2651}",
+    "\u{2652}",
+    "\u{2653}",
+    "\u{265f}\u{fe0f}",
+    "\u{2660}\u{fe0f}",
+    "\u{2663}\u{fe0f}",
+    "\u{2665}\u{fe0f}",
+    "\u{2666}\u{fe0f}",
+    "\u{2668}\u{fe0f}",
+    "\u{267b}\u{fe0f}",
+    "\u{267e}\u{fe0f}",
+    "\u{267f}",
+    "\u{2692}\u{fe0f}",
+    "\u{2693}",
+    "\u{2694}\u{fe0f}",
+    "\u{2695}\u{fe0f}",
+    "\u{2696}\u{fe0f}",
+    "\u{2697}\u{fe0f}",
+    "\u{2699}\u{fe0f}",
+    "\u{269b}\u{fe0f}",
+    "\u{269c}\u{fe0f}",
+    "\u{26a0}\u{fe0f}",
+    "\u{26a1}",
+    "\u{26a7}\u{fe0f}",
+    "\u{26aa}",
+    "\u{26ab}",
+    "\u{26b0}\u{fe0f}",
+    "\u{26b1}\u{fe0f}",
+    "\u{26bd}",
+    "\u{26be}",
+    "\u{26c4}",
+    "\u{26c5}",
+    "\u{26c8}\u{fe0f}",
+    "\u{26ce}",
+    "\u{26cf}\u{fe0f}",
+    "\u{26d1}\u{fe0f}",
+    "\u{26d3}\u{fe0f}",
+    "\u{26d3}\u{fe0f}\u{200d}\u{1f4a5}",
+    "\u{26d4}",
+    "\u{26e9}\u{fe0f}",
+    "\u{26ea}",
+    "\u{26f0}\u{fe0f}",
+    "\u{26f1}\u{fe0f}",
+    "\u{26f2}",
+    "\u{26f3}",
+    "\u{26f4}\u{fe0f}",
+    "\u{26f5}",
+    "\u{26f7}\u{fe0f}",
+    "\u{26f8}\u{fe0f}",
+    "\u{26f9}\u{fe0f}",
+    "\u{26f9}\u{fe0f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{fe0f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3fb}",
+    "\u{26f9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3fc}",
+    "\u{26f9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3fd}",
+    "\u{26f9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3fe}",
+    "\u{26f9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26f9}\u{1f3ff}",
+    "\u{26f9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{26f9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{26fa}",
+    "\u{26fd}",
+    "\u{2702}\u{fe0f}",
+    "\u{2705}",
+    "\u{2708}\u{fe0f}",
+    "\u{2709}\u{fe0f}",
+    "\u{270a}",
+    "\u{270a}\u{1f3fb}",
+    "\u{270a}\u{1f3fc}",
+    "\u{270a}\u{1f3fd}",
+    "\u{270a}\u{1f3fe}",
+    "\u{270a}\u{1f3ff}",
+    "\u{270b}",
+    "\u{270b}\u{1f3fb}",
+    "\u{270b}\u{1f3fc}",
+    "\u{270b}\u{1f3fd}",
+    "\u{270b}\u{1f3fe}",
+    "\u{270b}\u{1f3ff}",
+    "\u{270c}\u{fe0f}",
+    "\u{270c}\u{1f3fb}",
+    "\u{270c}\u{1f3fc}",
+    "\u{270c}\u{1f3fd}",
+    "\u{270c}\u{1f3fe}",
+    "\u{270c}\u{1f3ff}",
+    "\u{270d}\u{fe0f}",
+    "\u{270d}\u{1f3fb}",
+    "\u{270d}\u{1f3fc}",
+    "\u{270d}\u{1f3fd}",
+    "\u{270d}\u{1f3fe}",
+    "\u{270d}\u{1f3ff}",
+    "\u{270f}\u{fe0f}",
+    "\u{2712}\u{fe0f}",
+    "\u{2714}\u{fe0f}",
+    "\u{2716}\u{fe0f}",
+    "\u{271d}\u{fe0f}",
+    "\u{2721}\u{fe0f}",
+    "\u{2728}",
+    "\u{2733}\u{fe0f}",
+    "\u{2734}\u{fe0f}",
+    "\u{2744}\u{fe0f}",
+    "\u{2747}\u{fe0f}",
+    "\u{274c}",
+    "\u{274e}",
+    "\u{2753}",
+    "\u{2754}",
+    "\u{2755}",
+    "\u{2757}",
+    "\u{2763}\u{fe0f}",
+    "\u{2764}\u{fe0f}",
+    "\u{2764}\u{fe0f}\u{200d}\u{1f525}",
+    "\u{2764}\u{fe0f}\u{200d}\u{1fa79}",
+    "\u{2795}",
+    "\u{2796}",
+    "\u{2797}",
+    "\u{27a1}\u{fe0f}",
+    "\u{27b0}",
+    "\u{27bf}",
+    "\u{2934}\u{fe0f}",
+    "\u{2935}\u{fe0f}",
+    "\u{2b05}\u{fe0f}",
+    "\u{2b06}\u{fe0f}",
+    "\u{2b07}\u{fe0f}",
+    "\u{2b1b}",
+    "\u{2b1c}",
+    "\u{2b50}",
+    "\u{2b55}",
+    "\u{3030}\u{fe0f}",
+    "\u{303d}\u{fe0f}",
+    "\u{3297}\u{fe0f}",
+    "\u{3299}\u{fe0f}",
+    "\u{1f004}",
+    "\u{1f0cf}",
+    "\u{1f170}\u{fe0f}",
+    "\u{1f171}\u{fe0f}",
+    "\u{1f17e}\u{fe0f}",
+    "\u{1f17f}\u{fe0f}",
+    "\u{1f18e}",
+    "\u{1f191}",
+    "\u{1f192}",
+    "\u{1f193}",
+    "\u{1f194}",
+    "\u{1f195}",
+    "\u{1f196}",
+    "\u{1f197}",
+    "\u{1f198}",
+    "\u{1f199}",
+    "\u{1f19a}",
+    "\u{1f1e6}\u{1f1e8}",
+    "\u{1f1e6}\u{1f1e9}",
+    "\u{1f1e6}\u{1f1ea}",
+    "\u{1f1e6}\u{1f1eb}",
+    "\u{1f1e6}\u{1f1ec}",
+    "\u{1f1e6}\u{1f1ee}",
+    "\u{1f1e6}\u{1f1f1}",
+    "\u{1f1e6}\u{1f1f2}",
+    "\u{1f1e6}\u{1f1f4}",
+    "\u{1f1e6}\u{1f1f6}",
+    "\u{1f1e6}\u{1f1f7}",
+    "\u{1f1e6}\u{1f1f8}",
+    "\u{1f1e6}\u{1f1f9}",
+    "\u{1f1e6}\u{1f1fa}",
+    "\u{1f1e6}\u{1f1fc}",
+    "\u{1f1e6}\u{1f1fd}",
+    "\u{1f1e6}\u{1f1ff}",
+    "\u{1f1e7}\u{1f1e6}",
+    "\u{1f1e7}\u{1f1e7}",
+    "\u{1f1e7}\u{1f1e9}",
+    "\u{1f1e7}\u{1f1ea}",
+    "\u{1f1e7}\u{1f1eb}",
+    "\u{1f1e7}\u{1f1ec}",
+    "\u{1f1e7}\u{1f1ed}",
+    "\u{1f1e7}\u{1f1ee}",
+    "\u{1f1e7}\u{1f1ef}",
+    "\u{1f1e7}\u{1f1f1}",
+    "\u{1f1e7}\u{1f1f2}",
+    "\u{1f1e7}\u{1f1f3}",
+    "\u{1f1e7}\u{1f1f4}",
+    "\u{1f1e7}\u{1f1f6}",
+    "\u{1f1e7}\u{1f1f7}",
+    "\u{1f1e7}\u{1f1f8}",
+    "\u{1f1e7}\u{1f1f9}",
+    "\u{1f1e7}\u{1f1fb}",
+    "\u{1f1e7}\u{1f1fc}",
+    "\u{1f1e7}\u{1f1fe}",
+    "\u{1f1e7}\u{1f1ff}",
+    "\u{1f1e8}\u{1f1e6}",
+    "\u{1f1e8}\u{1f1e8}",
+    "\u{1f1e8}\u{1f1e9}",
+    "\u{1f1e8}\u{1f1eb}",
+    "\u{1f1e8}\u{1f1ec}",
+    "\u{1f1e8}\u{1f1ed}",
+    "\u{1f1e8}\u{1f1ee}",
+    "\u{1f1e8}\u{1f1f0}",
+    "\u{1f1e8}\u{1f1f1}",
+    "\u{1f1e8}\u{1f1f2}",
+    "\u{1f1e8}\u{1f1f3}",
+    "\u{1f1e8}\u{1f1f4}",
+    "\u{1f1e8}\u{1f1f5}",
+    "\u{1f1e8}\u{1f1f6}",
+    "\u{1f1e8}\u{1f1f7}",
+    "\u{1f1e8}\u{1f1fa}",
+    "\u{1f1e8}\u{1f1fb}",
+    "\u{1f1e8}\u{1f1fc}",
+    "\u{1f1e8}\u{1f1fd}",
+    "\u{1f1e8}\u{1f1fe}",
+    "\u{1f1e8}\u{1f1ff}",
+    "\u{1f1e9}\u{1f1ea}",
+    "\u{1f1e9}\u{1f1ec}",
+    "\u{1f1e9}\u{1f1ef}",
+    "\u{1f1e9}\u{1f1f0}",
+    "\u{1f1e9}\u{1f1f2}",
+    "\u{1f1e9}\u{1f1f4}",
+    "\u{1f1e9}\u{1f1ff}",
+    "\u{1f1ea}\u{1f1e6}",
+    "\u{1f1ea}\u{1f1e8}",
+    "\u{1f1ea}\u{1f1ea}",
+    "\u{1f1ea}\u{1f1ec}",
+    "\u{1f1ea}\u{1f1ed}",
+    "\u{1f1ea}\u{1f1f7}",
+    "\u{1f1ea}\u{1f1f8}",
+    "\u{1f1ea}\u{1f1f9}",
+    "\u{1f1ea}\u{1f1fa}",
+    "\u{1f1eb}\u{1f1ee}",
+    "\u{1f1eb}\u{1f1ef}",
+    "\u{1f1eb}\u{1f1f0}",
+    "\u{1f1eb}\u{1f1f2}",
+    "\u{1f1eb}\u{1f1f4}",
+    "\u{1f1eb}\u{1f1f7}",
+    "\u{1f1ec}\u{1f1e6}",
+    "\u{1f1ec}\u{1f1e7}",
+    "\u{1f1ec}\u{1f1e9}",
+    "\u{1f1ec}\u{1f1ea}",
+    "\u{1f1ec}\u{1f1eb}",
+    "\u{1f1ec}\u{1f1ec}",
+    "\u{1f1ec}\u{1f1ed}",
+    "\u{1f1ec}\u{1f1ee}",
+    "\u{1f1ec}\u{1f1f1}",
+    "\u{1f1ec}\u{1f1f2}",
+    "\u{1f1ec}\u{1f1f3}",
+    "\u{1f1ec}\u{1f1f5}",
+    "\u{1f1ec}\u{1f1f6}",
+    "\u{1f1ec}\u{1f1f7}",
+    "\u{1f1ec}\u{1f1f8}",
+    "\u{1f1ec}\u{1f1f9}",
+    "\u{1f1ec}\u{1f1fa}",
+    "\u{1f1ec}\u{1f1fc}",
+    "\u{1f1ec}\u{1f1fe}",
+    "\u{1f1ed}\u{1f1f0}",
+    "\u{1f1ed}\u{1f1f2}",
+    "\u{1f1ed}\u{1f1f3}",
+    "\u{1f1ed}\u{1f1f7}",
+    "\u{1f1ed}\u{1f1f9}",
+    "\u{1f1ed}\u{1f1fa}",
+    "\u{1f1ee}\u{1f1e8}",
+    "\u{1f1ee}\u{1f1e9}",
+    "\u{1f1ee}\u{1f1ea}",
+    "\u{1f1ee}\u{1f1f1}",
+    "\u{1f1ee}\u{1f1f2}",
+    "\u{1f1ee}\u{1f1f3}",
+    "\u{1f1ee}\u{1f1f4}",
+    "\u{1f1ee}\u{1f1f6}",
+    "\u{1f1ee}\u{1f1f7}",
+    "\u{1f1ee}\u{1f1f8}",
+    "\u{1f1ee}\u{1f1f9}",
+    "\u{1f1ef}\u{1f1ea}",
+    "\u{1f1ef}\u{1f1f2}",
+    "\u{1f1ef}\u{1f1f4}",
+    "\u{1f1ef}\u{1f1f5}",
+    "\u{1f1f0}\u{1f1ea}",
+    "\u{1f1f0}\u{1f1ec}",
+    "\u{1f1f0}\u{1f1ed}",
+    "\u{1f1f0}\u{1f1ee}",
+    "\u{1f1f0}\u{1f1f2}",
+    "\u{1f1f0}\u{1f1f3}",
+    "\u{1f1f0}\u{1f1f5}",
+    "\u{1f1f0}\u{1f1f7}",
+    "\u{1f1f0}\u{1f1fc}",
+    "\u{1f1f0}\u{1f1fe}",
+    "\u{1f1f0}\u{1f1ff}",
+    "\u{1f1f1}\u{1f1e6}",
+    "\u{1f1f1}\u{1f1e7}",
+    "\u{1f1f1}\u{1f1e8}",
+    "\u{1f1f1}\u{1f1ee}",
+    "\u{1f1f1}\u{1f1f0}",
+    "\u{1f1f1}\u{1f1f7}",
+    "\u{1f1f1}\u{1f1f8}",
+    "\u{1f1f1}\u{1f1f9}",
+    "\u{1f1f1}\u{1f1fa}",
+    "\u{1f1f1}\u{1f1fb}",
+    "\u{1f1f1}\u{1f1fe}",
+    "\u{1f1f2}\u{1f1e6}",
+    "\u{1f1f2}\u{1f1e8}",
+    "\u{1f1f2}\u{1f1e9}",
+    "\u{1f1f2}\u{1f1ea}",
+    "\u{1f1f2}\u{1f1eb}",
+    "\u{1f1f2}\u{1f1ec}",
+    "\u{1f1f2}\u{1f1ed}",
+    "\u{1f1f2}\u{1f1f0}",
+    "\u{1f1f2}\u{1f1f1}",
+    "\u{1f1f2}\u{1f1f2}",
+    "\u{1f1f2}\u{1f1f3}",
+    "\u{1f1f2}\u{1f1f4}",
+    "\u{1f1f2}\u{1f1f5}",
+    "\u{1f1f2}\u{1f1f6}",
+    "\u{1f1f2}\u{1f1f7}",
+    "\u{1f1f2}\u{1f1f8}",
+    "\u{1f1f2}\u{1f1f9}",
+    "\u{1f1f2}\u{1f1fa}",
+    "\u{1f1f2}\u{1f1fb}",
+    "\u{1f1f2}\u{1f1fc}",
+    "\u{1f1f2}\u{1f1fd}",
+    "\u{1f1f2}\u{1f1fe}",
+    "\u{1f1f2}\u{1f1ff}",
+    "\u{1f1f3}\u{1f1e6}",
+    "\u{1f1f3}\u{1f1e8}",
+    "\u{1f1f3}\u{1f1ea}",
+    "\u{1f1f3}\u{1f1eb}",
+    "\u{1f1f3}\u{1f1ec}",
+    "\u{1f1f3}\u{1f1ee}",
+    "\u{1f1f3}\u{1f1f1}",
+    "\u{1f1f3}\u{1f1f4}",
+    "\u{1f1f3}\u{1f1f5}",
+    "\u{1f1f3}\u{1f1f7}",
+    "\u{1f1f3}\u{1f1fa}",
+    "\u{1f1f3}\u{1f1ff}",
+    "\u{1f1f4}\u{1f1f2}",
+    "\u{1f1f5}\u{1f1e6}",
+    "\u{1f1f5}\u{1f1ea}",
+    "\u{1f1f5}\u{1f1eb}",
+    "\u{1f1f5}\u{1f1ec}",
+    "\u{1f1f5}\u{1f1ed}",
+    "\u{1f1f5}\u{1f1f0}",
+    "\u{1f1f5}\u{1f1f1}",
+    "\u{1f1f5}\u{1f1f2}",
+    "\u{1f1f5}\u{1f1f3}",
+    "\u{1f1f5}\u{1f1f7}",
+    "\u{1f1f5}\u{1f1f8}",
+    "\u{1f1f5}\u{1f1f9}",
+    "\u{1f1f5}\u{1f1fc}",
+    "\u{1f1f5}\u{1f1fe}",
+    "\u{1f1f6}\u{1f1e6}",
+    "\u{1f1f7}\u{1f1ea}",
+    "\u{1f1f7}\u{1f1f4}",
+    "\u{1f1f7}\u{1f1f8}",
+    "\u{1f1f7}\u{1f1fa}",
+    "\u{1f1f7}\u{1f1fc}",
+    "\u{1f1f8}\u{1f1e6}",
+    "\u{1f1f8}\u{1f1e7}",
+    "\u{1f1f8}\u{1f1e8}",
+    "\u{1f1f8}\u{1f1e9}",
+    "\u{1f1f8}\u{1f1ea}",
+    "\u{1f1f8}\u{1f1ec}",
+    "\u{1f1f8}\u{1f1ed}",
+    "\u{1f1f8}\u{1f1ee}",
+    "\u{1f1f8}\u{1f1ef}",
+    "\u{1f1f8}\u{1f1f0}",
+    "\u{1f1f8}\u{1f1f1}",
+    "\u{1f1f8}\u{1f1f2}",
+    "\u{1f1f8}\u{1f1f3}",
+    "\u{1f1f8}\u{1f1f4}",
+    "\u{1f1f8}\u{1f1f7}",
+    "\u{1f1f8}\u{1f1f8}",
+    "\u{1f1f8}\u{1f1f9}",
+    "\u{1f1f8}\u{1f1fb}",
+    "\u{1f1f8}\u{1f1fd}",
+    "\u{1f1f8}\u{1f1fe}",
+    "\u{1f1f8}\u{1f1ff}",
+    "\u{1f1f9}\u{1f1e6}",
+    "\u{1f1f9}\u{1f1e8}",
+    "\u{1f1f9}\u{1f1e9}",
+    "\u{1f1f9}\u{1f1eb}",
+    "\u{1f1f9}\u{1f1ec}",
+    "\u{1f1f9}\u{1f1ed}",
+    "\u{1f1f9}\u{1f1ef}",
+    "\u{1f1f9}\u{1f1f0}",
+    "\u{1f1f9}\u{1f1f1}",
+    "\u{1f1f9}\u{1f1f2}",
+    "\u{1f1f9}\u{1f1f3}",
+    "\u{1f1f9}\u{1f1f4}",
+    "\u{1f1f9}\u{1f1f7}",
+    "\u{1f1f9}\u{1f1f9}",
+    "\u{1f1f9}\u{1f1fb}",
+    "\u{1f1f9}\u{1f1fc}",
+    "\u{1f1f9}\u{1f1ff}",
+    "\u{1f1fa}\u{1f1e6}",
+    "\u{1f1fa}\u{1f1ec}",
+    "\u{1f1fa}\u{1f1f2}",
+    "\u{1f1fa}\u{1f1f3}",
+    "\u{1f1fa}\u{1f1f8}",
+    "\u{1f1fa}\u{1f1fe}",
+    "\u{1f1fa}\u{1f1ff}",
+    "\u{1f1fb}\u{1f1e6}",
+    "\u{1f1fb}\u{1f1e8}",
+    "\u{1f1fb}\u{1f1ea}",
+    "\u{1f1fb}\u{1f1ec}",
+    "\u{1f1fb}\u{1f1ee}",
+    "\u{1f1fb}\u{1f1f3}",
+    "\u{1f1fb}\u{1f1fa}",
+    "\u{1f1fc}\u{1f1eb}",
+    "\u{1f1fc}\u{1f1f8}",
+    "\u{1f1fd}\u{1f1f0}",
+    "\u{1f1fe}\u{1f1ea}",
+    "\u{1f1fe}\u{1f1f9}",
+    "\u{1f1ff}\u{1f1e6}",
+    "\u{1f1ff}\u{1f1f2}",
+    "\u{1f1ff}\u{1f1fc}",
+    "\u{1f201}",
+    "\u{1f202}\u{fe0f}",
+    "\u{1f21a}",
+    "\u{1f22f}",
+    "\u{1f232}",
+    "\u{1f233}",
+    "\u{1f234}",
+    "\u{1f235}",
+    "\u{1f236}",
+    "\u{1f237}\u{fe0f}",
+    "\u{1f238}",
+    "\u{1f239}",
+    "\u{1f23a}",
+    "\u{1f250}",
+    "\u{1f251}",
+    "\u{1f300}",
+    "\u{1f301}",
+    "\u{1f302}",
+    "\u{1f303}",
+    "\u{1f304}",
+    "\u{1f305}",
+    "\u{1f306}",
+    "\u{1f307}",
+    "\u{1f308}",
+    "\u{1f309}",
+    "\u{1f30a}",
+    "\u{1f30b}",
+    "\u{1f30c}",
+    "\u{1f30d}",
+    "\u{1f30e}",
+    "\u{1f30f}",
+    "\u{1f310}",
+    "\u{1f311}",
+    "\u{1f312}",
+    "\u{1f313}",
+    "\u{1f314}",
+    "\u{1f315}",
+    "\u{1f316}",
+    "\u{1f317}",
+    "\u{1f318}",
+    "\u{1f319}",
+    "\u{1f31a}",
+    "\u{1f31b}",
+    "\u{1f31c}",
+    "\u{1f31d}",
+    "\u{1f31e}",
+    "\u{1f31f}",
+    "\u{1f320}",
+    "\u{1f321}\u{fe0f}",
+    "\u{1f324}\u{fe0f}",
+    "\u{1f325}\u{fe0f}",
+    "\u{1f326}\u{fe0f}",
+    "\u{1f327}\u{fe0f}",
+    "\u{1f328}\u{fe0f}",
+    "\u{1f329}\u{fe0f}",
+    "\u{1f32a}\u{fe0f}",
+    "\u{1f32b}\u{fe0f}",
+    "\u{1f32c}\u{fe0f}",
+    "\u{1f32d}",
+    "\u{1f32e}",
+    "\u{1f32f}",
+    "\u{1f330}",
+    "\u{1f331}",
+    "\u{1f332}",
+    "\u{1f333}",
+    "\u{1f334}",
+    "\u{1f335}",
+    "\u{1f336}\u{fe0f}",
+    "\u{1f337}",
+    "\u{1f338}",
+    "\u{1f339}",
+    "\u{1f33a}",
+    "\u{1f33b}",
+    "\u{1f33c}",
+    "\u{1f33d}",
+    "\u{1f33e}",
+    "\u{1f33f}",
+    "\u{1f340}",
+    "\u{1f341}",
+    "\u{1f342}",
+    "\u{1f343}",
+    "\u{1f344}",
+    "\u{1f344}\u{200d}\u{1f7eb}",
+    "\u{1f345}",
+    "\u{1f346}",
+    "\u{1f347}",
+    "\u{1f348}",
+    "\u{1f349}",
+    "\u{1f34a}",
+    "\u{1f34b}",
+    "\u{1f34b}\u{200d}\u{1f7e9}",
+    "\u{1f34c}",
+    "\u{1f34d}",
+    "\u{1f34e}",
+    "\u{1f34f}",
+    "\u{1f350}",
+    "\u{1f351}",
+    "\u{1f352}",
+    "\u{1f353}",
+    "\u{1f354}",
+    "\u{1f355}",
+    "\u{1f356}",
+    "\u{1f357}",
+    "\u{1f358}",
+    "\u{1f359}",
+    "\u{1f35a}",
+    "\u{1f35b}",
+    "\u{1f35c}",
+    "\u{1f35d}",
+    "\u{1f35e}",
+    "\u{1f35f}",
+    "\u{1f360}",
+    "\u{1f361}",
+    "\u{1f362}",
+    "\u{1f363}",
+    "\u{1f364}",
+    "\u{1f365}",
+    "\u{1f366}",
+    "\u{1f367}",
+    "\u{1f368}",
+    "\u{1f369}",
+    "\u{1f36a}",
+    "\u{1f36b}",
+    "\u{1f36c}",
+    "\u{1f36d}",
+    "\u{1f36e}",
+    "\u{1f36f}",
+    "\u{1f370}",
+    "\u{1f371}",
+    "\u{1f372}",
+    "\u{1f373}",
+    "\u{1f374}",
+    "\u{1f375}",
+    "\u{1f376}",
+    "\u{1f377}",
+    "\u{1f378}",
+    "\u{1f379}",
+    "\u{1f37a}",
+    "\u{1f37b}",
+    "\u{1f37c}",
+    "\u{1f37d}\u{fe0f}",
+    "\u{1f37e}",
+    "\u{1f37f}",
+    "\u{1f380}",
+    "\u{1f381}",
+    "\u{1f382}",
+    "\u{1f383}",
+    "\u{1f384}",
+    "\u{1f385}",
+    "\u{1f385}\u{1f3fb}",
+    "\u{1f385}\u{1f3fc}",
+    "\u{1f385}\u{1f3fd}",
+    "\u{1f385}\u{1f3fe}",
+    "\u{1f385}\u{1f3ff}",
+    "\u{1f386}",
+    "\u{1f387}",
+    "\u{1f388}",
+    "\u{1f389}",
+    "\u{1f38a}",
+    "\u{1f38b}",
+    "\u{1f38c}",
+    "\u{1f38d}",
+    "\u{1f38e}",
+    "\u{1f38f}",
+    "\u{1f390}",
+    "\u{1f391}",
+    "\u{1f392}",
+    "\u{1f393}",
+    "\u{1f396}\u{fe0f}",
+    "\u{1f397}\u{fe0f}",
+    "\u{1f399}\u{fe0f}",
+    "\u{1f39a}\u{fe0f}",
+    "\u{1f39b}\u{fe0f}",
+    "\u{1f39e}\u{fe0f}",
+    "\u{1f39f}\u{fe0f}",
+    "\u{1f3a0}",
+    "\u{1f3a1}",
+    "\u{1f3a2}",
+    "\u{1f3a3}",
+    "\u{1f3a4}",
+    "\u{1f3a5}",
+    "\u{1f3a6}",
+    "\u{1f3a7}",
+    "\u{1f3a8}",
+    "\u{1f3a9}",
+    "\u{1f3aa}",
+    "\u{1f3ab}",
+    "\u{1f3ac}",
+    "\u{1f3ad}",
+    "\u{1f3ae}",
+    "\u{1f3af}",
+    "\u{1f3b0}",
+    "\u{1f3b1}",
+    "\u{1f3b2}",
+    "\u{1f3b3}",
+    "\u{1f3b4}",
+    "\u{1f3b5}",
+    "\u{1f3b6}",
+    "\u{1f3b7}",
+    "\u{1f3b8}",
+    "\u{1f3b9}",
+    "\u{1f3ba}",
+    "\u{1f3bb}",
+    "\u{1f3bc}",
+    "\u{1f3bd}",
+    "\u{1f3be}",
+    "\u{1f3bf}",
+    "\u{1f3c0}",
+    "\u{1f3c1}",
+    "\u{1f3c2}",
+    "\u{1f3c2}\u{1f3fb}",
+    "\u{1f3c2}\u{1f3fc}",
+    "\u{1f3c2}\u{1f3fd}",
+    "\u{1f3c2}\u{1f3fe}",
+    "\u{1f3c2}\u{1f3ff}",
+    "\u{1f3c3}",
+    "\u{1f3c3}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fb}",
+    "\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fc}",
+    "\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fd}",
+    "\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fe}",
+    "\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3ff}",
+    "\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c3}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f3c4}",
+    "\u{1f3c4}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fb}",
+    "\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fc}",
+    "\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fd}",
+    "\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fe}",
+    "\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c4}\u{1f3ff}",
+    "\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3c5}",
+    "\u{1f3c6}",
+    "\u{1f3c7}",
+    "\u{1f3c7}\u{1f3fb}",
+    "\u{1f3c7}\u{1f3fc}",
+    "\u{1f3c7}\u{1f3fd}",
+    "\u{1f3c7}\u{1f3fe}",
+    "\u{1f3c7}\u{1f3ff}",
+    "\u{1f3c8}",
+    "\u{1f3c9}",
+    "\u{1f3ca}",
+    "\u{1f3ca}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fb}",
+    "\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fc}",
+    "\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fd}",
+    "\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fe}",
+    "\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3ca}\u{1f3ff}",
+    "\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{fe0f}",
+    "\u{1f3cb}\u{fe0f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{fe0f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fb}",
+    "\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fc}",
+    "\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fd}",
+    "\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fe}",
+    "\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cb}\u{1f3ff}",
+    "\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{fe0f}",
+    "\u{1f3cc}\u{fe0f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{fe0f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fb}",
+    "\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fc}",
+    "\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fd}",
+    "\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fe}",
+    "\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cc}\u{1f3ff}",
+    "\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f3cd}\u{fe0f}",
+    "\u{1f3ce}\u{fe0f}",
+    "\u{1f3cf}",
+    "\u{1f3d0}",
+    "\u{1f3d1}",
+    "\u{1f3d2}",
+    "\u{1f3d3}",
+    "\u{1f3d4}\u{fe0f}",
+    "\u{1f3d5}\u{fe0f}",
+    "\u{1f3d6}\u{fe0f}",
+    "\u{1f3d7}\u{fe0f}",
+    "\u{1f3d8}\u{fe0f}",
+    "\u{1f3d9}\u{fe0f}",
+    "\u{1f3da}\u{fe0f}",
+    "\u{1f3db}\u{fe0f}",
+    "\u{1f3dc}\u{fe0f}",
+    "\u{1f3dd}\u{fe0f}",
+    "\u{1f3de}\u{fe0f}",
+    "\u{1f3df}\u{fe0f}",
+    "\u{1f3e0}",
+    "\u{1f3e1}",
+    "\u{1f3e2}",
+    "\u{1f3e3}",
+    "\u{1f3e4}",
+    "\u{1f3e5}",
+    "\u{1f3e6}",
+    "\u{1f3e7}",
+    "\u{1f3e8}",
+    "\u{1f3e9}",
+    "\u{1f3ea}",
+    "\u{1f3eb}",
+    "\u{1f3ec}",
+    "\u{1f3ed}",
+    "\u{1f3ee}",
+    "\u{1f3ef}",
+    "\u{1f3f0}",
+    "\u{1f3f3}\u{fe0f}",
+    "\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}",
+    "\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}",
+    "\u{1f3f4}",
+    "\u{1f3f4}\u{200d}\u{2620}\u{fe0f}",
+    "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}",
+    "\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}",
+    "\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}",
+    "\u{1f3f5}\u{fe0f}",
+    "\u{1f3f7}\u{fe0f}",
+    "\u{1f3f8}",
+    "\u{1f3f9}",
+    "\u{1f3fa}",
+    "\u{1f3fb}",
+    "\u{1f3fc}",
+    "\u{1f3fd}",
+    "\u{1f3fe}",
+    "\u{1f3ff}",
+    "\u{1f400}",
+    "\u{1f401}",
+    "\u{1f402}",
+    "\u{1f403}",
+    "\u{1f404}",
+    "\u{1f405}",
+    "\u{1f406}",
+    "\u{1f407}",
+    "\u{1f408}",
+    "\u{1f408}\u{200d}\u{2b1b}",
+    "\u{1f409}",
+    "\u{1f40a}",
+    "\u{1f40b}",
+    "\u{1f40c}",
+    "\u{1f40d}",
+    "\u{1f40e}",
+    "\u{1f40f}",
+    "\u{1f410}",
+    "\u{1f411}",
+    "\u{1f412}",
+    "\u{1f413}",
+    "\u{1f414}",
+    "\u{1f415}",
+    "\u{1f415}\u{200d}\u{1f9ba}",
+    "\u{1f416}",
+    "\u{1f417}",
+    "\u{1f418}",
+    "\u{1f419}",
+    "\u{1f41a}",
+    "\u{1f41b}",
+    "\u{1f41c}",
+    "\u{1f41d}",
+    "\u{1f41e}",
+    "\u{1f41f}",
+    "\u{1f420}",
+    "\u{1f421}",
+    "\u{1f422}",
+    "\u{1f423}",
+    "\u{1f424}",
+    "\u{1f425}",
+    "\u{1f426}",
+    "\u{1f426}\u{200d}\u{2b1b}",
+    "\u{1f426}\u{200d}\u{1f525}",
+    "\u{1f427}",
+    "\u{1f428}",
+    "\u{1f429}",
+    "\u{1f42a}",
+    "\u{1f42b}",
+    "\u{1f42c}",
+    "\u{1f42d}",
+    "\u{1f42e}",
+    "\u{1f42f}",
+    "\u{1f430}",
+    "\u{1f431}",
+    "\u{1f432}",
+    "\u{1f433}",
+    "\u{1f434}",
+    "\u{1f435}",
+    "\u{1f436}",
+    "\u{1f437}",
+    "\u{1f438}",
+    "\u{1f439}",
+    "\u{1f43a}",
+    "\u{1f43b}",
+    "\u{1f43b}\u{200d}\u{2744}\u{fe0f}",
+    "\u{1f43c}",
+    "\u{1f43d}",
+    "\u{1f43e}",
+    "\u{1f43f}\u{fe0f}",
+    "\u{1f440}",
+    "\u{1f441}\u{fe0f}",
+    "\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}",
+    "\u{1f442}",
+    "\u{1f442}\u{1f3fb}",
+    "\u{1f442}\u{1f3fc}",
+    "\u{1f442}\u{1f3fd}",
+    "\u{1f442}\u{1f3fe}",
+    "\u{1f442}\u{1f3ff}",
+    "\u{1f443}",
+    "\u{1f443}\u{1f3fb}",
+    "\u{1f443}\u{1f3fc}",
+    "\u{1f443}\u{1f3fd}",
+    "\u{1f443}\u{1f3fe}",
+    "\u{1f443}\u{1f3ff}",
+    "\u{1f444}",
+    "\u{1f445}",
+    "\u{1f446}",
+    "\u{1f446}\u{1f3fb}",
+    "\u{1f446}\u{1f3fc}",
+    "\u{1f446}\u{1f3fd}",
+    "\u{1f446}\u{1f3fe}",
+    "\u{1f446}\u{1f3ff}",
+    "\u{1f447}",
+    "\u{1f447}\u{1f3fb}",
+    "\u{1f447}\u{1f3fc}",
+    "\u{1f447}\u{1f3fd}",
+    "\u{1f447}\u{1f3fe}",
+    "\u{1f447}\u{1f3ff}",
+    "\u{1f448}",
+    "\u{1f448}\u{1f3fb}",
+    "\u{1f448}\u{1f3fc}",
+    "\u{1f448}\u{1f3fd}",
+    "\u{1f448}\u{1f3fe}",
+    "\u{1f448}\u{1f3ff}",
+    "\u{1f449}",
+    "\u{1f449}\u{1f3fb}",
+    "\u{1f449}\u{1f3fc}",
+    "\u{1f449}\u{1f3fd}",
+    "\u{1f449}\u{1f3fe}",
+    "\u{1f449}\u{1f3ff}",
+    "\u{1f44a}",
+    "\u{1f44a}\u{1f3fb}",
+    "\u{1f44a}\u{1f3fc}",
+    "\u{1f44a}\u{1f3fd}",
+    "\u{1f44a}\u{1f3fe}",
+    "\u{1f44a}\u{1f3ff}",
+    "\u{1f44b}",
+    "\u{1f44b}\u{1f3fb}",
+    "\u{1f44b}\u{1f3fc}",
+    "\u{1f44b}\u{1f3fd}",
+    "\u{1f44b}\u{1f3fe}",
+    "\u{1f44b}\u{1f3ff}",
+    "\u{1f44c}",
+    "\u{1f44c}\u{1f3fb}",
+    "\u{1f44c}\u{1f3fc}",
+    "\u{1f44c}\u{1f3fd}",
+    "\u{1f44c}\u{1f3fe}",
+    "\u{1f44c}\u{1f3ff}",
+    "\u{1f44d}",
+    "\u{1f44d}\u{1f3fb}",
+    "\u{1f44d}\u{1f3fc}",
+    "\u{1f44d}\u{1f3fd}",
+    "\u{1f44d}\u{1f3fe}",
+    "\u{1f44d}\u{1f3ff}",
+    "\u{1f44e}",
+    "\u{1f44e}\u{1f3fb}",
+    "\u{1f44e}\u{1f3fc}",
+    "\u{1f44e}\u{1f3fd}",
+    "\u{1f44e}\u{1f3fe}",
+    "\u{1f44e}\u{1f3ff}",
+    "\u{1f44f}",
+    "\u{1f44f}\u{1f3fb}",
+    "\u{1f44f}\u{1f3fc}",
+    "\u{1f44f}\u{1f3fd}",
+    "\u{1f44f}\u{1f3fe}",
+    "\u{1f44f}\u{1f3ff}",
+    "\u{1f450}",
+    "\u{1f450}\u{1f3fb}",
+    "\u{1f450}\u{1f3fc}",
+    "\u{1f450}\u{1f3fd}",
+    "\u{1f450}\u{1f3fe}",
+    "\u{1f450}\u{1f3ff}",
+    "\u{1f451}",
+    "\u{1f452}",
+    "\u{1f453}",
+    "\u{1f454}",
+    "\u{1f455}",
+    "\u{1f456}",
+    "\u{1f457}",
+    "\u{1f458}",
+    "\u{1f459}",
+    "\u{1f45a}",
+    "\u{1f45b}",
+    "\u{1f45c}",
+    "\u{1f45d}",
+    "\u{1f45e}",
+    "\u{1f45f}",
+    "\u{1f460}",
+    "\u{1f461}",
+    "\u{1f462}",
+    "\u{1f463}",
+    "\u{1f464}",
+    "\u{1f465}",
+    "\u{1f466}",
+    "\u{1f466}\u{1f3fb}",
+    "\u{1f466}\u{1f3fc}",
+    "\u{1f466}\u{1f3fd}",
+    "\u{1f466}\u{1f3fe}",
+    "\u{1f466}\u{1f3ff}",
+    "\u{1f467}",
+    "\u{1f467}\u{1f3fb}",
+    "\u{1f467}\u{1f3fc}",
+    "\u{1f467}\u{1f3fd}",
+    "\u{1f467}\u{1f3fe}",
+    "\u{1f467}\u{1f3ff}",
+    "\u{1f468}",
+    "\u{1f468}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}",
+    "\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}",
+    "\u{1f468}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{200d}\u{1f373}",
+    "\u{1f468}\u{200d}\u{1f37c}",
+    "\u{1f468}\u{200d}\u{1f393}",
+    "\u{1f468}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f468}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{200d}\u{1f527}",
+    "\u{1f468}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{200d}\u{1f680}",
+    "\u{1f468}\u{200d}\u{1f692}",
+    "\u{1f468}\u{200d}\u{1f9af}",
+    "\u{1f468}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{200d}\u{1f9bc}",
+    "\u{1f468}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{200d}\u{1f9bd}",
+    "\u{1f468}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f37c}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f37c}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f37c}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f37c}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f33e}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f373}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f37c}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f393}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f3a4}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f3a8}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f3eb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f3ed}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f4bb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f4bc}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f527}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f52c}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f680}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f692}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b0}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b1}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b2}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b3}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f468}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}",
+    "\u{1f469}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}",
+    "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}",
+    "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}",
+    "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}",
+    "\u{1f469}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{200d}\u{1f373}",
+    "\u{1f469}\u{200d}\u{1f37c}",
+    "\u{1f469}\u{200d}\u{1f393}",
+    "\u{1f469}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f467}",
+    "\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}",
+    "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}",
+    "\u{1f469}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{200d}\u{1f527}",
+    "\u{1f469}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{200d}\u{1f680}",
+    "\u{1f469}\u{200d}\u{1f692}",
+    "\u{1f469}\u{200d}\u{1f9af}",
+    "\u{1f469}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{200d}\u{1f9bc}",
+    "\u{1f469}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{200d}\u{1f9bd}",
+    "\u{1f469}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f37c}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f37c}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f37c}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f37c}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f33e}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f373}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f37c}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f393}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f3a4}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f3a8}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f3eb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f3ed}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f4bb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f4bc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f527}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f52c}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f680}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f692}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b0}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b1}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b2}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b3}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fb}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fc}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fd}",
+    "\u{1f469}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f469}\u{1f3fe}",
+    "\u{1f46a}",
+    "\u{1f46b}",
+    "\u{1f46b}\u{1f3fb}",
+    "\u{1f46b}\u{1f3fc}",
+    "\u{1f46b}\u{1f3fd}",
+    "\u{1f46b}\u{1f3fe}",
+    "\u{1f46b}\u{1f3ff}",
+    "\u{1f46c}",
+    "\u{1f46c}\u{1f3fb}",
+    "\u{1f46c}\u{1f3fc}",
+    "\u{1f46c}\u{1f3fd}",
+    "\u{1f46c}\u{1f3fe}",
+    "\u{1f46c}\u{1f3ff}",
+    "\u{1f46d}",
+    "\u{1f46d}\u{1f3fb}",
+    "\u{1f46d}\u{1f3fc}",
+    "\u{1f46d}\u{1f3fd}",
+    "\u{1f46d}\u{1f3fe}",
+    "\u{1f46d}\u{1f3ff}",
+    "\u{1f46e}",
+    "\u{1f46e}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3fb}",
+    "\u{1f46e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3fc}",
+    "\u{1f46e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3fd}",
+    "\u{1f46e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3fe}",
+    "\u{1f46e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46e}\u{1f3ff}",
+    "\u{1f46e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46f}",
+    "\u{1f46f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46f}\u{1f3fb}",
+    "\u{1f46f}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46f}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46f}\u{1f3fc}",
+    "\u{1f46f}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46f}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46f}\u{1f3fd}",
+    "\u{1f46f}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46f}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46f}\u{1f3fe}",
+    "\u{1f46f}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46f}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f46f}\u{1f3ff}",
+    "\u{1f46f}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f46f}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f470}",
+    "\u{1f470}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f470}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f470}\u{1f3fb}",
+    "\u{1f470}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f470}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f470}\u{1f3fc}",
+    "\u{1f470}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f470}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f470}\u{1f3fd}",
+    "\u{1f470}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f470}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f470}\u{1f3fe}",
+    "\u{1f470}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f470}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f470}\u{1f3ff}",
+    "\u{1f470}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f470}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}",
+    "\u{1f471}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3fb}",
+    "\u{1f471}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3fc}",
+    "\u{1f471}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3fd}",
+    "\u{1f471}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3fe}",
+    "\u{1f471}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f471}\u{1f3ff}",
+    "\u{1f471}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f471}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f472}",
+    "\u{1f472}\u{1f3fb}",
+    "\u{1f472}\u{1f3fc}",
+    "\u{1f472}\u{1f3fd}",
+    "\u{1f472}\u{1f3fe}",
+    "\u{1f472}\u{1f3ff}",
+    "\u{1f473}",
+    "\u{1f473}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3fb}",
+    "\u{1f473}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3fc}",
+    "\u{1f473}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3fd}",
+    "\u{1f473}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3fe}",
+    "\u{1f473}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f473}\u{1f3ff}",
+    "\u{1f473}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f473}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f474}",
+    "\u{1f474}\u{1f3fb}",
+    "\u{1f474}\u{1f3fc}",
+    "\u{1f474}\u{1f3fd}",
+    "\u{1f474}\u{1f3fe}",
+    "\u{1f474}\u{1f3ff}",
+    "\u{1f475}",
+    "\u{1f475}\u{1f3fb}",
+    "\u{1f475}\u{1f3fc}",
+    "\u{1f475}\u{1f3fd}",
+    "\u{1f475}\u{1f3fe}",
+    "\u{1f475}\u{1f3ff}",
+    "\u{1f476}",
+    "\u{1f476}\u{1f3fb}",
+    "\u{1f476}\u{1f3fc}",
+    "\u{1f476}\u{1f3fd}",
+    "\u{1f476}\u{1f3fe}",
+    "\u{1f476}\u{1f3ff}",
+    "\u{1f477}",
+    "\u{1f477}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3fb}",
+    "\u{1f477}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3fc}",
+    "\u{1f477}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3fd}",
+    "\u{1f477}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3fe}",
+    "\u{1f477}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f477}\u{1f3ff}",
+    "\u{1f477}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f477}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f478}",
+    "\u{1f478}\u{1f3fb}",
+    "\u{1f478}\u{1f3fc}",
+    "\u{1f478}\u{1f3fd}",
+    "\u{1f478}\u{1f3fe}",
+    "\u{1f478}\u{1f3ff}",
+    "\u{1f479}",
+    "\u{1f47a}",
+    "\u{1f47b}",
+    "\u{1f47c}",
+    "\u{1f47c}\u{1f3fb}",
+    "\u{1f47c}\u{1f3fc}",
+    "\u{1f47c}\u{1f3fd}",
+    "\u{1f47c}\u{1f3fe}",
+    "\u{1f47c}\u{1f3ff}",
+    "\u{1f47d}",
+    "\u{1f47e}",
+    "\u{1f47f}",
+    "\u{1f480}",
+    "\u{1f481}",
+    "\u{1f481}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3fb}",
+    "\u{1f481}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3fc}",
+    "\u{1f481}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3fd}",
+    "\u{1f481}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3fe}",
+    "\u{1f481}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f481}\u{1f3ff}",
+    "\u{1f481}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f481}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}",
+    "\u{1f482}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3fb}",
+    "\u{1f482}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3fc}",
+    "\u{1f482}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3fd}",
+    "\u{1f482}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3fe}",
+    "\u{1f482}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f482}\u{1f3ff}",
+    "\u{1f482}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f482}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f483}",
+    "\u{1f483}\u{1f3fb}",
+    "\u{1f483}\u{1f3fc}",
+    "\u{1f483}\u{1f3fd}",
+    "\u{1f483}\u{1f3fe}",
+    "\u{1f483}\u{1f3ff}",
+    "\u{1f484}",
+    "\u{1f485}",
+    "\u{1f485}\u{1f3fb}",
+    "\u{1f485}\u{1f3fc}",
+    "\u{1f485}\u{1f3fd}",
+    "\u{1f485}\u{1f3fe}",
+    "\u{1f485}\u{1f3ff}",
+    "\u{1f486}",
+    "\u{1f486}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3fb}",
+    "\u{1f486}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3fc}",
+    "\u{1f486}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3fd}",
+    "\u{1f486}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3fe}",
+    "\u{1f486}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f486}\u{1f3ff}",
+    "\u{1f486}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f486}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}",
+    "\u{1f487}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3fb}",
+    "\u{1f487}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3fc}",
+    "\u{1f487}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3fd}",
+    "\u{1f487}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3fe}",
+    "\u{1f487}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f487}\u{1f3ff}",
+    "\u{1f487}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f487}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f488}",
+    "\u{1f489}",
+    "\u{1f48a}",
+    "\u{1f48b}",
+    "\u{1f48c}",
+    "\u{1f48d}",
+    "\u{1f48e}",
+    "\u{1f48f}",
+    "\u{1f48f}\u{1f3fb}",
+    "\u{1f48f}\u{1f3fc}",
+    "\u{1f48f}\u{1f3fd}",
+    "\u{1f48f}\u{1f3fe}",
+    "\u{1f48f}\u{1f3ff}",
+    "\u{1f490}",
+    "\u{1f491}",
+    "\u{1f491}\u{1f3fb}",
+    "\u{1f491}\u{1f3fc}",
+    "\u{1f491}\u{1f3fd}",
+    "\u{1f491}\u{1f3fe}",
+    "\u{1f491}\u{1f3ff}",
+    "\u{1f492}",
+    "\u{1f493}",
+    "\u{1f494}",
+    "\u{1f495}",
+    "\u{1f496}",
+    "\u{1f497}",
+    "\u{1f498}",
+    "\u{1f499}",
+    "\u{1f49a}",
+    "\u{1f49b}",
+    "\u{1f49c}",
+    "\u{1f49d}",
+    "\u{1f49e}",
+    "\u{1f49f}",
+    "\u{1f4a0}",
+    "\u{1f4a1}",
+    "\u{1f4a2}",
+    "\u{1f4a3}",
+    "\u{1f4a4}",
+    "\u{1f4a5}",
+    "\u{1f4a6}",
+    "\u{1f4a7}",
+    "\u{1f4a8}",
+    "\u{1f4a9}",
+    "\u{1f4aa}",
+    "\u{1f4aa}\u{1f3fb}",
+    "\u{1f4aa}\u{1f3fc}",
+    "\u{1f4aa}\u{1f3fd}",
+    "\u{1f4aa}\u{1f3fe}",
+    "\u{1f4aa}\u{1f3ff}",
+    "\u{1f4ab}",
+    "\u{1f4ac}",
+    "\u{1f4ad}",
+    "\u{1f4ae}",
+    "\u{1f4af}",
+    "\u{1f4b0}",
+    "\u{1f4b1}",
+    "\u{1f4b2}",
+    "\u{1f4b3}",
+    "\u{1f4b4}",
+    "\u{1f4b5}",
+    "\u{1f4b6}",
+    "\u{1f4b7}",
+    "\u{1f4b8}",
+    "\u{1f4b9}",
+    "\u{1f4ba}",
+    "\u{1f4bb}",
+    "\u{1f4bc}",
+    "\u{1f4bd}",
+    "\u{1f4be}",
+    "\u{1f4bf}",
+    "\u{1f4c0}",
+    "\u{1f4c1}",
+    "\u{1f4c2}",
+    "\u{1f4c3}",
+    "\u{1f4c4}",
+    "\u{1f4c5}",
+    "\u{1f4c6}",
+    "\u{1f4c7}",
+    "\u{1f4c8}",
+    "\u{1f4c9}",
+    "\u{1f4ca}",
+    "\u{1f4cb}",
+    "\u{1f4cc}",
+    "\u{1f4cd}",
+    "\u{1f4ce}",
+    "\u{1f4cf}",
+    "\u{1f4d0}",
+    "\u{1f4d1}",
+    "\u{1f4d2}",
+    "\u{1f4d3}",
+    "\u{1f4d4}",
+    "\u{1f4d5}",
+    "\u{1f4d6}",
+    "\u{1f4d7}",
+    "\u{1f4d8}",
+    "\u{1f4d9}",
+    "\u{1f4da}",
+    "\u{1f4db}",
+    "\u{1f4dc}",
+    "\u{1f4dd}",
+    "\u{1f4de}",
+    "\u{1f4df}",
+    "\u{1f4e0}",
+    "\u{1f4e1}",
+    "\u{1f4e2}",
+    "\u{1f4e3}",
+    "\u{1f4e4}",
+    "\u{1f4e5}",
+    "\u{1f4e6}",
+    "\u{1f4e7}",
+    "\u{1f4e8}",
+    "\u{1f4e9}",
+    "\u{1f4ea}",
+    "\u{1f4eb}",
+    "\u{1f4ec}",
+    "\u{1f4ed}",
+    "\u{1f4ee}",
+    "\u{1f4ef}",
+    "\u{1f4f0}",
+    "\u{1f4f1}",
+    "\u{1f4f2}",
+    "\u{1f4f3}",
+    "\u{1f4f4}",
+    "\u{1f4f5}",
+    "\u{1f4f6}",
+    "\u{1f4f7}",
+    "\u{1f4f8}",
+    "\u{1f4f9}",
+    "\u{1f4fa}",
+    "\u{1f4fb}",
+    "\u{1f4fc}",
+    "\u{1f4fd}\u{fe0f}",
+    "\u{1f4ff}",
+    "\u{1f500}",
+    "\u{1f501}",
+    "\u{1f502}",
+    "\u{1f503}",
+    "\u{1f504}",
+    "\u{1f505}",
+    "\u{1f506}",
+    "\u{1f507}",
+    "\u{1f508}",
+    "\u{1f509}",
+    "\u{1f50a}",
+    "\u{1f50b}",
+    "\u{1f50c}",
+    "\u{1f50d}",
+    "\u{1f50e}",
+    "\u{1f50f}",
+    "\u{1f510}",
+    "\u{1f511}",
+    "\u{1f512}",
+    "\u{1f513}",
+    "\u{1f514}",
+    "\u{1f515}",
+    "\u{1f516}",
+    "\u{1f517}",
+    "\u{1f518}",
+    "\u{1f519}",
+    "\u{1f51a}",
+    "\u{1f51b}",
+    "\u{1f51c}",
+    "\u{1f51d}",
+    "\u{1f51e}",
+    "\u{1f51f}",
+    "\u{1f520}",
+    "\u{1f521}",
+    "\u{1f522}",
+    "\u{1f523}",
+    "\u{1f524}",
+    "\u{1f525}",
+    "\u{1f526}",
+    "\u{1f527}",
+    "\u{1f528}",
+    "\u{1f529}",
+    "\u{1f52a}",
+    "\u{1f52b}",
+    "\u{1f52c}",
+    "\u{1f52d}",
+    "\u{1f52e}",
+    "\u{1f52f}",
+    "\u{1f530}",
+    "\u{1f531}",
+    "\u{1f532}",
+    "\u{1f533}",
+    "\u{1f534}",
+    "\u{1f535}",
+    "\u{1f536}",
+    "\u{1f537}",
+    "\u{1f538}",
+    "\u{1f539}",
+    "\u{1f53a}",
+    "\u{1f53b}",
+    "\u{1f53c}",
+    "\u{1f53d}",
+    "\u{1f549}\u{fe0f}",
+    "\u{1f54a}\u{fe0f}",
+    "\u{1f54b}",
+    "\u{1f54c}",
+    "\u{1f54d}",
+    "\u{1f54e}",
+    "\u{1f550}",
+    "\u{1f551}",
+    "\u{1f552}",
+    "\u{1f553}",
+    "\u{1f554}",
+    "\u{1f555}",
+    "\u{1f556}",
+    "\u{1f557}",
+    "\u{1f558}",
+    "\u{1f559}",
+    "\u{1f55a}",
+    "\u{1f55b}",
+    "\u{1f55c}",
+    "\u{1f55d}",
+    "\u{1f55e}",
+    "\u{1f55f}",
+    "\u{1f560}",
+    "\u{1f561}",
+    "\u{1f562}",
+    "\u{1f563}",
+    "\u{1f564}",
+    "\u{1f565}",
+    "\u{1f566}",
+    "\u{1f567}",
+    "\u{1f56f}\u{fe0f}",
+    "\u{1f570}\u{fe0f}",
+    "\u{1f573}\u{fe0f}",
+    "\u{1f574}\u{fe0f}",
+    "\u{1f574}\u{1f3fb}",
+    "\u{1f574}\u{1f3fc}",
+    "\u{1f574}\u{1f3fd}",
+    "\u{1f574}\u{1f3fe}",
+    "\u{1f574}\u{1f3ff}",
+    "\u{1f575}\u{fe0f}",
+    "\u{1f575}\u{fe0f}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{fe0f}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3fb}",
+    "\u{1f575}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3fc}",
+    "\u{1f575}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3fd}",
+    "\u{1f575}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3fe}",
+    "\u{1f575}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f575}\u{1f3ff}",
+    "\u{1f575}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f575}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f576}\u{fe0f}",
+    "\u{1f577}\u{fe0f}",
+    "\u{1f578}\u{fe0f}",
+    "\u{1f579}\u{fe0f}",
+    "\u{1f57a}",
+    "\u{1f57a}\u{1f3fb}",
+    "\u{1f57a}\u{1f3fc}",
+    "\u{1f57a}\u{1f3fd}",
+    "\u{1f57a}\u{1f3fe}",
+    "\u{1f57a}\u{1f3ff}",
+    "\u{1f587}\u{fe0f}",
+    "\u{1f58a}\u{fe0f}",
+    "\u{1f58b}\u{fe0f}",
+    "\u{1f58c}\u{fe0f}",
+    "\u{1f58d}\u{fe0f}",
+    "\u{1f590}\u{fe0f}",
+    "\u{1f590}\u{1f3fb}",
+    "\u{1f590}\u{1f3fc}",
+    "\u{1f590}\u{1f3fd}",
+    "\u{1f590}\u{1f3fe}",
+    "\u{1f590}\u{1f3ff}",
+    "\u{1f595}",
+    "\u{1f595}\u{1f3fb}",
+    "\u{1f595}\u{1f3fc}",
+    "\u{1f595}\u{1f3fd}",
+    "\u{1f595}\u{1f3fe}",
+    "\u{1f595}\u{1f3ff}",
+    "\u{1f596}",
+    "\u{1f596}\u{1f3fb}",
+    "\u{1f596}\u{1f3fc}",
+    "\u{1f596}\u{1f3fd}",
+    "\u{1f596}\u{1f3fe}",
+    "\u{1f596}\u{1f3ff}",
+    "\u{1f5a4}",
+    "\u{1f5a5}\u{fe0f}",
+    "\u{1f5a8}\u{fe0f}",
+    "\u{1f5b1}\u{fe0f}",
+    "\u{1f5b2}\u{fe0f}",
+    "\u{1f5bc}\u{fe0f}",
+    "\u{1f5c2}\u{fe0f}",
+    "\u{1f5c3}\u{fe0f}",
+    "\u{1f5c4}\u{fe0f}",
+    "\u{1f5d1}\u{fe0f}",
+    "\u{1f5d2}\u{fe0f}",
+    "\u{1f5d3}\u{fe0f}",
+    "\u{1f5dc}\u{fe0f}",
+    "\u{1f5dd}\u{fe0f}",
+    "\u{1f5de}\u{fe0f}",
+    "\u{1f5e1}\u{fe0f}",
+    "\u{1f5e3}\u{fe0f}",
+    "\u{1f5e8}\u{fe0f}",
+    "\u{1f5ef}\u{fe0f}",
+    "\u{1f5f3}\u{fe0f}",
+    "\u{1f5fa}\u{fe0f}",
+    "\u{1f5fb}",
+    "\u{1f5fc}",
+    "\u{1f5fd}",
+    "\u{1f5fe}",
+    "\u{1f5ff}",
+    "\u{1f600}",
+    "\u{1f601}",
+    "\u{1f602}",
+    "\u{1f603}",
+    "\u{1f604}",
+    "\u{1f605}",
+    "\u{1f606}",
+    "\u{1f607}",
+    "\u{1f608}",
+    "\u{1f609}",
+    "\u{1f60a}",
+    "\u{1f60b}",
+    "\u{1f60c}",
+    "\u{1f60d}",
+    "\u{1f60e}",
+    "\u{1f60f}",
+    "\u{1f610}",
+    "\u{1f611}",
+    "\u{1f612}",
+    "\u{1f613}",
+    "\u{1f614}",
+    "\u{1f615}",
+    "\u{1f616}",
+    "\u{1f617}",
+    "\u{1f618}",
+    "\u{1f619}",
+    "\u{1f61a}",
+    "\u{1f61b}",
+    "\u{1f61c}",
+    "\u{1f61d}",
+    "\u{1f61e}",
+    "\u{1f61f}",
+    "\u{1f620}",
+    "\u{1f621}",
+    "\u{1f622}",
+    "\u{1f623}",
+    "\u{1f624}",
+    "\u{1f625}",
+    "\u{1f626}",
+    "\u{1f627}",
+    "\u{1f628}",
+    "\u{1f629}",
+    "\u{1f62a}",
+    "\u{1f62b}",
+    "\u{1f62c}",
+    "\u{1f62d}",
+    "\u{1f62e}",
+    "\u{1f62e}\u{200d}\u{1f4a8}",
+    "\u{1f62f}",
+    "\u{1f630}",
+    "\u{1f631}",
+    "\u{1f632}",
+    "\u{1f633}",
+    "\u{1f634}",
+    "\u{1f635}",
+    "\u{1f635}\u{200d}\u{1f4ab}",
+    "\u{1f636}",
+    "\u{1f636}\u{200d}\u{1f32b}\u{fe0f}",
+    "\u{1f637}",
+    "\u{1f638}",
+    "\u{1f639}",
+    "\u{1f63a}",
+    "\u{1f63b}",
+    "\u{1f63c}",
+    "\u{1f63d}",
+    "\u{1f63e}",
+    "\u{1f63f}",
+    "\u{1f640}",
+    "\u{1f641}",
+    "\u{1f642}",
+    "\u{1f642}\u{200d}\u{2194}\u{fe0f}",
+    "\u{1f642}\u{200d}\u{2195}\u{fe0f}",
+    "\u{1f643}",
+    "\u{1f644}",
+    "\u{1f645}",
+    "\u{1f645}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3fb}",
+    "\u{1f645}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3fc}",
+    "\u{1f645}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3fd}",
+    "\u{1f645}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3fe}",
+    "\u{1f645}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f645}\u{1f3ff}",
+    "\u{1f645}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f645}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}",
+    "\u{1f646}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3fb}",
+    "\u{1f646}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3fc}",
+    "\u{1f646}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3fd}",
+    "\u{1f646}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3fe}",
+    "\u{1f646}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f646}\u{1f3ff}",
+    "\u{1f646}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f646}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}",
+    "\u{1f647}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3fb}",
+    "\u{1f647}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3fc}",
+    "\u{1f647}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3fd}",
+    "\u{1f647}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3fe}",
+    "\u{1f647}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f647}\u{1f3ff}",
+    "\u{1f647}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f647}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f648}",
+    "\u{1f649}",
+    "\u{1f64a}",
+    "\u{1f64b}",
+    "\u{1f64b}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3fb}",
+    "\u{1f64b}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3fc}",
+    "\u{1f64b}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3fd}",
+    "\u{1f64b}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3fe}",
+    "\u{1f64b}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64b}\u{1f3ff}",
+    "\u{1f64b}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64b}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64c}",
+    "\u{1f64c}\u{1f3fb}",
+    "\u{1f64c}\u{1f3fc}",
+    "\u{1f64c}\u{1f3fd}",
+    "\u{1f64c}\u{1f3fe}",
+    "\u{1f64c}\u{1f3ff}",
+    "\u{1f64d}",
+    "\u{1f64d}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3fb}",
+    "\u{1f64d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3fc}",
+    "\u{1f64d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3fd}",
+    "\u{1f64d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3fe}",
+    "\u{1f64d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64d}\u{1f3ff}",
+    "\u{1f64d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}",
+    "\u{1f64e}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3fb}",
+    "\u{1f64e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3fc}",
+    "\u{1f64e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3fd}",
+    "\u{1f64e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3fe}",
+    "\u{1f64e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64e}\u{1f3ff}",
+    "\u{1f64e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f64e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f64f}",
+    "\u{1f64f}\u{1f3fb}",
+    "\u{1f64f}\u{1f3fc}",
+    "\u{1f64f}\u{1f3fd}",
+    "\u{1f64f}\u{1f3fe}",
+    "\u{1f64f}\u{1f3ff}",
+    "\u{1f680}",
+    "\u{1f681}",
+    "\u{1f682}",
+    "\u{1f683}",
+    "\u{1f684}",
+    "\u{1f685}",
+    "\u{1f686}",
+    "\u{1f687}",
+    "\u{1f688}",
+    "\u{1f689}",
+    "\u{1f68a}",
+    "\u{1f68b}",
+    "\u{1f68c}",
+    "\u{1f68d}",
+    "\u{1f68e}",
+    "\u{1f68f}",
+    "\u{1f690}",
+    "\u{1f691}",
+    "\u{1f692}",
+    "\u{1f693}",
+    "\u{1f694}",
+    "\u{1f695}",
+    "\u{1f696}",
+    "\u{1f697}",
+    "\u{1f698}",
+    "\u{1f699}",
+    "\u{1f69a}",
+    "\u{1f69b}",
+    "\u{1f69c}",
+    "\u{1f69d}",
+    "\u{1f69e}",
+    "\u{1f69f}",
+    "\u{1f6a0}",
+    "\u{1f6a1}",
+    "\u{1f6a2}",
+    "\u{1f6a3}",
+    "\u{1f6a3}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fb}",
+    "\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fc}",
+    "\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fd}",
+    "\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fe}",
+    "\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a3}\u{1f3ff}",
+    "\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6a4}",
+    "\u{1f6a5}",
+    "\u{1f6a6}",
+    "\u{1f6a7}",
+    "\u{1f6a8}",
+    "\u{1f6a9}",
+    "\u{1f6aa}",
+    "\u{1f6ab}",
+    "\u{1f6ac}",
+    "\u{1f6ad}",
+    "\u{1f6ae}",
+    "\u{1f6af}",
+    "\u{1f6b0}",
+    "\u{1f6b1}",
+    "\u{1f6b2}",
+    "\u{1f6b3}",
+    "\u{1f6b4}",
+    "\u{1f6b4}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fb}",
+    "\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fc}",
+    "\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fd}",
+    "\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fe}",
+    "\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b4}\u{1f3ff}",
+    "\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}",
+    "\u{1f6b5}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fb}",
+    "\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fc}",
+    "\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fd}",
+    "\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fe}",
+    "\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b5}\u{1f3ff}",
+    "\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}",
+    "\u{1f6b6}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fb}",
+    "\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fc}",
+    "\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fd}",
+    "\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fe}",
+    "\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3ff}",
+    "\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b6}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f6b7}",
+    "\u{1f6b8}",
+    "\u{1f6b9}",
+    "\u{1f6ba}",
+    "\u{1f6bb}",
+    "\u{1f6bc}",
+    "\u{1f6bd}",
+    "\u{1f6be}",
+    "\u{1f6bf}",
+    "\u{1f6c0}",
+    "\u{1f6c0}\u{1f3fb}",
+    "\u{1f6c0}\u{1f3fc}",
+    "\u{1f6c0}\u{1f3fd}",
+    "\u{1f6c0}\u{1f3fe}",
+    "\u{1f6c0}\u{1f3ff}",
+    "\u{1f6c1}",
+    "\u{1f6c2}",
+    "\u{1f6c3}",
+    "\u{1f6c4}",
+    "\u{1f6c5}",
+    "\u{1f6cb}\u{fe0f}",
+    "\u{1f6cc}",
+    "\u{1f6cc}\u{1f3fb}",
+    "\u{1f6cc}\u{1f3fc}",
+    "\u{1f6cc}\u{1f3fd}",
+    "\u{1f6cc}\u{1f3fe}",
+    "\u{1f6cc}\u{1f3ff}",
+    "\u{1f6cd}\u{fe0f}",
+    "\u{1f6ce}\u{fe0f}",
+    "\u{1f6cf}\u{fe0f}",
+    "\u{1f6d0}",
+    "\u{1f6d1}",
+    "\u{1f6d2}",
+    "\u{1f6d5}",
+    "\u{1f6d6}",
+    "\u{1f6d7}",
+    "\u{1f6d8}",
+    "\u{1f6dc}",
+    "\u{1f6dd}",
+    "\u{1f6de}",
+    "\u{1f6df}",
+    "\u{1f6e0}\u{fe0f}",
+    "\u{1f6e1}\u{fe0f}",
+    "\u{1f6e2}\u{fe0f}",
+    "\u{1f6e3}\u{fe0f}",
+    "\u{1f6e4}\u{fe0f}",
+    "\u{1f6e5}\u{fe0f}",
+    "\u{1f6e9}\u{fe0f}",
+    "\u{1f6eb}",
+    "\u{1f6ec}",
+    "\u{1f6f0}\u{fe0f}",
+    "\u{1f6f3}\u{fe0f}",
+    "\u{1f6f4}",
+    "\u{1f6f5}",
+    "\u{1f6f6}",
+    "\u{1f6f7}",
+    "\u{1f6f8}",
+    "\u{1f6f9}",
+    "\u{1f6fa}",
+    "\u{1f6fb}",
+    "\u{1f6fc}",
+    "\u{1f7e0}",
+    "\u{1f7e1}",
+    "\u{1f7e2}",
+    "\u{1f7e3}",
+    "\u{1f7e4}",
+    "\u{1f7e5}",
+    "\u{1f7e6}",
+    "\u{1f7e7}",
+    "\u{1f7e8}",
+    "\u{1f7e9}",
+    "\u{1f7ea}",
+    "\u{1f7eb}",
+    "\u{1f7f0}",
+    "\u{1f90c}",
+    "\u{1f90c}\u{1f3fb}",
+    "\u{1f90c}\u{1f3fc}",
+    "\u{1f90c}\u{1f3fd}",
+    "\u{1f90c}\u{1f3fe}",
+    "\u{1f90c}\u{1f3ff}",
+    "\u{1f90d}",
+    "\u{1f90e}",
+    "\u{1f90f}",
+    "\u{1f90f}\u{1f3fb}",
+    "\u{1f90f}\u{1f3fc}",
+    "\u{1f90f}\u{1f3fd}",
+    "\u{1f90f}\u{1f3fe}",
+    "\u{1f90f}\u{1f3ff}",
+    "\u{1f910}",
+    "\u{1f911}",
+    "\u{1f912}",
+    "\u{1f913}",
+    "\u{1f914}",
+    "\u{1f915}",
+    "\u{1f916}",
+    "\u{1f917}",
+    "\u{1f918}",
+    "\u{1f918}\u{1f3fb}",
+    "\u{1f918}\u{1f3fc}",
+    "\u{1f918}\u{1f3fd}",
+    "\u{1f918}\u{1f3fe}",
+    "\u{1f918}\u{1f3ff}",
+    "\u{1f919}",
+    "\u{1f919}\u{1f3fb}",
+    "\u{1f919}\u{1f3fc}",
+    "\u{1f919}\u{1f3fd}",
+    "\u{1f919}\u{1f3fe}",
+    "\u{1f919}\u{1f3ff}",
+    "\u{1f91a}",
+    "\u{1f91a}\u{1f3fb}",
+    "\u{1f91a}\u{1f3fc}",
+    "\u{1f91a}\u{1f3fd}",
+    "\u{1f91a}\u{1f3fe}",
+    "\u{1f91a}\u{1f3ff}",
+    "\u{1f91b}",
+    "\u{1f91b}\u{1f3fb}",
+    "\u{1f91b}\u{1f3fc}",
+    "\u{1f91b}\u{1f3fd}",
+    "\u{1f91b}\u{1f3fe}",
+    "\u{1f91b}\u{1f3ff}",
+    "\u{1f91c}",
+    "\u{1f91c}\u{1f3fb}",
+    "\u{1f91c}\u{1f3fc}",
+    "\u{1f91c}\u{1f3fd}",
+    "\u{1f91c}\u{1f3fe}",
+    "\u{1f91c}\u{1f3ff}",
+    "\u{1f91d}",
+    "\u{1f91d}\u{1f3fb}",
+    "\u{1f91d}\u{1f3fc}",
+    "\u{1f91d}\u{1f3fd}",
+    "\u{1f91d}\u{1f3fe}",
+    "\u{1f91d}\u{1f3ff}",
+    "\u{1f91e}",
+    "\u{1f91e}\u{1f3fb}",
+    "\u{1f91e}\u{1f3fc}",
+    "\u{1f91e}\u{1f3fd}",
+    "\u{1f91e}\u{1f3fe}",
+    "\u{1f91e}\u{1f3ff}",
+    "\u{1f91f}",
+    "\u{1f91f}\u{1f3fb}",
+    "\u{1f91f}\u{1f3fc}",
+    "\u{1f91f}\u{1f3fd}",
+    "\u{1f91f}\u{1f3fe}",
+    "\u{1f91f}\u{1f3ff}",
+    "\u{1f920}",
+    "\u{1f921}",
+    "\u{1f922}",
+    "\u{1f923}",
+    "\u{1f924}",
+    "\u{1f925}",
+    "\u{1f926}",
+    "\u{1f926}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3fb}",
+    "\u{1f926}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3fc}",
+    "\u{1f926}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3fd}",
+    "\u{1f926}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3fe}",
+    "\u{1f926}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f926}\u{1f3ff}",
+    "\u{1f926}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f926}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f927}",
+    "\u{1f928}",
+    "\u{1f929}",
+    "\u{1f92a}",
+    "\u{1f92b}",
+    "\u{1f92c}",
+    "\u{1f92d}",
+    "\u{1f92e}",
+    "\u{1f92f}",
+    "\u{1f930}",
+    "\u{1f930}\u{1f3fb}",
+    "\u{1f930}\u{1f3fc}",
+    "\u{1f930}\u{1f3fd}",
+    "\u{1f930}\u{1f3fe}",
+    "\u{1f930}\u{1f3ff}",
+    "\u{1f931}",
+    "\u{1f931}\u{1f3fb}",
+    "\u{1f931}\u{1f3fc}",
+    "\u{1f931}\u{1f3fd}",
+    "\u{1f931}\u{1f3fe}",
+    "\u{1f931}\u{1f3ff}",
+    "\u{1f932}",
+    "\u{1f932}\u{1f3fb}",
+    "\u{1f932}\u{1f3fc}",
+    "\u{1f932}\u{1f3fd}",
+    "\u{1f932}\u{1f3fe}",
+    "\u{1f932}\u{1f3ff}",
+    "\u{1f933}",
+    "\u{1f933}\u{1f3fb}",
+    "\u{1f933}\u{1f3fc}",
+    "\u{1f933}\u{1f3fd}",
+    "\u{1f933}\u{1f3fe}",
+    "\u{1f933}\u{1f3ff}",
+    "\u{1f934}",
+    "\u{1f934}\u{1f3fb}",
+    "\u{1f934}\u{1f3fc}",
+    "\u{1f934}\u{1f3fd}",
+    "\u{1f934}\u{1f3fe}",
+    "\u{1f934}\u{1f3ff}",
+    "\u{1f935}",
+    "\u{1f935}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f935}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f935}\u{1f3fb}",
+    "\u{1f935}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f935}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f935}\u{1f3fc}",
+    "\u{1f935}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f935}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f935}\u{1f3fd}",
+    "\u{1f935}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f935}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f935}\u{1f3fe}",
+    "\u{1f935}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f935}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f935}\u{1f3ff}",
+    "\u{1f935}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f935}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f936}",
+    "\u{1f936}\u{1f3fb}",
+    "\u{1f936}\u{1f3fc}",
+    "\u{1f936}\u{1f3fd}",
+    "\u{1f936}\u{1f3fe}",
+    "\u{1f936}\u{1f3ff}",
+    "\u{1f937}",
+    "\u{1f937}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3fb}",
+    "\u{1f937}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3fc}",
+    "\u{1f937}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3fd}",
+    "\u{1f937}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3fe}",
+    "\u{1f937}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f937}\u{1f3ff}",
+    "\u{1f937}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f937}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}",
+    "\u{1f938}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3fb}",
+    "\u{1f938}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3fc}",
+    "\u{1f938}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3fd}",
+    "\u{1f938}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3fe}",
+    "\u{1f938}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f938}\u{1f3ff}",
+    "\u{1f938}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f938}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}",
+    "\u{1f939}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3fb}",
+    "\u{1f939}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3fc}",
+    "\u{1f939}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3fd}",
+    "\u{1f939}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3fe}",
+    "\u{1f939}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f939}\u{1f3ff}",
+    "\u{1f939}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f939}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93a}",
+    "\u{1f93c}",
+    "\u{1f93c}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93c}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93c}\u{1f3fb}",
+    "\u{1f93c}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93c}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93c}\u{1f3fc}",
+    "\u{1f93c}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93c}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93c}\u{1f3fd}",
+    "\u{1f93c}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93c}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93c}\u{1f3fe}",
+    "\u{1f93c}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93c}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93c}\u{1f3ff}",
+    "\u{1f93c}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93c}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}",
+    "\u{1f93d}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3fb}",
+    "\u{1f93d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3fc}",
+    "\u{1f93d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3fd}",
+    "\u{1f93d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3fe}",
+    "\u{1f93d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93d}\u{1f3ff}",
+    "\u{1f93d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}",
+    "\u{1f93e}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3fb}",
+    "\u{1f93e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3fc}",
+    "\u{1f93e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3fd}",
+    "\u{1f93e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3fe}",
+    "\u{1f93e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93e}\u{1f3ff}",
+    "\u{1f93e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f93e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f93f}",
+    "\u{1f940}",
+    "\u{1f941}",
+    "\u{1f942}",
+    "\u{1f943}",
+    "\u{1f944}",
+    "\u{1f945}",
+    "\u{1f947}",
+    "\u{1f948}",
+    "\u{1f949}",
+    "\u{1f94a}",
+    "\u{1f94b}",
+    "\u{1f94c}",
+    "\u{1f94d}",
+    "\u{1f94e}",
+    "\u{1f94f}",
+    "\u{1f950}",
+    "\u{1f951}",
+    "\u{1f952}",
+    "\u{1f953}",
+    "\u{1f954}",
+    "\u{1f955}",
+    "\u{1f956}",
+    "\u{1f957}",
+    "\u{1f958}",
+    "\u{1f959}",
+    "\u{1f95a}",
+    "\u{1f95b}",
+    "\u{1f95c}",
+    "\u{1f95d}",
+    "\u{1f95e}",
+    "\u{1f95f}",
+    "\u{1f960}",
+    "\u{1f961}",
+    "\u{1f962}",
+    "\u{1f963}",
+    "\u{1f964}",
+    "\u{1f965}",
+    "\u{1f966}",
+    "\u{1f967}",
+    "\u{1f968}",
+    "\u{1f969}",
+    "\u{1f96a}",
+    "\u{1f96b}",
+    "\u{1f96c}",
+    "\u{1f96d}",
+    "\u{1f96e}",
+    "\u{1f96f}",
+    "\u{1f970}",
+    "\u{1f971}",
+    "\u{1f972}",
+    "\u{1f973}",
+    "\u{1f974}",
+    "\u{1f975}",
+    "\u{1f976}",
+    "\u{1f977}",
+    "\u{1f977}\u{1f3fb}",
+    "\u{1f977}\u{1f3fc}",
+    "\u{1f977}\u{1f3fd}",
+    "\u{1f977}\u{1f3fe}",
+    "\u{1f977}\u{1f3ff}",
+    "\u{1f978}",
+    "\u{1f979}",
+    "\u{1f97a}",
+    "\u{1f97b}",
+    "\u{1f97c}",
+    "\u{1f97d}",
+    "\u{1f97e}",
+    "\u{1f97f}",
+    "\u{1f980}",
+    "\u{1f981}",
+    "\u{1f982}",
+    "\u{1f983}",
+    "\u{1f984}",
+    "\u{1f985}",
+    "\u{1f986}",
+    "\u{1f987}",
+    "\u{1f988}",
+    "\u{1f989}",
+    "\u{1f98a}",
+    "\u{1f98b}",
+    "\u{1f98c}",
+    "\u{1f98d}",
+    "\u{1f98e}",
+    "\u{1f98f}",
+    "\u{1f990}",
+    "\u{1f991}",
+    "\u{1f992}",
+    "\u{1f993}",
+    "\u{1f994}",
+    "\u{1f995}",
+    "\u{1f996}",
+    "\u{1f997}",
+    "\u{1f998}",
+    "\u{1f999}",
+    "\u{1f99a}",
+    "\u{1f99b}",
+    "\u{1f99c}",
+    "\u{1f99d}",
+    "\u{1f99e}",
+    "\u{1f99f}",
+    "\u{1f9a0}",
+    "\u{1f9a1}",
+    "\u{1f9a2}",
+    "\u{1f9a3}",
+    "\u{1f9a4}",
+    "\u{1f9a5}",
+    "\u{1f9a6}",
+    "\u{1f9a7}",
+    "\u{1f9a8}",
+    "\u{1f9a9}",
+    "\u{1f9aa}",
+    "\u{1f9ab}",
+    "\u{1f9ac}",
+    "\u{1f9ad}",
+    "\u{1f9ae}",
+    "\u{1f9af}",
+    "\u{1f9b0}",
+    "\u{1f9b1}",
+    "\u{1f9b2}",
+    "\u{1f9b3}",
+    "\u{1f9b4}",
+    "\u{1f9b5}",
+    "\u{1f9b5}\u{1f3fb}",
+    "\u{1f9b5}\u{1f3fc}",
+    "\u{1f9b5}\u{1f3fd}",
+    "\u{1f9b5}\u{1f3fe}",
+    "\u{1f9b5}\u{1f3ff}",
+    "\u{1f9b6}",
+    "\u{1f9b6}\u{1f3fb}",
+    "\u{1f9b6}\u{1f3fc}",
+    "\u{1f9b6}\u{1f3fd}",
+    "\u{1f9b6}\u{1f3fe}",
+    "\u{1f9b6}\u{1f3ff}",
+    "\u{1f9b7}",
+    "\u{1f9b8}",
+    "\u{1f9b8}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fb}",
+    "\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fc}",
+    "\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fd}",
+    "\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fe}",
+    "\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b8}\u{1f3ff}",
+    "\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}",
+    "\u{1f9b9}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fb}",
+    "\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fc}",
+    "\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fd}",
+    "\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fe}",
+    "\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9b9}\u{1f3ff}",
+    "\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9ba}",
+    "\u{1f9bb}",
+    "\u{1f9bb}\u{1f3fb}",
+    "\u{1f9bb}\u{1f3fc}",
+    "\u{1f9bb}\u{1f3fd}",
+    "\u{1f9bb}\u{1f3fe}",
+    "\u{1f9bb}\u{1f3ff}",
+    "\u{1f9bc}",
+    "\u{1f9bd}",
+    "\u{1f9be}",
+    "\u{1f9bf}",
+    "\u{1f9c0}",
+    "\u{1f9c1}",
+    "\u{1f9c2}",
+    "\u{1f9c3}",
+    "\u{1f9c4}",
+    "\u{1f9c5}",
+    "\u{1f9c6}",
+    "\u{1f9c7}",
+    "\u{1f9c8}",
+    "\u{1f9c9}",
+    "\u{1f9ca}",
+    "\u{1f9cb}",
+    "\u{1f9cc}",
+    "\u{1f9cd}",
+    "\u{1f9cd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cd}\u{1f3fb}",
+    "\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cd}\u{1f3fc}",
+    "\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cd}\u{1f3fd}",
+    "\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cd}\u{1f3fe}",
+    "\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cd}\u{1f3ff}",
+    "\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9ce}",
+    "\u{1f9ce}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9ce}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9ce}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fb}",
+    "\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fc}",
+    "\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fd}",
+    "\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fe}",
+    "\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3ff}",
+    "\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9ce}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9cf}",
+    "\u{1f9cf}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cf}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cf}\u{1f3fb}",
+    "\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cf}\u{1f3fc}",
+    "\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cf}\u{1f3fd}",
+    "\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cf}\u{1f3fe}",
+    "\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9cf}\u{1f3ff}",
+    "\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d0}",
+    "\u{1f9d1}",
+    "\u{1f9d1}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f9d1}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f9d1}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f9d1}\u{200d}\u{1f33e}",
+    "\u{1f9d1}\u{200d}\u{1f373}",
+    "\u{1f9d1}\u{200d}\u{1f37c}",
+    "\u{1f9d1}\u{200d}\u{1f384}",
+    "\u{1f9d1}\u{200d}\u{1f393}",
+    "\u{1f9d1}\u{200d}\u{1f3a4}",
+    "\u{1f9d1}\u{200d}\u{1f3a8}",
+    "\u{1f9d1}\u{200d}\u{1f3eb}",
+    "\u{1f9d1}\u{200d}\u{1f3ed}",
+    "\u{1f9d1}\u{200d}\u{1f4bb}",
+    "\u{1f9d1}\u{200d}\u{1f4bc}",
+    "\u{1f9d1}\u{200d}\u{1f527}",
+    "\u{1f9d1}\u{200d}\u{1f52c}",
+    "\u{1f9d1}\u{200d}\u{1f680}",
+    "\u{1f9d1}\u{200d}\u{1f692}",
+    "\u{1f9d1}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}",
+    "\u{1f9d1}\u{200d}\u{1f9af}",
+    "\u{1f9d1}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{200d}\u{1f9b0}",
+    "\u{1f9d1}\u{200d}\u{1f9b1}",
+    "\u{1f9d1}\u{200d}\u{1f9b2}",
+    "\u{1f9d1}\u{200d}\u{1f9b3}",
+    "\u{1f9d1}\u{200d}\u{1f9bc}",
+    "\u{1f9d1}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{200d}\u{1f9bd}",
+    "\u{1f9d1}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}",
+    "\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}\u{200d}\u{1f9d2}",
+    "\u{1f9d1}\u{200d}\u{1f9d2}",
+    "\u{1f9d1}\u{200d}\u{1f9d2}\u{200d}\u{1f9d2}",
+    "\u{1f9d1}\u{200d}\u{1fa70}",
+    "\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f33e}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f373}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f37c}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f384}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f393}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a4}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a8}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3eb}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3ed}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bb}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bc}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f527}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f52c}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f680}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f692}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b0}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b1}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b2}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b3}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1fa70}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fb}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f33e}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f373}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f37c}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f384}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f393}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a4}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a8}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3eb}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3ed}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bb}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bc}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f527}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f52c}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f680}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f692}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b0}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b1}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b2}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b3}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1fa70}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fc}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f33e}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f373}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f37c}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f384}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f393}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a4}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a8}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3eb}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3ed}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bb}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bc}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f527}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f52c}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f680}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f692}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b0}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b1}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b2}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b3}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1fa70}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fd}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f33e}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f373}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f37c}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f384}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f393}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a4}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a8}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3eb}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3ed}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bb}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bc}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f527}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f52c}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f680}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f692}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b0}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b1}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b2}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b3}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1fa70}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3fe}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f33e}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f373}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f37c}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f384}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f393}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a4}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a8}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3eb}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3ed}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f430}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bb}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bc}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f527}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f52c}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f680}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f692}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b0}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b1}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b2}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b3}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1fa70}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fb}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fc}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fd}",
+    "\u{1f9d1}\u{1f3ff}\u{200d}\u{1faef}\u{200d}\u{1f9d1}\u{1f3fe}",
+    "\u{1f9d2}",
+    "\u{1f9d2}\u{1f3fb}",
+    "\u{1f9d2}\u{1f3fc}",
+    "\u{1f9d2}\u{1f3fd}",
+    "\u{1f9d2}\u{1f3fe}",
+    "\u{1f9d2}\u{1f3ff}",
+    "\u{1f9d3}",
+    "\u{1f9d3}\u{1f3fb}",
+    "\u{1f9d3}\u{1f3fc}",
+    "\u{1f9d3}\u{1f3fd}",
+    "\u{1f9d3}\u{1f3fe}",
+    "\u{1f9d3}\u{1f3ff}",
+    "\u{1f9d4}",
+    "\u{1f9d4}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d4}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d4}\u{1f3fb}",
+    "\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d4}\u{1f3fc}",
+    "\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d4}\u{1f3fd}",
+    "\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d4}\u{1f3fe}",
+    "\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d4}\u{1f3ff}",
+    "\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d5}",
+    "\u{1f9d5}\u{1f3fb}",
+    "\u{1f9d5}\u{1f3fc}",
+    "\u{1f9d5}\u{1f3fd}",
+    "\u{1f9d5}\u{1f3fe}",
+    "\u{1f9d5}\u{1f3ff}",
+    "\u{1f9d6}",
+    "\u{1f9d6}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fb}",
+    "\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fc}",
+    "\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fd}",
+    "\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fe}",
+    "\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d6}\u{1f3ff}",
+    "\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}",
+    "\u{1f9d7}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fb}",
+    "\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fc}",
+    "\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fd}",
+    "\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fe}",
+    "\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d7}\u{1f3ff}",
+    "\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}",
+    "\u{1f9d8}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fb}",
+    "\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fc}",
+    "\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fd}",
+    "\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fe}",
+    "\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d8}\u{1f3ff}",
+    "\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}",
+    "\u{1f9d9}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fb}",
+    "\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fc}",
+    "\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fd}",
+    "\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fe}",
+    "\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9d9}\u{1f3ff}",
+    "\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}",
+    "\u{1f9da}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3fb}",
+    "\u{1f9da}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3fc}",
+    "\u{1f9da}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3fd}",
+    "\u{1f9da}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3fe}",
+    "\u{1f9da}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9da}\u{1f3ff}",
+    "\u{1f9da}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9da}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}",
+    "\u{1f9db}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3fb}",
+    "\u{1f9db}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3fc}",
+    "\u{1f9db}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3fd}",
+    "\u{1f9db}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3fe}",
+    "\u{1f9db}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9db}\u{1f3ff}",
+    "\u{1f9db}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9db}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}",
+    "\u{1f9dc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fb}",
+    "\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fc}",
+    "\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fd}",
+    "\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fe}",
+    "\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dc}\u{1f3ff}",
+    "\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}",
+    "\u{1f9dd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fb}",
+    "\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fc}",
+    "\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fd}",
+    "\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fe}",
+    "\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9dd}\u{1f3ff}",
+    "\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9de}",
+    "\u{1f9de}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9de}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9df}",
+    "\u{1f9df}\u{200d}\u{2640}\u{fe0f}",
+    "\u{1f9df}\u{200d}\u{2642}\u{fe0f}",
+    "\u{1f9e0}",
+    "\u{1f9e1}",
+    "\u{1f9e2}",
+    "\u{1f9e3}",
+    "\u{1f9e4}",
+    "\u{1f9e5}",
+    "\u{1f9e6}",
+    "\u{1f9e7}",
+    "\u{1f9e8}",
+    "\u{1f9e9}",
+    "\u{1f9ea}",
+    "\u{1f9eb}",
+    "\u{1f9ec}",
+    "\u{1f9ed}",
+    "\u{1f9ee}",
+    "\u{1f9ef}",
+    "\u{1f9f0}",
+    "\u{1f9f1}",
+    "\u{1f9f2}",
+    "\u{1f9f3}",
+    "\u{1f9f4}",
+    "\u{1f9f5}",
+    "\u{1f9f6}",
+    "\u{1f9f7}",
+    "\u{1f9f8}",
+    "\u{1f9f9}",
+    "\u{1f9fa}",
+    "\u{1f9fb}",
+    "\u{1f9fc}",
+    "\u{1f9fd}",
+    "\u{1f9fe}",
+    "\u{1f9ff}",
+    "\u{1fa70}",
+    "\u{1fa71}",
+    "\u{1fa72}",
+    "\u{1fa73}",
+    "\u{1fa74}",
+    "\u{1fa75}",
+    "\u{1fa76}",
+    "\u{1fa77}",
+    "\u{1fa78}",
+    "\u{1fa79}",
+    "\u{1fa7a}",
+    "\u{1fa7b}",
+    "\u{1fa7c}",
+    "\u{1fa80}",
+    "\u{1fa81}",
+    "\u{1fa82}",
+    "\u{1fa83}",
+    "\u{1fa84}",
+    "\u{1fa85}",
+    "\u{1fa86}",
+    "\u{1fa87}",
+    "\u{1fa88}",
+    "\u{1fa89}",
+    "\u{1fa8a}",
+    "\u{1fa8e}",
+    "\u{1fa8f}",
+    "\u{1fa90}",
+    "\u{1fa91}",
+    "\u{1fa92}",
+    "\u{1fa93}",
+    "\u{1fa94}",
+    "\u{1fa95}",
+    "\u{1fa96}",
+    "\u{1fa97}",
+    "\u{1fa98}",
+    "\u{1fa99}",
+    "\u{1fa9a}",
+    "\u{1fa9b}",
+    "\u{1fa9c}",
+    "\u{1fa9d}",
+    "\u{1fa9e}",
+    "\u{1fa9f}",
+    "\u{1faa0}",
+    "\u{1faa1}",
+    "\u{1faa2}",
+    "\u{1faa3}",
+    "\u{1faa4}",
+    "\u{1faa5}",
+    "\u{1faa6}",
+    "\u{1faa7}",
+    "\u{1faa8}",
+    "\u{1faa9}",
+    "\u{1faaa}",
+    "\u{1faab}",
+    "\u{1faac}",
+    "\u{1faad}",
+    "\u{1faae}",
+    "\u{1faaf}",
+    "\u{1fab0}",
+    "\u{1fab1}",
+    "\u{1fab2}",
+    "\u{1fab3}",
+    "\u{1fab4}",
+    "\u{1fab5}",
+    "\u{1fab6}",
+    "\u{1fab7}",
+    "\u{1fab8}",
+    "\u{1fab9}",
+    "\u{1faba}",
+    "\u{1fabb}",
+    "\u{1fabc}",
+    "\u{1fabd}",
+    "\u{1fabe}",
+    "\u{1fabf}",
+    "\u{1fac0}",
+    "\u{1fac1}",
+    "\u{1fac2}",
+    "\u{1fac3}",
+    "\u{1fac3}\u{1f3fb}",
+    "\u{1fac3}\u{1f3fc}",
+    "\u{1fac3}\u{1f3fd}",
+    "\u{1fac3}\u{1f3fe}",
+    "\u{1fac3}\u{1f3ff}",
+    "\u{1fac4}",
+    "\u{1fac4}\u{1f3fb}",
+    "\u{1fac4}\u{1f3fc}",
+    "\u{1fac4}\u{1f3fd}",
+    "\u{1fac4}\u{1f3fe}",
+    "\u{1fac4}\u{1f3ff}",
+    "\u{1fac5}",
+    "\u{1fac5}\u{1f3fb}",
+    "\u{1fac5}\u{1f3fc}",
+    "\u{1fac5}\u{1f3fd}",
+    "\u{1fac5}\u{1f3fe}",
+    "\u{1fac5}\u{1f3ff}",
+    "\u{1fac6}",
+    "\u{1fac8}",
+    "\u{1facd}",
+    "\u{1face}",
+    "\u{1facf}",
+    "\u{1fad0}",
+    "\u{1fad1}",
+    "\u{1fad2}",
+    "\u{1fad3}",
+    "\u{1fad4}",
+    "\u{1fad5}",
+    "\u{1fad6}",
+    "\u{1fad7}",
+    "\u{1fad8}",
+    "\u{1fad9}",
+    "\u{1fada}",
+    "\u{1fadb}",
+    "\u{1fadc}",
+    "\u{1fadf}",
+    "\u{1fae0}",
+    "\u{1fae1}",
+    "\u{1fae2}",
+    "\u{1fae3}",
+    "\u{1fae4}",
+    "\u{1fae5}",
+    "\u{1fae6}",
+    "\u{1fae7}",
+    "\u{1fae8}",
+    "\u{1fae9}",
+    "\u{1faea}",
+    "\u{1faef}",
+    "\u{1faf0}",
+    "\u{1faf0}\u{1f3fb}",
+    "\u{1faf0}\u{1f3fc}",
+    "\u{1faf0}\u{1f3fd}",
+    "\u{1faf0}\u{1f3fe}",
+    "\u{1faf0}\u{1f3ff}",
+    "\u{1faf1}",
+    "\u{1faf1}\u{1f3fb}",
+    "\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fc}",
+    "\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fd}",
+    "\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fe}",
+    "\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3ff}",
+    "\u{1faf1}\u{1f3fc}",
+    "\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fb}",
+    "\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fd}",
+    "\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fe}",
+    "\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3ff}",
+    "\u{1faf1}\u{1f3fd}",
+    "\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fb}",
+    "\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fc}",
+    "\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fe}",
+    "\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3ff}",
+    "\u{1faf1}\u{1f3fe}",
+    "\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fb}",
+    "\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fc}",
+    "\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fd}",
+    "\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3ff}",
+    "\u{1faf1}\u{1f3ff}",
+    "\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fb}",
+    "\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fc}",
+    "\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fd}",
+    "\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fe}",
+    "\u{1faf2}",
+    "\u{1faf2}\u{1f3fb}",
+    "\u{1faf2}\u{1f3fc}",
+    "\u{1faf2}\u{1f3fd}",
+    "\u{1faf2}\u{1f3fe}",
+    "\u{1faf2}\u{1f3ff}",
+    "\u{1faf3}",
+    "\u{1faf3}\u{1f3fb}",
+    "\u{1faf3}\u{1f3fc}",
+    "\u{1faf3}\u{1f3fd}",
+    "\u{1faf3}\u{1f3fe}",
+    "\u{1faf3}\u{1f3ff}",
+    "\u{1faf4}",
+    "\u{1faf4}\u{1f3fb}",
+    "\u{1faf4}\u{1f3fc}",
+    "\u{1faf4}\u{1f3fd}",
+    "\u{1faf4}\u{1f3fe}",
+    "\u{1faf4}\u{1f3ff}",
+    "\u{1faf5}",
+    "\u{1faf5}\u{1f3fb}",
+    "\u{1faf5}\u{1f3fc}",
+    "\u{1faf5}\u{1f3fd}",
+    "\u{1faf5}\u{1f3fe}",
+    "\u{1faf5}\u{1f3ff}",
+    "\u{1faf6}",
+    "\u{1faf6}\u{1f3fb}",
+    "\u{1faf6}\u{1f3fc}",
+    "\u{1faf6}\u{1f3fd}",
+    "\u{1faf6}\u{1f3fe}",
+    "\u{1faf6}\u{1f3ff}",
+    "\u{1faf7}",
+    "\u{1faf7}\u{1f3fb}",
+    "\u{1faf7}\u{1f3fc}",
+    "\u{1faf7}\u{1f3fd}",
+    "\u{1faf7}\u{1f3fe}",
+    "\u{1faf7}\u{1f3ff}",
+    "\u{1faf8}",
+    "\u{1faf8}\u{1f3fb}",
+    "\u{1faf8}\u{1f3fc}",
+    "\u{1faf8}\u{1f3fd}",
+    "\u{1faf8}\u{1f3fe}",
+    "\u{1faf8}\u{1f3ff}",
+];
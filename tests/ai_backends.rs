@@ -0,0 +1,48 @@
+// Following ADD: Test backend selection from the model setting
+
+use emo::backend::{self, ModelSpec};
+use emo::models::ModelRegistry;
+use std::path::Path;
+
+#[test]
+fn plain_model_id_uses_llama() {
+    assert_eq!(
+        ModelSpec::parse(Some("qwen2.5-0.5b")),
+        ModelSpec::Llama(Some("qwen2.5-0.5b".to_string()))
+    );
+    assert_eq!(ModelSpec::parse(None), ModelSpec::Llama(None));
+}
+
+#[test]
+fn prefixed_model_ids_select_http_backends() {
+    assert_eq!(
+        ModelSpec::parse(Some("ollama:llama3.2:1b")),
+        ModelSpec::Ollama("llama3.2:1b".to_string())
+    );
+    assert_eq!(
+        ModelSpec::parse(Some("openai:gpt-4o-mini")),
+        ModelSpec::OpenAi("gpt-4o-mini".to_string())
+    );
+    assert_eq!(
+        ModelSpec::parse(Some("llama:phi-2")),
+        ModelSpec::Llama(Some("phi-2".to_string()))
+    );
+}
+
+#[test]
+fn http_backend_needs_model_name() {
+    let result = backend::connect(Some("ollama:"), None, &ModelRegistry::new(), Path::new("."));
+    assert!(result.is_err());
+}
+
+#[test]
+fn unreachable_server_fails_cleanly() {
+    use emo::ai::AiEmojiSelector;
+    use emo::backend::HttpBackend;
+
+    // Nothing listens on port 9 (discard), so the request fails fast
+    let backend = HttpBackend::ollama(Some("http://127.0.0.1:9"), "llama3.2".to_string());
+    let selector = AiEmojiSelector::with_backend(Box::new(backend));
+    let err = selector.select_emoji_llm("coffee").unwrap_err();
+    assert!(err.to_string().contains("Failed to reach"));
+}
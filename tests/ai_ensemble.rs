@@ -0,0 +1,99 @@
+// Following ADD: Several models voting on AI picks
+
+use assert_cmd::prelude::*;
+use emo::ai::AiEmojiSelector;
+use emo::backend::mock::{MockResponse, MockScript};
+use emo::backend::MockBackend;
+use emo::generators::{AiGenerator, EmojiGenerator, EnsembleGenerator};
+use predicates::prelude::*;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn member(name: &str, mock: MockBackend) -> (String, Box<dyn EmojiGenerator>) {
+    let selector = AiEmojiSelector::with_backend(Box::new(mock));
+    (name.to_string(), Box::new(AiGenerator::new(selector)))
+}
+
+fn failing() -> MockBackend {
+    MockBackend::new(MockScript {
+        responses: vec![MockResponse {
+            tokens: vec![],
+            error: Some("model crashed".to_string()),
+        }],
+        record: None,
+    })
+}
+
+#[test]
+fn shared_second_choice_beats_single_favourite() {
+    let ensemble = EnsembleGenerator::new(vec![
+        member("qwen", MockBackend::replies(["🚀", "🎉", "🔥"])),
+        member("phi", MockBackend::replies(["🎉", "🌟", "🚀"])),
+    ]);
+
+    let candidates = ensemble.candidates("launch party", 2).unwrap();
+    let emojis: Vec<_> = candidates.iter().map(|c| c.emoji.as_str()).collect();
+    assert_eq!(emojis, vec!["🎉", "🚀"]);
+
+    // 1/2 from qwen plus 1 from phi, averaged over both models
+    assert!((candidates[0].score - 0.75).abs() < 1e-9);
+    assert_eq!(
+        candidates[0].explanation.as_deref(),
+        Some("qwen #2 (+0.50), phi #1 (+1.00)")
+    );
+}
+
+#[test]
+fn spellings_with_and_without_selector_share_a_tally() {
+    let ensemble = EnsembleGenerator::new(vec![
+        member("qwen", MockBackend::replies(["🛳️", "🚀", "🎉"])),
+        member("phi", MockBackend::replies(["🎉", "🛳", "🚀"])),
+    ]);
+
+    let candidates = ensemble.candidates("cruise", 1).unwrap();
+    assert_eq!(candidates[0].emoji, "🛳️");
+    assert_eq!(candidates[0].explanation.as_deref(), Some("qwen #1 (+1.00), phi #2 (+0.50)"));
+}
+
+#[test]
+fn failing_model_is_skipped() {
+    let ensemble = EnsembleGenerator::new(vec![
+        member("broken", failing()),
+        member("phi", MockBackend::replies(["☕", "🌅", "😴"])),
+    ]);
+
+    assert_eq!(ensemble.generate_n("morning coffee", 1).unwrap(), vec!["☕"]);
+}
+
+#[test]
+fn all_models_failing_is_an_error() {
+    let ensemble = EnsembleGenerator::new(vec![member("a", failing()), member("b", failing())]);
+
+    let err = ensemble.candidates("anything", 1).unwrap_err();
+    assert!(err.to_string().contains("model crashed"));
+}
+
+#[test]
+fn cli_saves_ensemble_and_explains_votes() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["🐛"]},{"tokens":["🔍"]},{"tokens":["🔧"]}]}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()));
+    cmd.args(["--model", "qwen,phi", "-v", "debugging"]);
+    cmd.assert()
+        .success()
+        .stdout("🐛\n")
+        .stderr(predicate::str::contains("qwen #1 (+1.00), phi #1 (+1.00)"));
+
+    let config = fs::read_to_string(config_dir.join("config.json")).unwrap();
+    let config: serde_json::Value = serde_json::from_str(&config).unwrap();
+    assert_eq!(config["ensemble"], serde_json::json!(["qwen", "phi"]));
+}
@@ -0,0 +1,81 @@
+// Following ADD: Explain emoji messages in plain words
+
+use assert_cmd::prelude::*;
+use emo::ai::AiEmojiSelector;
+use emo::backend::MockBackend;
+use emo::error::EmoError;
+use predicates::prelude::*;
+use std::fs;
+use std::process::Command;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+#[test]
+fn prompt_carries_emojis_definitions_and_context() {
+    let mock = Arc::new(MockBackend::replies(["  Up late, so coffee before work.\n"]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+
+    let explanation = selector.explain_emoji("monday 😴☕ again").unwrap();
+    assert_eq!(explanation, "Up late, so coffee before work.");
+
+    let user = &mock.prompts()[0].user;
+    assert!(user.contains("means: 😴☕"));
+    assert!(user.contains("😴 sleeping face"));
+    assert!(user.contains("Context: monday again."));
+}
+
+#[test]
+fn context_line_is_dropped_for_bare_emojis() {
+    let mock = Arc::new(MockBackend::replies(["Burning out."]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+
+    selector.explain_emoji("🔥🔥").unwrap();
+    let user = &mock.prompts()[0].user;
+    assert!(!user.contains("Context:"));
+    // Repeated emojis are defined once
+    assert_eq!(user.matches("🔥 fire").count(), 1);
+}
+
+#[test]
+fn message_without_emoji_is_rejected_before_generating() {
+    let mock = Arc::new(MockBackend::replies(["unused"]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+
+    let err = selector.explain_emoji("no pictures here").unwrap_err();
+    assert!(matches!(err, EmoError::InvalidInput(_)));
+    assert!(mock.prompts().is_empty());
+}
+
+fn cli(script: &str) -> (TempDir, Command) {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, script).unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()));
+    (temp_dir, cmd)
+}
+
+#[test]
+fn cli_single_emoji_shows_definition_then_meaning() {
+    let (_temp_dir, mut cmd) = cli(r#"{"responses":[{"tokens":["This is ","a hot take."]}]}"#);
+    cmd.args(["--ai", "--explain-emoji", "that take 🔥"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("🔥 - fire").and(predicate::str::ends_with("This is a hot take.\n")));
+}
+
+#[test]
+fn cli_falls_back_to_definitions_without_a_model() {
+    let (_temp_dir, mut cmd) = cli(r#"{"responses":[{"error":"model crashed"}]}"#);
+    cmd.args(["--ai", "--explain-emoji", "😴🔥"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("😴 sleeping face").and(predicate::str::contains("🔥 fire")))
+        .stderr(predicate::str::contains("model crashed"));
+}
@@ -0,0 +1,143 @@
+// Following ADD: Few-shot examples from memos and AI feedback
+
+use assert_cmd::prelude::*;
+use emo::feedback::FeedbackLog;
+use emo::fewshot::{self, Example};
+use predicates::prelude::*;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn example(situation: &str, emoji: &str) -> Example {
+    Example {
+        situation: situation.to_string(),
+        emoji: emoji.to_string(),
+    }
+}
+
+#[test]
+fn similarity_matches_word_stems() {
+    assert_eq!(fewshot::similarity("deploy", "deploying to production"), 1.0);
+    assert_eq!(fewshot::similarity("bug", "monday morning"), 0.0);
+}
+
+#[test]
+fn select_prefers_relevant_examples() {
+    let examples = vec![
+        example("coffee", "☕"),
+        example("deploy", "🚀"),
+        example("bug", "🐞"),
+    ];
+
+    let selected = fewshot::select("fixed a nasty bug before deploying", examples, 5);
+    assert_eq!(selected, vec![example("deploy", "🚀"), example("bug", "🐞")]);
+}
+
+#[test]
+fn select_respects_limit() {
+    let examples = vec![example("deploy", "🚀"), example("deploy prod", "🛳")];
+    assert_eq!(fewshot::select("deploy", examples, 1).len(), 1);
+}
+
+#[test]
+fn judge_without_suggestion_fails() {
+    let mut log = FeedbackLog::default();
+    assert!(log.judge(&[], true).is_err());
+}
+
+#[test]
+fn judge_records_whole_suggestion_and_replaces_verdicts() {
+    let mut log = FeedbackLog::default();
+    log.remember("shipping", &["🚢".to_string(), "📦".to_string()]);
+
+    log.judge(&[], true).unwrap();
+    log.judge(&["📦".to_string()], false).unwrap();
+
+    assert_eq!(log.accepted().count(), 1);
+    assert_eq!(fewshot::rejected_for("shipping today", &log), vec!["📦".to_string()]);
+}
+
+#[test]
+fn concurrent_feedback_updates_keep_every_verdict() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("feedback.json");
+
+    let threads: Vec<_> = (0..16)
+        .map(|i| {
+            let path = path.clone();
+            std::thread::spawn(move || {
+                FeedbackLog::update(&path, |log| {
+                    log.remember(&format!("situation {}", i), &["🚀".to_string()]);
+                    log.judge(&[], true)
+                })
+                .unwrap();
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    assert_eq!(FeedbackLog::load_from(&path).unwrap().accepted().count(), 16);
+    let leftovers = fs::read_dir(temp_dir.path())
+        .unwrap()
+        .filter(|e| e.as_ref().unwrap().file_name().to_string_lossy().ends_with(".tmp"))
+        .count();
+    assert_eq!(leftovers, 0);
+}
+
+#[test]
+fn accept_flag_records_last_suggestion() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+    fs::write(
+        config_dir.join("feedback.json"),
+        r#"{"records":[],"last":{"situation":"deploy","emojis":["🚀"]}}"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["--ai", "--accept"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("deploy ➡ 🚀 accepted"));
+
+    let saved = fs::read_to_string(config_dir.join("feedback.json")).unwrap();
+    assert!(saved.contains("\"accepted\": true"));
+}
+
+#[test]
+fn search_fallback_is_not_remembered_as_an_ai_suggestion() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":"missing:model"}"#).unwrap();
+
+    // The model can't be used, so search answers instead
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["--ai", "fire"]);
+    cmd.assert().success().stdout("🔥\n");
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["--ai", "--accept"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("No AI suggestion"));
+}
+
+#[test]
+fn reject_flag_without_suggestion_errors() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["--ai", "--reject"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("No AI suggestion"));
+}
@@ -24,8 +24,9 @@ fn test_llama_cpp_2_model_loads_modern_gguf() {
 
     // The key is that it shouldn't crash with an assertion failure
     // Even if it fails to generate (no model downloaded), it should fail cleanly
+    // and fall back to search with a warning
     cmd.assert()
-        .failure() // Expected to fail without model downloaded
+        .success() // Falls back to search without the model downloaded
         .stderr(predicate::str::contains("Downloading").or(
             predicate::str::contains("Failed to download")
         ))
@@ -0,0 +1,150 @@
+// Following ADD: Exercise the AI pipeline against the scripted backend
+
+use assert_cmd::prelude::*;
+use emo::ai::AiEmojiSelector;
+use emo::backend::mock::{MockResponse, MockScript, MockToken};
+use emo::backend::MockBackend;
+use emo::feedback::FeedbackLog;
+use predicates::prelude::*;
+use std::fs;
+use std::process::Command;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn selector(mock: &Arc<MockBackend>) -> AiEmojiSelector {
+    AiEmojiSelector::with_backend(Box::new(Arc::clone(mock)))
+}
+
+#[test]
+fn emoji_split_across_tokens_is_decoded() {
+    // 🚀 is F0 9F 9A 80, delivered in two tokens like a real tokenizer would
+    let mock = Arc::new(MockBackend::new(MockScript {
+        responses: vec![MockResponse {
+            tokens: vec![MockToken::Bytes(vec![0xF0, 0x9F]), MockToken::Bytes(vec![0x9A, 0x80])],
+            error: None,
+        }],
+        record: None,
+    }));
+
+    assert_eq!(selector(&mock).select_emoji_llm("launch").unwrap(), "🚀");
+}
+
+#[test]
+fn prompt_contains_situation() {
+    let mock = Arc::new(MockBackend::replies(["🌧"]));
+    selector(&mock).select_emoji_llm("rainy monday").unwrap();
+
+    let prompts = mock.prompts();
+    assert_eq!(prompts.len(), 1);
+    assert!(prompts[0].user.contains("rainy monday"));
+}
+
+#[test]
+fn excluded_emoji_are_skipped_and_listed_in_prompt() {
+    let mock = Arc::new(MockBackend::replies(["Sure: 🚀 🎉"]));
+    let exclude = vec!["🚀".to_string()];
+
+    let emoji = selector(&mock)
+        .select_emoji_with_exclusions("launch party", &exclude)
+        .unwrap();
+
+    assert_eq!(emoji, "🎉");
+    assert!(mock.prompts()[0].user.contains("Do not use: 🚀"));
+}
+
+#[test]
+fn rejected_picks_are_skipped_even_if_the_model_repeats_them() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut log = FeedbackLog::default();
+    log.remember("shipping", &["📦".to_string()]);
+    log.judge(&[], false).unwrap();
+    log.save_to(&temp_dir.path().join("feedback.json")).unwrap();
+
+    let mock = Arc::new(MockBackend::replies(["📦 🚢"]));
+    let emoji = selector(&mock)
+        .with_paths(temp_dir.path().join("config.json"), temp_dir.path().join("models"))
+        .select_emoji_llm("shipping today")
+        .unwrap();
+
+    assert_eq!(emoji, "🚢");
+    assert!(mock.prompts()[0].user.contains("Do not use: 📦"));
+}
+
+#[test]
+fn config_is_read_once_per_selector() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = temp_dir.path().join("config.json");
+    let prompt = |user: &str| format!(r#"{{"mappings":{{}},"prompts":{{"*":{{"emoji":{{"user":"{}"}}}}}}}}"#, user);
+    fs::write(&config, prompt("first: {situation}")).unwrap();
+
+    let mock = Arc::new(MockBackend::replies(["🚀", "🎉"]));
+    let selector = selector(&mock).with_paths(config.clone(), temp_dir.path().join("models"));
+    selector.select_emoji_llm("launch").unwrap();
+    fs::write(&config, prompt("second: {situation}")).unwrap();
+    selector.select_emoji_llm("party").unwrap();
+
+    let prompts = mock.prompts();
+    assert_eq!(prompts[0].user, "first: launch");
+    assert_eq!(prompts[1].user, "first: party");
+}
+
+#[test]
+fn text_without_emoji_is_an_error() {
+    let mock = Arc::new(MockBackend::replies(["I cannot help with that."]));
+    let err = selector(&mock).select_emoji_llm("anything").unwrap_err();
+    assert!(err.to_string().contains("did not generate an emoji"));
+}
+
+#[test]
+fn scripted_errors_propagate() {
+    let mock = Arc::new(MockBackend::new(MockScript {
+        responses: vec![MockResponse {
+            tokens: vec![],
+            error: Some("model crashed".to_string()),
+        }],
+        record: None,
+    }));
+
+    let err = selector(&mock).select_emoji_llm("anything").unwrap_err();
+    assert!(err.to_string().contains("model crashed"));
+}
+
+#[test]
+fn sentence_stops_at_requested_length() {
+    let mock = Arc::new(MockBackend::replies(["😴☕💼😅🏃🎉"]));
+    let sentence = selector(&mock).generate_emoji_sentence("monday morning", 3).unwrap();
+    assert_eq!(sentence, "😴☕💼");
+}
+
+#[test]
+fn cli_uses_scripted_backend_for_each_pick() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["🐛"]},{"tokens":["🐛","🔍"]}]}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()));
+    cmd.args(["--ai", "-c", "2", "debugging"]);
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().collect::<Vec<_>>(), vec!["🐛", "🔍"]);
+}
+
+#[test]
+fn unknown_backend_override_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", "carrier-pigeon");
+    cmd.args(["--ai", "-s", "3", "coffee"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown EMO_AI_BACKEND"));
+}
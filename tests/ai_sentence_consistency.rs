@@ -54,21 +54,8 @@ fn test_sentence_length() {
         let output = cmd.output().unwrap();
         let stdout = String::from_utf8(output.stdout).unwrap().trim().to_string();
 
-        // Count emoji characters (this is approximate - some emojis are multi-codepoint)
-        let emoji_count = stdout.chars().filter(|c| {
-            matches!((*c as u32),
-                0x1F300..=0x1F9FF | // Emoticons & misc
-                0x2600..=0x26FF |   // Misc symbols
-                0x2700..=0x27BF |   // Dingbats
-                0x1F000..=0x1F02F | // Mahjong/Domino
-                0x1FA70..=0x1FAFF   // More symbols
-            )
-        }).count();
-
-        // Allow some flexibility due to multi-codepoint emojis
-        assert!(
-            emoji_count >= length - 2 && emoji_count <= length + 2,
-            "Expected ~{} emojis, got {} in: {}", length, emoji_count, stdout
-        );
+        // Grapheme clusters, so ZWJ sequences count once
+        let emoji_count = emo::unicode::emojis(&stdout).count();
+        assert_eq!(emoji_count, length, "Expected {} emojis in: {}", length, stdout);
     }
 }
\ No newline at end of file
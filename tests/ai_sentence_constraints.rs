@@ -0,0 +1,109 @@
+// Following ADD: Exact-length emoji sentences with user constraints
+
+use assert_cmd::prelude::*;
+use emo::ai::{AiEmojiSelector, SentenceOptions};
+use emo::backend::MockBackend;
+use std::fs;
+use std::process::Command;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn sentence(replies: &[&str], options: &SentenceOptions) -> emo::error::Result<String> {
+    let mock = MockBackend::replies(replies.iter().copied());
+    AiEmojiSelector::with_backend(Box::new(mock)).generate_sentence("a vampire eating a pear", options)
+}
+
+#[test]
+fn short_output_is_continued_to_exact_length() {
+    let mock = Arc::new(MockBackend::replies(["🧛🍐", "🧛🍐💘🌙🦇"]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+
+    let result = selector.generate_sentence("a vampire eating a pear", &SentenceOptions::new(4)).unwrap();
+    assert_eq!(result, "🧛🍐💘🌙");
+
+    // The second attempt continues from what the first one produced
+    let prompts = mock.prompts();
+    assert_eq!(prompts.len(), 2);
+    assert!(prompts[1].user.contains("Start with: 🧛🍐"));
+}
+
+#[test]
+fn zwj_sequences_count_as_one_emoji() {
+    let result = sentence(&["👨‍👩‍👧🏖️🌊"], &SentenceOptions::new(2)).unwrap();
+    assert_eq!(result, "👨‍👩‍👧🏖️");
+}
+
+#[test]
+fn no_repeat_skips_duplicates() {
+    let options = SentenceOptions {
+        no_repeat: true,
+        ..SentenceOptions::new(3)
+    };
+    assert_eq!(sentence(&["🎂🎂🎉🎉🎈"], &options).unwrap(), "🎂🎉🎈");
+}
+
+#[test]
+fn included_emoji_gets_a_slot() {
+    let options = SentenceOptions {
+        include: vec!["🍐".to_string()],
+        ..SentenceOptions::new(3)
+    };
+
+    // Generated late, it still makes the cut
+    assert_eq!(sentence(&["🧛🦇🌙🍐"], &options).unwrap(), "🧛🦇🍐");
+    // Never generated, it fills the slot kept for it
+    assert_eq!(sentence(&["🧛🦇🌙"], &options).unwrap(), "🧛🦇🍐");
+}
+
+#[test]
+fn start_with_leads_the_sentence() {
+    let options = SentenceOptions {
+        start_with: Some("🧛".to_string()),
+        ..SentenceOptions::new(3)
+    };
+    let mock = Arc::new(MockBackend::replies(["🍐🌙"]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+
+    assert_eq!(selector.generate_sentence("a vampire eating a pear", &options).unwrap(), "🧛🍐🌙");
+    assert!(mock.prompts()[0].user.contains("Start with: 🧛"));
+}
+
+#[test]
+fn gives_up_when_model_never_produces_emoji() {
+    let err = sentence(&["I only speak in words."], &SentenceOptions::new(3)).unwrap_err();
+    assert!(err.to_string().contains("got 0 of 3"));
+}
+
+#[test]
+fn impossible_constraints_are_rejected() {
+    let options = SentenceOptions {
+        include: vec!["🍐".to_string(), "🦇".to_string()],
+        start_with: Some("🧛".to_string()),
+        ..SentenceOptions::new(2)
+    };
+    assert!(sentence(&["🍐"], &options).is_err());
+
+    let options = SentenceOptions {
+        include: vec!["pear".to_string()],
+        ..SentenceOptions::new(2)
+    };
+    let err = sentence(&["🍐"], &options).unwrap_err();
+    assert!(err.to_string().contains("not a single emoji"));
+}
+
+#[test]
+fn cli_applies_sentence_constraints() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["🧛","🧛","💘","🌙","🦇"]}]}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()));
+    cmd.args(["--ai", "-s", "4", "--no-repeat", "--include", "🍐", "--start-with", "🧛", "vampire"]);
+    cmd.assert().success().stdout("🧛💘🌙🍐\n");
+}
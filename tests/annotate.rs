@@ -0,0 +1,112 @@
+// Following ADD: Decorate prose with AI-picked emoji
+
+use assert_cmd::prelude::*;
+use emo::ai::AiEmojiSelector;
+use emo::annotate::{self, Density, Placement};
+use emo::backend::MockBackend;
+use emo::unicode;
+use std::fs;
+use std::process::Command;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+const NOTES: &str = "Release 2.5 is out. It fixes the login bug. Docs were updated too!\n\
+                     \n\
+                     - Faster search across all emoji\n\
+                     - New annotate command. Try it on your standup notes.\n";
+
+fn contexts(text: &str, density: Density) -> Vec<String> {
+    annotate::slots(text, density).into_iter().map(|s| s.context).collect()
+}
+
+#[test]
+fn density_controls_how_many_sentences_are_decorated() {
+    assert_eq!(
+        contexts(NOTES, Density::Low),
+        vec![
+            "Release 2.5 is out",
+            "Faster search across all emoji",
+            "New annotate command. Try it on your standup notes.",
+        ]
+    );
+    assert_eq!(contexts(NOTES, Density::Medium).len(), 4);
+    assert_eq!(contexts(NOTES, Density::High).len(), 6);
+}
+
+#[test]
+fn bullets_get_emoji_after_the_marker() {
+    let slots = annotate::slots("  * tidy up\n12. ship it\n", Density::Low);
+    assert_eq!(slots[0].placement, Placement::Before);
+    assert_eq!(slots[0].offset, 4);
+    assert_eq!(slots[1].offset, "  * tidy up\n12. ".len());
+}
+
+#[test]
+fn phrases_get_emoji_before_punctuation() {
+    let text = "Shipped v2.1 today! Wrapped lines\ncontinue the paragraph.";
+    let slots = annotate::slots(text, Density::High);
+    let emojis = vec![Some("🚀".to_string()), Some("📝".to_string())];
+    assert_eq!(
+        annotate::apply(text, &slots, &emojis),
+        "Shipped v2.1 today 🚀! Wrapped lines\ncontinue the paragraph 📝."
+    );
+}
+
+#[test]
+fn phrases_that_already_have_emoji_are_skipped() {
+    assert!(annotate::slots("- 🐛 fixed the crash\nAll good ✅.", Density::High).is_empty());
+}
+
+#[test]
+fn original_text_is_kept_apart_from_insertions() {
+    let mock = MockBackend::replies(["🚀", "🐛", "📚", "⚡", "🆕", "📝"]);
+    let selector = AiEmojiSelector::with_backend(Box::new(mock));
+    let annotated = annotate::annotate(NOTES, Density::High, &selector).unwrap();
+
+    // Removing the inserted emoji and their spaces gives back the input
+    let mut restored = annotated.clone();
+    for emoji in unicode::emojis(&annotated) {
+        restored = restored.replacen(&format!(" {}", emoji), "", 1).replacen(&format!("{} ", emoji), "", 1);
+    }
+    assert_eq!(restored, NOTES);
+    assert!(annotated.contains("- ⚡ Faster search"));
+    assert!(annotated.contains("Release 2.5 is out 🚀."));
+}
+
+#[test]
+fn picks_are_not_repeated_within_a_document() {
+    let mock = Arc::new(MockBackend::replies(["🚀", "🚀 🎉"]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+
+    let annotated = annotate::annotate("Shipped it. Shipped it again.", Density::High, &selector).unwrap();
+    assert_eq!(annotated, "Shipped it 🚀. Shipped it again 🎉.");
+    assert!(mock.prompts()[1].user.contains("Do not use: 🚀"));
+}
+
+#[test]
+fn cli_annotates_stdin_with_one_model_load() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+
+    // A backend connected per line would start the script over each time
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["📋"]},{"tokens":["☕"]},{"tokens":["🐛"]},{"tokens":["🚢"]}]}"#).unwrap();
+
+    let mut cmd = assert_cmd::Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()));
+    cmd.args(["annotate", "--density", "low"]);
+    cmd.write_stdin("Standup:\n- coffee first\n- fixed the flaky test\n- shipped the release\n");
+    cmd.assert()
+        .success()
+        .stdout("Standup 📋:\n- ☕ coffee first\n- 🐛 fixed the flaky test\n- 🚢 shipped the release\n");
+}
+
+#[test]
+fn cli_rejects_unknown_density() {
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.args(["annotate", "--density", "extreme", "hello"]);
+    cmd.assert().failure();
+}
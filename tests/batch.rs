@@ -0,0 +1,137 @@
+// Following ADD: Batch mode and structured output formats
+
+use assert_cmd::Command;
+use emo::batch::{Batch, Format, Mode, Record};
+use emo::Emo;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn record() -> Record {
+    Record {
+        query: "fire\tpit".to_string(),
+        results: vec!["🔥".to_string(), "🚒".to_string()],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn records_render_in_each_format() {
+    assert_eq!(record().render(Format::Text), "🔥 🚒");
+    assert_eq!(record().render(Format::Tsv), "fire pit\t🔥\t🚒");
+    assert_eq!(record().render(Format::Json), r#"{"query":"fire\tpit","results":["🔥","🚒"]}"#);
+
+    let failed = Record {
+        query: "x".to_string(),
+        error: Some("boom".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(failed.render(Format::Json), r#"{"query":"x","results":[],"error":"boom"}"#);
+    assert_eq!(failed.render(Format::Text), "");
+}
+
+#[test]
+fn define_records_carry_name_and_definition() {
+    let temp_dir = TempDir::new().unwrap();
+    let emo = Emo::builder().config_path(temp_dir.path().join("config.json")).build().unwrap();
+    let mut batch = Batch::new(Mode::Define, emo).unwrap();
+    let record = batch.run("🔥");
+    assert_eq!(record.results, vec!["🔥"]);
+    assert_eq!(record.name.as_deref(), Some("fire"));
+    assert!(record.render(Format::Text).starts_with("🔥 - fire"));
+    assert!(batch.run("   ").results.is_empty());
+}
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(config_dir.join("config.json"), r#"{"mappings":{"deploy":"🚀"},"model":null}"#).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd
+}
+
+#[test]
+fn one_line_out_per_line_in() {
+    let temp_dir = TempDir::new().unwrap();
+    let queries = temp_dir.path().join("queries.txt");
+    fs::write(&queries, "deploy\n\nfire\n").unwrap();
+
+    let output = emo(&temp_dir).arg("--batch").arg(&queries).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines, vec!["🚀", "", "🔥"]);
+}
+
+#[test]
+fn stdin_batch_in_tsv() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["--batch", "--format", "tsv", "-d"])
+        .write_stdin("🔥\r\nsleeping\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("🔥\t🔥\tfire\t").and(predicate::str::contains("\nsleeping\t😴\tsleeping face\n")));
+}
+
+#[test]
+fn failures_are_reported_without_stopping() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["--batch", "--format", "json", "-m", "1"])
+        .write_stdin("fire\nzzqqxx\nsleeping\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#"{"query":"zzqqxx","results":[],"error":"#))
+        .stderr(predicate::str::contains("Warning: 'zzqqxx'"));
+
+    // Memos from the batch are saved together at the end
+    let config = fs::read_to_string(temp_dir.path().join("emo/config.json")).unwrap();
+    assert!(config.contains("\"fire\": \"🔥\"") && config.contains("\"sleeping\": \"😴\""));
+    assert!(config.contains("\"deploy\": \"🚀\""));
+}
+
+#[test]
+fn ai_batch_loads_the_model_once() {
+    let temp_dir = TempDir::new().unwrap();
+    // A backend connected per line would start the script over each time
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["🐛"]},{"tokens":["🚀"]}]}"#).unwrap();
+
+    emo(&temp_dir)
+        .env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()))
+        .args(["--ai", "--batch"])
+        .write_stdin("fix the crash\nship it\n")
+        .assert()
+        .success()
+        .stdout("🐛\n🚀\n");
+}
+
+#[test]
+fn sentence_batch_reuses_one_selector() {
+    let temp_dir = TempDir::new().unwrap();
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["🧛🍐"]},{"tokens":["🌙🦇"]}]}"#).unwrap();
+
+    emo(&temp_dir)
+        .env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()))
+        .args(["--ai", "-s", "2", "--batch", "--format", "json"])
+        .write_stdin("vampire pear\nnight\n")
+        .assert()
+        .success()
+        .stdout("{\"query\":\"vampire pear\",\"results\":[\"🧛🍐\"]}\n{\"query\":\"night\",\"results\":[\"🌙🦇\"]}\n");
+}
+
+#[test]
+fn single_query_in_json() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["--format", "json", "deploy"])
+        .assert()
+        .success()
+        .stdout("{\"query\":\"deploy\",\"results\":[\"🚀\"]}\n");
+}
@@ -0,0 +1,157 @@
+// Following ADD: Emoji changelogs from conventional commits
+
+use assert_cmd::prelude::*;
+use emo::ai::AiEmojiSelector;
+use emo::backend::MockBackend;
+use emo::changelog::{self, Entry, Pick};
+use emo::memos::MemoIndex;
+use emo::EmojiDatabase;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn subjects(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn subjects_are_parsed_into_entries() {
+    let entry = Entry::parse("fix(parser): handle empty input");
+    assert_eq!(entry.section, "fix");
+    assert_eq!(entry.scope.as_deref(), Some("parser"));
+    assert_eq!(entry.description, "handle empty input");
+    assert_eq!(entry.emoji, None);
+
+    assert_eq!(Entry::parse("feat!: drop v1 syntax").section, "breaking");
+    assert_eq!(Entry::parse("wip: half done").section, "other");
+
+    // Gitmoji prefixes are kept, codes become emoji, and they imply a section
+    let entry = Entry::parse(":bug: crash on empty config");
+    assert_eq!((entry.section, entry.emoji.as_deref()), ("fix", Some("🐛")));
+    assert_eq!(entry.description, "crash on empty config");
+}
+
+#[test]
+fn oneline_hashes_are_dropped() {
+    assert_eq!(
+        changelog::subjects("a1b2c3d feat: gitmoji\n\nadded cafe menu\n"),
+        vec!["feat: gitmoji", "added cafe menu"]
+    );
+}
+
+#[test]
+fn sections_follow_changelog_order() {
+    let rendered = changelog::render(
+        &subjects(&["chore: tidy up", "fix(parser): rename module files", "feat!: drop v1 syntax", "✨ feat: gitmoji mode"]),
+        Pick::Gitmoji,
+        &MemoIndex::default(),
+        &EmojiDatabase::default(),
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        rendered,
+        "## 💥 Breaking Changes\n\n- drop v1 syntax\n\n\
+         ## ✨ Features\n\n- ✨ gitmoji mode\n\n\
+         ## 🐛 Bug Fixes\n\n- 🚚 **parser:** rename module files\n\n\
+         ## 🔧 Chores\n\n- tidy up\n"
+    );
+}
+
+#[test]
+fn memos_override_section_emoji() {
+    let memos = MemoIndex::new([("feat".to_string(), '🚀'), ("other".to_string(), '📦')]);
+    let database = EmojiDatabase::default();
+    let rendered = changelog::render(&subjects(&["feat: gitmoji", "bump"]), Pick::Gitmoji, &memos, &database, None).unwrap();
+    assert!(rendered.contains("## 🚀 Features"));
+    assert!(rendered.contains("## 📦 Other Changes"));
+}
+
+#[test]
+fn ai_picks_entry_emoji() {
+    let selector = AiEmojiSelector::with_backend(Box::new(MockBackend::replies(["☕", "🧪"])));
+    let rendered = changelog::render(
+        &subjects(&["feat: coffee mode", "🐛 fix: flaky test", "test: cover parser"]),
+        Pick::Ai,
+        &MemoIndex::default(),
+        &EmojiDatabase::default(),
+        Some(&selector),
+    )
+    .unwrap();
+    assert!(rendered.contains("- ☕ coffee mode"));
+    assert!(rendered.contains("- 🐛 flaky test"));
+    assert!(rendered.contains("- 🧪 cover parser"));
+
+    let err = changelog::render(&subjects(&["feat: x"]), Pick::Ai, &MemoIndex::default(), &EmojiDatabase::default(), None)
+        .unwrap_err();
+    assert!(err.to_string().contains("needs a model"));
+}
+
+fn config(temp_dir: &Path, json: &str) {
+    let config_dir = temp_dir.join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), json).unwrap();
+}
+
+#[test]
+fn cli_reads_subjects_from_stdin() {
+    let temp_dir = TempDir::new().unwrap();
+    config(temp_dir.path(), r#"{"mappings":{"fix":"🩹"},"model":null}"#);
+
+    let mut cmd = assert_cmd::Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.arg("changelog");
+    cmd.write_stdin("0c1d2e3 fix: typo in help\n");
+    cmd.assert().success().stdout("## 🩹 Bug Fixes\n\n- ✏️ typo in help\n");
+}
+
+#[test]
+fn cli_section_emoji_follow_the_active_memos() {
+    let temp_dir = TempDir::new().unwrap();
+    config(
+        temp_dir.path(),
+        r#"{"mappings":{"fix":"🩹"},"profiles":{"work":{"mappings":{"fix":"🐛","do*":"📖"}}},"model":null}"#,
+    );
+
+    let mut cmd = assert_cmd::Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["changelog", "--profile", "work"]);
+    cmd.write_stdin("fix: typo in help\ndocs: readme\n");
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("## 🐛 Bug Fixes"))
+        .stdout(predicates::str::contains("## 📖 Documentation"));
+}
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=emo", "-c", "user.email=emo@example.com"])
+        .args(args)
+        .current_dir(repo)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn cli_reads_git_log_range() {
+    let temp_dir = TempDir::new().unwrap();
+    config(temp_dir.path(), r#"{"mappings":{},"model":null}"#);
+    let repo = temp_dir.path().join("repo");
+    fs::create_dir_all(&repo).unwrap();
+    git(&repo, &["init", "-q"]);
+    git(&repo, &["commit", "-q", "--allow-empty", "-m", "feat: first release"]);
+    git(&repo, &["tag", "v1"]);
+    git(&repo, &["commit", "-q", "--allow-empty", "-m", "perf: faster search"]);
+    git(&repo, &["commit", "-q", "--allow-empty", "-m", "docs: changelog"]);
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.current_dir(&repo);
+    cmd.args(["changelog", "v1..HEAD"]);
+    cmd.assert()
+        .success()
+        .stdout("## ⚡️ Performance\n\n- faster search\n\n## 📝 Documentation\n\n- changelog\n");
+}
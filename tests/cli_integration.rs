@@ -105,8 +105,13 @@ fn test_ai_flag_ignores_memo() {
     let config_path = config_dir.join("config.json");
     fs::write(&config_path, r#"{"mappings":{"test":"🧪"},"model":"llama-3.2-1b"}"#).unwrap();
 
+    // Scripted backend stands in for the model
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["✅"]}]}"#).unwrap();
+
     let mut cmd = Command::cargo_bin("emo").unwrap();
     cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()));
     cmd.args(&["--ai", "test"]);
 
     // AI mode should succeed and NOT return the memo emoji 🧪
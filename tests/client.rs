@@ -0,0 +1,156 @@
+// Following ADD: Library client with explicit paths, overlays and backend
+
+use emo::backend::MockBackend;
+use emo::memos::Memo;
+use emo::{Emo, EmojiMappings, EmojiRecord};
+use std::fs;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn client(temp_dir: &TempDir) -> Emo {
+    Emo::builder()
+        .config_path(temp_dir.path().join("config.json"))
+        .build()
+        .unwrap()
+}
+
+fn record(unicode: &str, name: &str, keywords: &[&str], definition: Option<&str>) -> EmojiRecord {
+    EmojiRecord {
+        keywords: keywords.iter().map(|k| k.to_string()).collect(),
+        unicode: unicode.to_string(),
+        name: name.to_string(),
+        shortcode: None,
+        definition: definition.map(str::to_string),
+    }
+}
+
+#[test]
+fn memos_persist_to_the_given_config_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("config.json");
+
+    let mut emo = client(&temp_dir);
+    assert_eq!(emo.set_memo("deploy", "🚀").unwrap(), '🚀');
+    assert_eq!(emo.memo("deploy"), Some("🚀"));
+
+    let saved = EmojiMappings::load_from(&path).unwrap();
+    assert_eq!(saved.mappings.get("deploy"), Some(&Memo::from('🚀')));
+
+    // A fresh client sees it, and removing it writes the file again
+    let mut emo = client(&temp_dir);
+    assert_eq!(emo.memos(), vec![("deploy", &Memo::from('🚀'))]);
+    assert!(emo.remove_memo("deploy").unwrap());
+    assert!(!emo.remove_memo("deploy").unwrap());
+    assert!(emo.remove_memo("").is_err());
+    assert!(EmojiMappings::load_from(&path).unwrap().mappings.is_empty());
+}
+
+#[test]
+fn search_puts_memos_first() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut emo = client(&temp_dir);
+    emo.set_memo("fire", "🚒").unwrap();
+
+    let results = emo.search("fire", 2).unwrap();
+    assert_eq!(results[0], "🚒");
+    assert!(results.contains(&"🔥".to_string()));
+}
+
+#[test]
+fn clients_with_different_configs_are_independent() {
+    let (first_dir, second_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+    let mut first = client(&first_dir);
+    let second = client(&second_dir);
+
+    first.set_memo("fire", "🚒").unwrap();
+    assert_eq!(first.search("fire", 1).unwrap(), vec!["🚒"]);
+    assert_eq!(second.search("fire", 1).unwrap(), vec!["🔥"]);
+    assert!(second.memos().is_empty());
+}
+
+#[test]
+fn overlays_replace_and_extend_the_bundled_data() {
+    let temp_dir = TempDir::new().unwrap();
+    let overlay = temp_dir.path().join("overlay.json");
+    let extra = vec![record("U+1FAE8", "shaking face", &["quake"], None)];
+    std::fs::write(&overlay, serde_json::to_string(&extra).unwrap()).unwrap();
+
+    let emo = Emo::builder()
+        .config_path(temp_dir.path().join("config.json"))
+        .overlay(vec![record("U+1F525", "fire", &["fire", "flame"], Some("Our build status."))])
+        .overlay_file(&overlay)
+        .build()
+        .unwrap();
+
+    let (_, fire) = emo.define("🔥").unwrap();
+    assert_eq!(fire.definition.as_deref(), Some("Our build status."));
+    assert_eq!(emo.emojis().iter().filter(|e| e.unicode == "U+1F525").count(), 1);
+    assert_eq!(emo.search("quake", 1).unwrap(), vec!["🫨"]);
+}
+
+#[test]
+fn missing_overlay_file_is_an_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let result = Emo::builder()
+        .config_path(temp_dir.path().join("config.json"))
+        .overlay_file(temp_dir.path().join("missing.json"))
+        .build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn define_and_random_use_the_client_data() {
+    let temp_dir = TempDir::new().unwrap();
+    let emo = client(&temp_dir);
+
+    let (ch, fire) = emo.define("🔥").unwrap();
+    assert_eq!((ch, fire.name.as_str()), ('🔥', "fire"));
+
+    let (ch, random) = emo.random().unwrap();
+    assert_eq!(emo::to_char(random).unwrap(), ch);
+}
+
+#[test]
+fn ai_uses_the_given_backend_and_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock = Arc::new(MockBackend::replies(["🚀"]));
+    let emo = Emo::builder()
+        .config_path(temp_dir.path().join("config.json"))
+        .backend(Box::new(mock.clone()))
+        .build()
+        .unwrap();
+
+    let picks = emo.ai("shipping the release", 1).unwrap();
+    assert_eq!(picks[0].emoji, "🚀");
+    assert!(mock.prompts()[0].user.contains("shipping the release"));
+    assert_eq!(emo.model_dir(), temp_dir.path().join("models"));
+
+    // Feedback lives next to the config, not in the user's config directory
+    emo.remember("shipping the release", &["🚀".to_string()]).unwrap();
+    let judged = emo.judge(&[], true).unwrap();
+    assert_eq!(judged[0].emoji, "🚀");
+    assert!(temp_dir.path().join("feedback.json").exists());
+}
+
+#[test]
+fn ensembles_use_the_given_backend() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("config.json"), r#"{"mappings":{},"ensemble":["phi","qwen"]}"#).unwrap();
+    let mock = Arc::new(MockBackend::replies(["🚀"]));
+    let emo = Emo::builder()
+        .config_path(temp_dir.path().join("config.json"))
+        .backend(Box::new(mock.clone()))
+        .build()
+        .unwrap();
+    assert_eq!(emo.ai("shipping the release", 1).unwrap()[0].emoji, "🚀");
+
+    let mock = Arc::new(MockBackend::replies(["🛳"]));
+    let emo = Emo::builder()
+        .config_path(temp_dir.path().join("config.json"))
+        .models(vec!["phi".to_string(), "qwen".to_string()])
+        .backend(Box::new(mock.clone()))
+        .build()
+        .unwrap();
+    assert_eq!(emo.ai("shipping the release", 1).unwrap()[0].emoji, "🛳");
+    assert_eq!(mock.prompts().len(), 1);
+}
@@ -0,0 +1,220 @@
+// Following ADD: Config get/set/unset/edit, schema versions and EMO_* overrides
+
+use assert_cmd::Command;
+use emo::batch::Format;
+use emo::config::{self, Overrides};
+use emo::memos::Memo;
+use emo::EmojiMappings;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    for var in ["EMO_MODEL", "EMO_COUNT", "EMO_FORMAT", "EMO_STRATEGY", "VISUAL", "EDITOR"] {
+        cmd.env_remove(var);
+    }
+    cmd
+}
+
+fn write_config(temp_dir: &TempDir, text: &str) {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), text).unwrap();
+}
+
+fn config(temp_dir: &TempDir) -> serde_json::Value {
+    let text = fs::read_to_string(temp_dir.path().join("emo").join("config.json")).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn version_one_configs_are_migrated() {
+    let path = Path::new("config.json");
+    let config = config::parse(r#"{"mappings":{"love":"❤"},"model":null}"#, path).unwrap();
+    assert_eq!(config.version, config::CONFIG_VERSION);
+    // Older versions saved "❤" without its selector
+    assert_eq!(config.mappings["love"].first(), "❤️");
+
+    // Older versions took any character; those memos still load
+    let config = config::parse(r#"{"mappings":{"lambda":"λ","done":["✓","✅"]}}"#, path).unwrap();
+    assert_eq!(config.mappings["lambda"].first(), "λ");
+    assert_eq!(config.mappings["done"].emojis(), ["✓", "✅"]);
+    assert!(config::set(&config, "mappings.pi", "π").is_err());
+
+    let err = config::parse(r#"{"version":99,"mappings":{}}"#, path).unwrap_err();
+    assert_eq!(err.exit_code(), 4);
+    assert!(err.to_string().contains("please upgrade emo"));
+}
+
+#[test]
+fn mistakes_name_the_field() {
+    let path = Path::new("config.json");
+    let err = config::parse(r#"{"mappings":{},"modle":"qwen"}"#, path).unwrap_err();
+    assert!(err.to_string().contains("Unknown config key 'modle' in config.json; did you mean 'model'?"));
+
+    let err = config::parse(r#"{"mappings":{},"count":"three"}"#, path).unwrap_err();
+    assert!(err.to_string().contains("Invalid 'count' in config.json"));
+}
+
+#[test]
+fn get_set_unset() {
+    let config = EmojiMappings::default();
+    let config = config::set(&config, "count", "3").unwrap();
+    assert_eq!(config.count, Some(3));
+    let config = config::set(&config, "model", "qwen").unwrap();
+    assert_eq!(config::get(&config, "model").unwrap(), Some("qwen".into()));
+    let config = config::set(&config, "mappings.deploy", "🚀").unwrap();
+    assert_eq!(config.mappings.get("deploy"), Some(&Memo::from('🚀')));
+
+    assert!(config::set(&config, "count", "lots").is_err());
+    // Selectors are kept, and emoji of several characters fit
+    let loved = config::set(&config, "mappings.love", "❤️").unwrap();
+    assert_eq!(loved.mappings["love"].first(), "❤️");
+    let approved = config::set(&config, "mappings.approve", "👍🏽 🎉").unwrap();
+    assert_eq!(approved.mappings["approve"], Memo::new(["👍🏽", "🎉"]).unwrap());
+    assert!(config::set(&config, "mappings.love", "a").is_err());
+    assert!(config::set(&config, "version", "3").is_err());
+
+    let (config, removed) = config::unset(&config, "mappings.deploy").unwrap();
+    assert!(removed);
+    assert!(config.mappings.is_empty());
+    let (_, removed) = config::unset(&config, "format").unwrap();
+    assert!(!removed);
+}
+
+#[test]
+fn overrides_come_from_emo_variables() {
+    let vars = |name: &str| match name {
+        "EMO_COUNT" => Some("4".to_string()),
+        "EMO_FORMAT" => Some("json".to_string()),
+        _ => None,
+    };
+    let overrides = Overrides::from_vars(vars).unwrap();
+    assert_eq!(overrides.count, Some(4));
+    assert_eq!(overrides.format, Some(Format::Json));
+    assert_eq!(overrides.model, None);
+    assert_eq!(overrides.source("count"), Some("EMO_COUNT"));
+
+    let err = Overrides::from_vars(|name| (name == "EMO_COUNT").then(|| "many".to_string())).unwrap_err();
+    assert_eq!(err.exit_code(), 4);
+}
+
+#[test]
+fn config_subcommands_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["config", "set", "count", "2"])
+        .assert()
+        .success()
+        .stdout("count = 2 ✅\n");
+    emo(&temp_dir).args(["config", "get", "count"]).assert().success().stdout("2\n");
+    assert_eq!(config(&temp_dir)["version"], config::CONFIG_VERSION);
+
+    emo(&temp_dir).args(["config", "unset", "count"]).assert().success();
+    emo(&temp_dir).args(["config", "get", "count"]).assert().code(3);
+
+    emo(&temp_dir)
+        .args(["config", "set", "modle", "qwen"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("did you mean 'model'?"));
+}
+
+#[test]
+fn flags_beat_environment_beat_config() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).args(["config", "set", "count", "2"]).assert().success();
+    let lines = |cmd: &mut Command| {
+        String::from_utf8(cmd.assert().success().get_output().stdout.clone()).unwrap().lines().count()
+    };
+
+    assert_eq!(lines(emo(&temp_dir).arg("fire")), 2);
+    assert_eq!(lines(emo(&temp_dir).env("EMO_COUNT", "3").arg("fire")), 3);
+    assert_eq!(lines(emo(&temp_dir).env("EMO_COUNT", "3").args(["-c", "1", "fire"])), 1);
+
+    emo(&temp_dir)
+        .env("EMO_COUNT", "3")
+        .args(["config", "get", "count"])
+        .assert()
+        .stdout("3\n")
+        .stderr(predicate::str::contains("(from EMO_COUNT)"));
+
+    emo(&temp_dir)
+        .env("EMO_FORMAT", "json")
+        .arg("fire")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("{"));
+    emo(&temp_dir)
+        .env("EMO_FORMAT", "json")
+        .args(["--format", "text", "fire"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("{").not());
+
+    emo(&temp_dir).env("EMO_COUNT", "many").arg("fire").assert().code(4);
+}
+
+#[test]
+fn old_configs_are_saved_in_the_new_schema() {
+    let temp_dir = TempDir::new().unwrap();
+    write_config(&temp_dir, r#"{"mappings":{"love":"❤","lambda":"λ"},"model":null}"#);
+    emo(&temp_dir).args(["search", "love"]).assert().success().stdout("❤️\n");
+    emo(&temp_dir).args(["search", "lambda"]).assert().success().stdout("λ\n");
+
+    emo(&temp_dir).args(["memo", "add", "🚀", "deploy"]).assert().success();
+    let saved = config(&temp_dir);
+    assert_eq!(saved["version"], config::CONFIG_VERSION);
+    assert_eq!(saved["mappings"]["love"], "❤️");
+    assert_eq!(saved["mappings"]["lambda"], "λ");
+}
+
+#[cfg(unix)]
+#[test]
+fn edit_only_saves_a_valid_config() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new().unwrap();
+    let editor = |name: &str, content: &str| {
+        let script = temp_dir.path().join(name);
+        fs::write(&script, format!("#!/bin/sh\nprintf '%s' '{}' > \"$1\"\n", content)).unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    };
+
+    let good = editor("good.sh", r#"{"version":2,"mappings":{},"count":4}"#);
+    emo(&temp_dir).env("EDITOR", &good).args(["config", "edit"]).assert().success();
+    assert_eq!(config(&temp_dir)["count"], 4);
+
+    let bad = editor("bad.sh", r#"{"version":2,"mappings":{},"cuont":4}"#);
+    emo(&temp_dir)
+        .env("EDITOR", &bad)
+        .args(["config", "edit"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("did you mean 'count'?"));
+    assert_eq!(config(&temp_dir)["count"], 4);
+}
+
+#[cfg(unix)]
+#[test]
+fn edit_starts_from_the_defaults_on_a_fresh_install() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path())
+        .env("EDITOR", "true")
+        .env_remove("VISUAL")
+        .args(["config", "edit"])
+        .assert()
+        .success();
+    assert_eq!(config(&temp_dir)["version"], config::CONFIG_VERSION);
+}
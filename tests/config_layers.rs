@@ -0,0 +1,227 @@
+// Following ADD: Layered system, user and project configs
+
+use assert_cmd::Command;
+use emo::config::{self, Scope};
+use emo::memos::Memo;
+use emo::{Emo, EmojiMappings};
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// A user config dir, a system config and a project with a subdirectory
+struct Setup {
+    temp_dir: TempDir,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("emo")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("project").join("src")).unwrap();
+        Setup { temp_dir }
+    }
+
+    fn path(&self, relative: &str) -> std::path::PathBuf {
+        self.temp_dir.path().join(relative)
+    }
+
+    fn write(&self, relative: &str, text: &str) {
+        fs::write(self.path(relative), text).unwrap();
+    }
+
+    /// emo run from `project/src`
+    fn emo(&self) -> Command {
+        let mut cmd = Command::cargo_bin("emo").unwrap();
+        cmd.env("XDG_CONFIG_HOME", self.temp_dir.path())
+            .env("XDG_CACHE_HOME", self.path("cache"))
+            .env("EMO_SYSTEM_CONFIG", self.path("system.json"))
+            .current_dir(self.path("project/src"));
+        cmd
+    }
+
+    fn read(&self, relative: &str) -> serde_json::Value {
+        serde_json::from_str(&fs::read_to_string(self.path(relative)).unwrap()).unwrap()
+    }
+}
+
+fn mappings(pairs: &[(&str, char)]) -> EmojiMappings {
+    EmojiMappings {
+        mappings: pairs.iter().map(|(t, e)| (t.to_string(), Memo::from(*e))).collect(),
+        ..EmojiMappings::default()
+    }
+}
+
+#[test]
+fn higher_layers_win() {
+    let mut system = mappings(&[("deploy", '📦'), ("fire", '🧯')]);
+    system.model = Some("phi".to_string());
+    system.count = Some(2);
+    let user = mappings(&[("deploy", '🚀')]);
+    let mut project = mappings(&[("deploy", '🛳')]);
+    project.ensemble = vec!["qwen".to_string(), "llama".to_string()];
+
+    let merged = config::merge([&system, &user, &project]);
+    assert_eq!(merged.mappings.get("deploy"), Some(&Memo::from('🛳')));
+    assert_eq!(merged.mappings.get("fire"), Some(&Memo::from('🧯')));
+    assert_eq!(merged.count, Some(2));
+    // `model` and `ensemble` come from the same layer
+    assert_eq!(merged.model, None);
+    assert_eq!(merged.ensemble, vec!["qwen", "llama"]);
+}
+
+#[test]
+fn project_config_is_found_walking_up() {
+    let setup = Setup::new();
+    let src = setup.path("project/src");
+    assert_eq!(config::find_project(&src), None);
+    assert_eq!(config::new_project_path(&src), src.join(".emo.json"));
+
+    fs::create_dir(setup.path("project/.git")).unwrap();
+    assert_eq!(config::new_project_path(&src), setup.path("project/.emo.json"));
+
+    setup.write("project/.emo.json", "{}");
+    assert_eq!(config::find_project(&src), Some(setup.path("project/.emo.json")));
+}
+
+#[test]
+fn client_merges_layers_and_writes_one() {
+    let setup = Setup::new();
+    setup.write("system.json", r#"{"mappings":{"fire":"🧯","deploy":"📦"}}"#);
+    setup.write("project/.emo.json", r#"{"mappings":{"deploy":"🛳"}}"#);
+
+    let mut emo = Emo::builder()
+        .config_path(setup.path("emo/config.json"))
+        .system_config(setup.path("system.json"))
+        .project_config(setup.path("project/.emo.json"))
+        .build()
+        .unwrap();
+    assert_eq!(emo.memo("deploy"), Some("🛳"));
+    assert_eq!(emo.memo_scope("fire"), Some(Scope::System));
+
+    emo.set_memo("ship", "🚀").unwrap();
+    assert_eq!(emo.memo_scope("ship"), Some(Scope::User));
+    assert!(setup.read("emo/config.json")["mappings"].get("fire").is_none());
+
+    assert!(!emo.remove_memo("deploy").unwrap());
+    assert!(emo.remove_memo_in(Scope::Project, "deploy").unwrap());
+    assert_eq!(emo.memo("deploy"), Some("📦"));
+    assert_eq!(setup.read("system.json")["mappings"]["deploy"], "📦");
+}
+
+#[test]
+fn missing_project_config_is_not_a_layer() {
+    let setup = Setup::new();
+    let mut emo = Emo::builder()
+        .config_path(setup.path("emo/config.json"))
+        .project_config(setup.path("project/.emo.json"))
+        .build()
+        .unwrap();
+    assert!(emo.layer(Scope::Project).is_none());
+    assert_eq!(emo.layers().len(), 1);
+
+    // Saving to the project scope creates it
+    emo.set_memo_in(Scope::Project, "deploy", "🛳").unwrap();
+    assert_eq!(emo.memo_scope("deploy"), Some(Scope::Project));
+    assert_eq!(setup.read("project/.emo.json")["mappings"]["deploy"], "🛳");
+}
+
+#[test]
+fn project_patterns_beat_longer_user_patterns() {
+    let setup = Setup::new();
+    setup.write("emo/config.json", r#"{"mappings":{"deploy*":"🚀","deploy-prod":"🏭"}}"#);
+    setup.write("project/.emo.json", r#"{"mappings":{"dep*":"🛳"}}"#);
+
+    let emo = Emo::builder()
+        .config_path(setup.path("emo/config.json"))
+        .project_config(setup.path("project/.emo.json"))
+        .build()
+        .unwrap();
+    assert_eq!(emo.memo("deploy-staging"), Some("🛳"));
+    // A term's own memo still comes first
+    assert_eq!(emo.memo("deploy-prod"), Some("🏭"));
+    setup.emo().arg("deploy-staging").assert().success().stdout("🛳\n");
+}
+
+#[test]
+fn list_shows_where_each_memo_comes_from() {
+    let setup = Setup::new();
+    setup.write("system.json", r#"{"mappings":{"fire":"🧯"}}"#);
+    setup.write("emo/config.json", r#"{"mappings":{"deploy":"🚀","ship":"🚢"}}"#);
+    setup.write("project/.emo.json", r#"{"mappings":{"deploy":"🛳"}}"#);
+
+    setup
+        .emo()
+        .arg("-l")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("deploy → 🛳  (project)"))
+        .stdout(predicate::str::contains("fire → 🧯  (system)"))
+        .stdout(predicate::str::contains("ship → 🚢  (user)"));
+    setup.emo().arg("deploy").assert().success().stdout("🛳\n");
+}
+
+#[test]
+fn memo_scope_chooses_the_file() {
+    let setup = Setup::new();
+    fs::create_dir(setup.path("project/.git")).unwrap();
+
+    setup.emo().args(["-m", "🛳", "--scope", "project", "deploy"]).assert().success();
+    assert_eq!(setup.read("project/.emo.json")["mappings"]["deploy"], "🛳");
+
+    // A user memo under a project one works, but says it is hidden
+    setup
+        .emo()
+        .args(["memo", "add", "🚀", "deploy"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("the project memo in"));
+    assert_eq!(setup.read("emo/config.json")["mappings"]["deploy"], "🚀");
+    setup.emo().arg("deploy").assert().stdout("🛳\n");
+
+    setup.emo().args(["memo", "rm", "--scope", "project", "deploy"]).assert().success();
+    setup.emo().arg("deploy").assert().stdout("🚀\n");
+
+    setup.emo().args(["-m", "🚀", "--scope", "team", "deploy"]).assert().failure();
+}
+
+#[test]
+fn project_writes_leave_only_the_config_in_the_repository() {
+    let setup = Setup::new();
+    fs::create_dir(setup.path("project/.git")).unwrap();
+
+    // Removing from a project config that doesn't exist changes nothing
+    setup.emo().args(["memo", "rm", "--scope", "project", "deploy"]).assert().success();
+    assert!(!setup.path("project/.emo.json").exists());
+
+    setup.emo().args(["memo", "add", "--scope", "project", "🛳", "deploy"]).assert().success();
+    setup.emo().args(["memo", "add", "--scope", "project", "🚢", "ship"]).assert().success();
+    let mut files: Vec<String> = fs::read_dir(setup.path("project"))
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    files.sort();
+    assert_eq!(files, [".emo.json", ".git", "src"]);
+
+    // The lock and backup are kept in the cache
+    let cached: Vec<_> = fs::read_dir(setup.path("cache/emo/projects")).unwrap().collect();
+    assert_eq!(cached.len(), 1);
+    let cached = cached[0].as_ref().unwrap().path();
+    assert!(cached.join(".emo.json.lock").exists());
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&fs::read_to_string(cached.join(".emo.json.bak")).unwrap()).unwrap()
+            ["mappings"]["deploy"],
+        "🛳"
+    );
+}
+
+#[test]
+fn projects_can_set_other_settings() {
+    let setup = Setup::new();
+    setup.write("emo/config.json", r#"{"mappings":{},"count":1}"#);
+    setup.write("project/.emo.json", r#"{"count":2}"#);
+
+    let output = setup.emo().arg("fire").assert().success().get_output().stdout.clone();
+    assert_eq!(String::from_utf8(output).unwrap().lines().count(), 2);
+    assert!(!Path::new(&setup.path("project/src/.emo.json")).exists());
+}
@@ -0,0 +1,127 @@
+// Following ADD: Locked, atomic config writes with a restorable backup
+
+use assert_cmd::Command;
+use emo::config;
+use emo::memos::Memo;
+use emo::EmojiMappings;
+use predicates::prelude::*;
+use std::fs;
+use std::thread;
+use tempfile::TempDir;
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd
+}
+
+#[test]
+fn concurrent_updates_keep_every_change() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("config.json");
+
+    let threads: Vec<_> = (0..16)
+        .map(|i| {
+            let path = path.clone();
+            thread::spawn(move || {
+                EmojiMappings::update(&path, |config| {
+                    config.mappings.insert(format!("term{}", i), '🚀'.into());
+                    Ok(())
+                })
+                .unwrap();
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    assert_eq!(EmojiMappings::load_from(&path).unwrap().mappings.len(), 16);
+}
+
+#[test]
+fn concurrent_memo_commands_keep_every_memo() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).arg("fire").assert().success();
+
+    let children: Vec<_> = (0..8)
+        .map(|i| {
+            std::process::Command::new(assert_cmd::cargo::cargo_bin("emo"))
+                .env("XDG_CONFIG_HOME", temp_dir.path())
+                .args(["-m", "🚀", &format!("term{}", i)])
+                .stdout(std::process::Stdio::null())
+                .spawn()
+                .unwrap()
+        })
+        .collect();
+    for mut child in children {
+        assert!(child.wait().unwrap().success());
+    }
+
+    let path = temp_dir.path().join("emo").join("config.json");
+    assert_eq!(EmojiMappings::load_from(&path).unwrap().mappings.len(), 8);
+}
+
+#[test]
+fn saves_leave_no_temp_files_and_keep_a_backup() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("config.json");
+
+    EmojiMappings::update(&path, |config| Ok(config.mappings.insert("fire".into(), '🔥'.into()))).unwrap();
+    EmojiMappings::update(&path, |config| Ok(config.mappings.insert("deploy".into(), '🚀'.into()))).unwrap();
+
+    let backup = EmojiMappings::load_from(&config::backup_path(&path)).unwrap();
+    assert_eq!(backup.mappings.len(), 1);
+    let leftovers: Vec<_> = fs::read_dir(temp_dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name.ends_with(".tmp"))
+        .collect();
+    assert!(leftovers.is_empty(), "{:?}", leftovers);
+}
+
+#[test]
+fn broken_config_is_restored_from_the_backup() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("config.json");
+    EmojiMappings::update(&path, |config| Ok(config.mappings.insert("fire".into(), '🔥'.into()))).unwrap();
+    EmojiMappings::update(&path, |config| Ok(config.mappings.insert("deploy".into(), '🚀'.into()))).unwrap();
+
+    // As left by a crash in the middle of a plain write
+    fs::write(&path, "").unwrap();
+
+    let config = EmojiMappings::load_from(&path).unwrap();
+    assert_eq!(config.mappings.get("fire"), Some(&Memo::from('🔥')));
+    assert!(EmojiMappings::load_from(&path).is_ok());
+    assert_eq!(fs::read_to_string(config::corrupt_path(&path)).unwrap(), "");
+}
+
+#[test]
+fn broken_config_without_a_backup_is_still_an_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("config.json");
+    fs::write(&path, r#"{"mappings":"#).unwrap();
+
+    assert_eq!(EmojiMappings::load_from(&path).unwrap_err().exit_code(), 4);
+}
+
+#[test]
+fn cli_warns_when_it_restores_the_backup() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).args(["-m", "🚀", "deploy"]).assert().success();
+    emo(&temp_dir).args(["-m", "🔥", "fire"]).assert().success();
+    fs::write(temp_dir.path().join("emo").join("config.json"), r#"{"mappings":{"#).unwrap();
+
+    emo(&temp_dir)
+        .arg("deploy")
+        .assert()
+        .success()
+        .stdout("🚀\n")
+        .stderr(predicate::str::contains("restored the last good config"));
+}
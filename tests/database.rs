@@ -0,0 +1,114 @@
+// Following ADD: Instance-based emoji databases
+
+use emo::generators::{EmojiGenerator, SearchGenerator};
+use emo::{Emo, EmojiDatabase, EmojiRecord};
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn record(unicode: &str, name: &str, keywords: &[&str]) -> EmojiRecord {
+    EmojiRecord {
+        keywords: keywords.iter().map(|k| k.to_string()).collect(),
+        unicode: unicode.to_string(),
+        name: name.to_string(),
+        shortcode: None,
+        definition: None,
+    }
+}
+
+#[test]
+fn databases_keep_their_own_index() {
+    let weather = EmojiDatabase::new(vec![record("U+2600", "sun", &["sunny", "weather"])]);
+    let food = EmojiDatabase::new(vec![record("U+1F355", "pizza", &["cheese", "food"])]);
+
+    // Each index covers its own records only
+    assert_eq!(weather.index().keyword_word("weather"), &[0]);
+    assert!(weather.index().keyword_word("food").is_empty());
+    assert_eq!(food.index().name_word("Pizza"), &[0]);
+
+    assert_eq!(weather.search("sunny", 1)[0].0, '☀');
+    assert!(weather.search("cheese", 1).is_empty());
+    assert_eq!(food.search("cheese", 1)[0].0, '🍕');
+}
+
+#[test]
+fn sequences_are_left_out() {
+    let database = EmojiDatabase::new(vec![
+        record("U+1F468 U+200D U+1F4BB", "man technologist", &["coder"]),
+        record("U+1F4BB", "laptop", &["computer"]),
+    ]);
+    assert_eq!(database.len(), 1);
+    assert_eq!(database.records()[0].name, "laptop");
+}
+
+#[test]
+fn bundled_data_and_files_load() {
+    let bundled = EmojiDatabase::default();
+    assert_eq!(bundled.lookup("🔥").unwrap().1.name, "fire");
+
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("emojis.json");
+    let records = vec![record("U+1F680", "rocket", &["launch"])];
+    std::fs::write(&path, serde_json::to_string(&records).unwrap()).unwrap();
+
+    let database = EmojiDatabase::from_file(&path).unwrap();
+    assert_eq!(database.define("launch").unwrap().0, '🚀');
+    assert!(EmojiDatabase::from_file(&temp_dir.path().join("missing.json")).is_err());
+}
+
+#[test]
+fn overlays_leave_the_original_alone() {
+    let base = EmojiDatabase::new(vec![record("U+1F680", "rocket", &["launch"])]);
+    let overlaid = base.with_overlay(vec![
+        record("U+1F680", "rocket", &["deploy"]),
+        record("U+1F41B", "bug", &["insect"]),
+    ]);
+
+    assert_eq!(overlaid.len(), 2);
+    assert_eq!(overlaid.search("deploy", 1)[0].0, '🚀');
+    assert!(base.search("deploy", 1).is_empty());
+}
+
+#[test]
+fn clients_share_a_database() {
+    let database = Arc::new(EmojiDatabase::new(vec![record("U+1F680", "rocket", &["deploy"])]));
+    let (first_dir, second_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+    let client = |dir: &TempDir| {
+        Emo::builder()
+            .config_path(dir.path().join("config.json"))
+            .database(database.clone())
+            .build()
+            .unwrap()
+    };
+
+    let (first, second) = (client(&first_dir), client(&second_dir));
+    assert!(Arc::ptr_eq(first.database(), second.database()));
+    assert_eq!(first.search("deploy", 1).unwrap(), vec!["🚀"]);
+    assert!(second.search("fire", 1).unwrap().is_empty());
+
+    let generator = SearchGenerator::with_database(database);
+    assert_eq!(generator.generate("deploy").unwrap(), "🚀");
+}
+
+#[test]
+fn indexed_search_matches_a_full_scan() {
+    let database = EmojiDatabase::default();
+    let picks = |results: Vec<(char, &EmojiRecord, emo::MatchKind)>| -> Vec<_> {
+        results.into_iter().map(|(c, _, kind)| (c, kind)).collect()
+    };
+    for term in ["fire", "ship", "red heart", "face", "thumbs-up", "zzqxv", ""] {
+        assert_eq!(
+            picks(database.search_ranked(term, 20)),
+            picks(emo::search_ranked(database.records(), term, 20)),
+            "{}",
+            term
+        );
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_globals_still_work() {
+    let emojis = emo::load_emojis().unwrap();
+    assert_eq!(emojis.len(), EmojiDatabase::default().len());
+    assert!(!emo::get_search_index(emojis).name_word("fire").is_empty());
+}
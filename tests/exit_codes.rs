@@ -0,0 +1,112 @@
+// Following ADD: Typed errors with stable exit codes
+
+use assert_cmd::Command;
+use emo::error::{self, EmoError};
+use predicates::prelude::*;
+use std::error::Error;
+use std::fs;
+use tempfile::TempDir;
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env_remove("EMO_AI_BACKEND");
+    cmd.env_remove("EMO_OFFLINE");
+    cmd
+}
+
+#[test]
+fn errors_chain_their_sources() {
+    let io = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused");
+    let err = EmoError::network("Failed to reach http://localhost:11434", io);
+    assert_eq!(err.exit_code(), 6);
+    assert_eq!(err.source().unwrap().to_string(), "connection refused");
+    assert_eq!(
+        error::report(&EmoError::download("Failed to download model", err)),
+        "Download failed: Failed to download model: Network error: Failed to reach http://localhost:11434: connection refused"
+    );
+
+    assert!(EmoError::no_answer("empty reply").source().is_none());
+    assert_eq!(EmoError::NoResults("none".to_string()).exit_code(), 3);
+    assert_eq!(EmoError::InvalidInput("bad".to_string()).exit_code(), 2);
+}
+
+#[test]
+fn empty_search_exits_with_no_results() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .arg("zzqxv")
+        .assert()
+        .code(3)
+        .stdout("")
+        .stderr(predicate::str::contains("No emoji found for 'zzqxv'"));
+
+    emo(&temp_dir).args(["-d", "zzqxv"]).assert().code(3);
+    emo(&temp_dir).arg("fire").assert().code(0);
+}
+
+#[test]
+fn invalid_input_exits_with_two() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).args(["--save", "0", "fire"]).assert().code(2);
+}
+
+#[test]
+fn broken_config_names_the_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":"#).unwrap();
+
+    emo(&temp_dir)
+        .arg("fire")
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("Could not parse").and(predicate::str::contains("config.json")));
+}
+
+#[test]
+fn model_failures_exit_with_inference() {
+    let temp_dir = TempDir::new().unwrap();
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"error":"model crashed"}]}"#).unwrap();
+
+    emo(&temp_dir)
+        .env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()))
+        .args(["--ai", "-s", "3", "monday"])
+        .assert()
+        .code(8)
+        .stderr(predicate::str::contains("Inference failed: model crashed"));
+}
+
+#[test]
+fn offline_skips_the_network() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .env("EMO_OFFLINE", "1")
+        .arg("--list-models")
+        .assert()
+        .code(9)
+        .stderr(predicate::str::contains("Offline"));
+
+    // AI picks fall back to search instead of waiting on the network
+    emo(&temp_dir)
+        .env("EMO_OFFLINE", "1")
+        .args(["--ai", "fire"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("🔥"))
+        .stderr(predicate::str::contains("Warning: Offline"));
+}
+
+#[test]
+fn gitmoji_without_a_match_exits_with_no_results() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).args(["gitmoji", "qqq zzz"]).assert().code(3);
+}
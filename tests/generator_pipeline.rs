@@ -0,0 +1,132 @@
+// Following ADD: Compose generators into pipelines
+
+use assert_cmd::prelude::*;
+use emo::error::{EmoError, Result};
+use emo::generators::{
+    strategy, Candidate, ChainGenerator, EmojiGenerator, FallbackGenerator, MemoGenerator, Provenance,
+};
+use emo::EmojiMappings;
+use predicates::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+struct Fixed(Vec<&'static str>);
+
+impl EmojiGenerator for Fixed {
+    fn generate(&self, _input: &str) -> Result<String> {
+        Ok(self.0[0].to_string())
+    }
+
+    fn candidates(&self, _input: &str, count: usize) -> Result<Vec<Candidate>> {
+        Ok(self
+            .0
+            .iter()
+            .take(count)
+            .map(|e| Candidate::new(*e, 0.5, Provenance::Custom))
+            .collect())
+    }
+}
+
+struct Broken;
+
+impl EmojiGenerator for Broken {
+    fn generate(&self, _input: &str) -> Result<String> {
+        Err(EmoError::ConfigError("model offline".to_string()))
+    }
+}
+
+fn memo(term: &str, emoji: char) -> Box<dyn EmojiGenerator> {
+    let mut mappings = HashMap::new();
+    mappings.insert(term.to_string(), emoji);
+    Box::new(MemoGenerator::with_mappings(mappings))
+}
+
+#[test]
+fn chain_combines_results_without_duplicates() {
+    let chain = ChainGenerator::new(vec![memo("party", '🎉'), Box::new(Fixed(vec!["🎉", "🥳", "🎊"]))]);
+    assert_eq!(chain.generate_n("party", 3).unwrap(), vec!["🎉", "🥳", "🎊"]);
+}
+
+#[test]
+fn chain_stops_when_count_is_reached() {
+    let chain = ChainGenerator::new(vec![memo("party", '🎉'), Box::new(Broken)]);
+    assert_eq!(chain.generate("party").unwrap(), "🎉");
+}
+
+#[test]
+fn fallback_skips_empty_generators() {
+    let fallback = FallbackGenerator::new(vec![memo("other", '🚀'), Box::new(Fixed(vec!["🔥"]))]);
+    assert_eq!(fallback.generate("fire").unwrap(), "🔥");
+}
+
+#[test]
+fn fallback_degrades_past_failures() {
+    let fallback = FallbackGenerator::new(vec![Box::new(Broken), Box::new(Fixed(vec!["🔍"]))]);
+    assert_eq!(fallback.generate_n("search", 1).unwrap(), vec!["🔍"]);
+}
+
+#[test]
+fn fallback_reports_error_when_everything_fails() {
+    let fallback = FallbackGenerator::new(vec![Box::new(Broken), Box::new(Broken)]);
+    let err = fallback.generate("anything").unwrap_err();
+    assert!(err.to_string().contains("model offline"));
+}
+
+#[test]
+fn strategy_rejects_unknown_generators() {
+    let config = EmojiMappings::default();
+    assert!(strategy::build("memo, telepathy", &config, &[]).is_err());
+}
+
+#[test]
+fn strategy_memo_then_search() {
+    let config: EmojiMappings = serde_json::from_str(r#"{"mappings":{"fire":"🧯"},"model":null}"#).unwrap();
+    let generator = strategy::build(strategy::DEFAULT_STRATEGY, &config, &[]).unwrap();
+
+    let results = generator.generate_n("fire", 2).unwrap();
+    assert_eq!(results[0], "🧯");
+    assert_eq!(results[1], "🔥");
+}
+
+#[test]
+fn ai_failure_falls_back_to_search_with_warning() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"error":"model offline"}]}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()));
+    cmd.args(["--ai", "fire"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("🔥"))
+        .stderr(predicate::str::contains("Warning: Inference failed: model offline"));
+}
+
+#[test]
+fn configured_strategy_uses_ai_only_when_search_is_empty() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("config.json"),
+        r#"{"mappings":{},"model":null,"strategy":"memo, search | ai"}"#,
+    )
+    .unwrap();
+
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["🦄"]}]}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.env("EMO_AI_BACKEND", format!("mock:{}", script_path.display()));
+    cmd.arg("xyzzyplugh");
+    cmd.assert().success().stdout(predicate::str::contains("🦄"));
+}
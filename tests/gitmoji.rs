@@ -0,0 +1,214 @@
+// Following ADD: Gitmoji for commit messages and a prepare-commit-msg hook
+
+use assert_cmd::prelude::*;
+use emo::ai::AiEmojiSelector;
+use emo::backend::MockBackend;
+use emo::gitmoji;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn code(message: &str) -> String {
+    gitmoji::suggest(message, None).unwrap().code.clone()
+}
+
+#[test]
+fn messages_match_the_gitmoji_table() {
+    assert_eq!(code("fix null pointer in parser"), ":bug:");
+    assert_eq!(code("Fix typos in the README"), ":pencil2:");
+    assert_eq!(code("remove unused dependency"), ":heavy_minus_sign:");
+    assert_eq!(code("upgrade serde to 1.0.200"), ":arrow_up:");
+    assert_eq!(code("add logging to the http backend"), ":loud_sound:");
+    assert_eq!(code("critical hotfix for login"), ":ambulance:");
+}
+
+#[test]
+fn conventional_types_decide() {
+    assert_eq!(code("feat: add gitmoji mode"), ":sparkles:");
+    assert_eq!(code("fix(parser): handle empty input"), ":bug:");
+    assert_eq!(code("docs: describe the hook"), ":memo:");
+    assert_eq!(code("feat(api)!: drop v1 endpoints"), ":boom:");
+
+    let subject = gitmoji::conventional("feat(api)!: drop v1 endpoints").unwrap();
+    assert_eq!((subject.commit_type, subject.scope, subject.breaking), ("feat", Some("api"), true));
+    assert_eq!(subject.description, "drop v1 endpoints");
+    assert_eq!(gitmoji::conventional("fix the thing"), None);
+    assert_eq!(gitmoji::conventional("fix(oops: unclosed"), None);
+}
+
+#[test]
+fn ambiguous_messages_need_the_model() {
+    let err = gitmoji::suggest("update readme", None).unwrap_err();
+    assert!(err.to_string().contains("No gitmoji matches"));
+
+    let mock = Arc::new(MockBackend::replies(["`:memo:` fits best"]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+    assert_eq!(gitmoji::suggest("update readme", Some(&selector)).unwrap().code, ":memo:");
+    assert!(mock.prompts()[0].user.contains(":memo: 📝 Add or update documentation."));
+}
+
+#[test]
+fn model_only_sees_the_tied_gitmoji() {
+    // A leading "fix" hints 🐛, and 💚 is "Fix CI Build."
+    let mock = Arc::new(MockBackend::replies([":green_heart:"]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+
+    assert_eq!(gitmoji::suggest("fix the build", Some(&selector)).unwrap().code, ":green_heart:");
+    let user = &mock.prompts()[0].user;
+    assert!(user.contains(":bug:"));
+    assert!(!user.contains(":sparkles:"));
+}
+
+#[test]
+fn clear_matches_skip_the_model() {
+    let mock = Arc::new(MockBackend::replies(["🔥"]));
+    let selector = AiEmojiSelector::with_backend(Box::new(Arc::clone(&mock)));
+
+    assert_eq!(gitmoji::suggest("rename module files", Some(&selector)).unwrap().code, ":truck:");
+    assert!(mock.prompts().is_empty());
+}
+
+#[test]
+fn subject_is_prefixed_in_place() {
+    let message = "\n# Please enter the commit message\nfix crash on empty config\n\nDetails.\n";
+    let prefixed = gitmoji::prefix_message(message, false, None).unwrap().unwrap();
+    assert_eq!(prefixed, "\n# Please enter the commit message\n🐛 fix crash on empty config\n\nDetails.\n");
+
+    let prefixed = gitmoji::prefix_message("feat(cli): gitmoji", true, None).unwrap().unwrap();
+    assert_eq!(prefixed, ":sparkles: feat(cli): gitmoji");
+}
+
+#[test]
+fn existing_prefixes_are_respected() {
+    for message in ["🐛 fix crash", ":bug: fix crash", "✨ feat: gitmoji", "# only comments\n", ""] {
+        assert_eq!(gitmoji::prefix_message(message, false, None).unwrap(), None, "{:?}", message);
+    }
+}
+
+fn emo(config: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", config);
+    cmd
+}
+
+#[test]
+fn cli_prints_emoji_and_code() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(temp_dir.path())
+        .args(["gitmoji", "fix", "null", "pointer", "in", "parser"])
+        .assert()
+        .success()
+        .stdout("🐛 :bug:\n");
+    emo(temp_dir.path())
+        .args(["gitmoji", "--code", "docs: hooks"])
+        .assert()
+        .success()
+        .stdout(":memo:\n");
+}
+
+#[test]
+fn cli_hook_rewrites_message_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("COMMIT_EDITMSG");
+
+    fs::write(&file, "fix crash on empty config\n").unwrap();
+    emo(temp_dir.path()).args(["gitmoji", "--hook"]).arg(&file).arg("message").assert().success();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "🐛 fix crash on empty config\n");
+
+    // Git writes merge messages itself
+    fs::write(&file, "Merge branch 'main'\n").unwrap();
+    emo(temp_dir.path()).args(["gitmoji", "--hook"]).arg(&file).arg("merge").assert().success();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "Merge branch 'main'\n");
+
+    // An unmatched subject never blocks the commit
+    fs::write(&file, "wip\n").unwrap();
+    emo(temp_dir.path())
+        .args(["gitmoji", "--hook"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Warning"));
+    assert_eq!(fs::read_to_string(&file).unwrap(), "wip\n");
+}
+
+fn git(repo: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(["-c", "user.name=emo", "-c", "user.email=emo@example.com"])
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn installed_hook_prefixes_commits() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = temp_dir.path().join("repo");
+    fs::create_dir_all(&repo).unwrap();
+    git(&repo, &["init", "-q"]);
+
+    emo(temp_dir.path())
+        .current_dir(&repo)
+        .args(["gitmoji", "--install"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("prepare-commit-msg"));
+
+    git(&repo, &["commit", "-q", "--allow-empty", "-m", "fix crash on empty config"]);
+    git(&repo, &["commit", "-q", "--allow-empty", "-m", "✅ add parser tests"]);
+    assert_eq!(
+        git(&repo, &["log", "--format=%s"]),
+        "✅ add parser tests\n🐛 fix crash on empty config\n"
+    );
+
+    // Reinstalling replaces our own hook
+    emo(temp_dir.path()).current_dir(&repo).args(["gitmoji", "--install", "--code"]).assert().success();
+}
+
+#[test]
+fn install_keeps_foreign_hooks() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = temp_dir.path().join("repo");
+    fs::create_dir_all(&repo).unwrap();
+    git(&repo, &["init", "-q"]);
+    let hook = repo.join(".git/hooks/prepare-commit-msg");
+    fs::write(&hook, "#!/bin/sh\necho mine\n").unwrap();
+
+    emo(temp_dir.path())
+        .current_dir(&repo)
+        .args(["gitmoji", "--install"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--force"));
+    assert_eq!(fs::read_to_string(&hook).unwrap(), "#!/bin/sh\necho mine\n");
+
+    emo(temp_dir.path()).current_dir(&repo).args(["gitmoji", "--install", "--force"]).assert().success();
+    assert!(fs::read_to_string(&hook).unwrap().contains("gitmoji --hook"));
+}
+
+#[cfg(unix)]
+#[test]
+fn hook_arguments_are_quoted() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new().unwrap();
+    // Stands in for emo, printing its arguments one per line
+    let fake = temp_dir.path().join("it's emo");
+    fs::write(&fake, "#!/bin/sh\nprintf '%s\\n' \"$@\"\n").unwrap();
+    fs::set_permissions(&fake, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let model = "qwen'; touch pwned; '";
+    let hook = temp_dir.path().join("hook");
+    fs::write(&hook, gitmoji::hook_script(&fake, &["--model", model])).unwrap();
+    let output = Command::new("sh").arg(&hook).arg("MSG").current_dir(temp_dir.path()).output().unwrap();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("gitmoji\n--model\n{}\n--hook\nMSG\n", model)
+    );
+    assert!(!temp_dir.path().join("pwned").exists());
+}
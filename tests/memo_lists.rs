@@ -0,0 +1,92 @@
+// Following ADD: Ranked emoji lists per memo
+
+use assert_cmd::Command;
+use emo::memos::{self, Memo, MemoFormat};
+use emo::EmojiMappings;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(config_dir.join("config.json"), r#"{"mappings":{"fire":["🚒","🧯"]},"model":null}"#).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path()).current_dir(temp_dir.path());
+    cmd
+}
+
+fn config(temp_dir: &TempDir) -> serde_json::Value {
+    let text = fs::read_to_string(temp_dir.path().join("emo").join("config.json")).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn search_gives_the_list_then_search_results() {
+    let temp_dir = TempDir::new().unwrap();
+    let output = emo(&temp_dir).args(["-c", "3", "fire"]).output().unwrap();
+    assert!(output.status.success());
+    let lines: Vec<String> = String::from_utf8_lossy(&output.stdout).lines().map(str::to_string).collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(&lines[..2], ["🚒", "🧯"]);
+    assert_eq!(lines[2], "🔥");
+
+    emo(&temp_dir).arg("fire").assert().success().stdout("🚒\n");
+}
+
+#[test]
+fn append_move_and_remove() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["memo", "append", "🔥", "fire"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("🚒 🧯 🔥"));
+    emo(&temp_dir).args(["memo", "append", "🔥", "fire"]).assert().failure();
+
+    emo(&temp_dir).args(["memo", "move", "🔥", "--to", "1", "fire"]).assert().success();
+    assert_eq!(config(&temp_dir)["mappings"]["fire"], serde_json::json!(["🔥", "🚒", "🧯"]));
+
+    emo(&temp_dir).args(["memo", "rm", "--emoji", "🚒", "fire"]).assert().success();
+    emo(&temp_dir).args(["memo", "rm", "--emoji", "🚒", "fire"]).assert().failure();
+    emo(&temp_dir).args(["memo", "rm", "--emoji", "🧯", "fire"]).assert().success();
+    // One emoji left is saved as a plain string
+    assert_eq!(config(&temp_dir)["mappings"]["fire"], "🔥");
+
+    emo(&temp_dir)
+        .args(["memo", "rm", "--emoji", "🔥", "fire"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("erased"));
+    assert!(config(&temp_dir)["mappings"].get("fire").is_none());
+}
+
+#[test]
+fn memo_flag_saves_a_list() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).args(["-m", "🎉 🥳 🎉", "party"]).assert().success();
+    assert_eq!(config(&temp_dir)["mappings"]["party"], serde_json::json!(["🎉", "🥳"]));
+    emo(&temp_dir)
+        .arg("-l")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("party → 🎉 🥳"));
+}
+
+#[test]
+fn lists_round_trip() {
+    let party = Memo::new(['🎉', '🥳']).unwrap();
+    let config: EmojiMappings = serde_json::from_str(r#"{"mappings":{"party":["🎉","🥳"],"fire":"🔥"}}"#).unwrap();
+    assert_eq!(config.mappings["party"], party);
+    assert_eq!(config.mappings["fire"], '🔥');
+    assert!(serde_json::from_str::<EmojiMappings>(r#"{"mappings":{"party":[]}}"#).is_err());
+
+    for format in [MemoFormat::Json, MemoFormat::Csv, MemoFormat::Yaml] {
+        let text = memos::export(&[("party", &party)], format).unwrap();
+        let parsed = memos::parse(&text, format).unwrap();
+        assert_eq!(parsed, vec![("party".to_string(), vec!["🎉".to_string(), "🥳".to_string()])], "{}", format);
+    }
+}
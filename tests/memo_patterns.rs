@@ -0,0 +1,104 @@
+// Following ADD: Pattern memos with prefix, glob and regex keys
+
+use assert_cmd::Command;
+use emo::memos::{Memo, MemoIndex};
+use std::fs;
+use tempfile::TempDir;
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(
+            config_dir.join("config.json"),
+            r#"{"mappings":{"deploy*":"🚀","deploy-prod":"🏭","*-failed":"❌","re:^v\\d+\\.\\d+":"🏷"},"model":null}"#,
+        )
+        .unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path()).current_dir(temp_dir.path());
+    cmd
+}
+
+fn index(keys: &[(&str, char)]) -> MemoIndex {
+    MemoIndex::new(keys.iter().map(|(k, e)| (k.to_string(), *e)))
+}
+
+fn lookup<'a>(index: &'a MemoIndex, query: &str) -> Option<&'a str> {
+    index.get(query).map(|(_, memo)| memo.first())
+}
+
+#[test]
+fn patterns_answer_queries() {
+    let temp_dir = TempDir::new().unwrap();
+    for (query, emoji) in [
+        ("deploy-staging", "🚀"),
+        ("deploy-prod", "🏭"),
+        ("deploy-failed", "🚀"),
+        ("build-failed", "❌"),
+        ("v1.2", "🏷"),
+    ] {
+        emo(&temp_dir).arg(query).assert().success().stdout(format!("{}\n", emoji));
+    }
+}
+
+#[test]
+fn exact_then_longest_prefix_then_order() {
+    let memos = index(&[("*", '⭐'), ("de*", '🅰'), ("deploy*", '🚀'), ("deploy", '📦'), ("d*y", '🅱')]);
+    assert_eq!(lookup(&memos, "deploy"), Some("📦"));
+    assert_eq!(lookup(&memos, "deploy-it"), Some("🚀"));
+    assert_eq!(lookup(&memos, "delay"), Some("🅰"));
+    assert_eq!(lookup(&memos, "dry"), Some("🅱"));
+    assert_eq!(lookup(&memos, "other"), Some("⭐"));
+
+    // On a tie the earlier pattern wins
+    let memos = index(&[("a*z", '1'), ("a*", '2')]);
+    assert_eq!(lookup(&memos, "abz"), Some("1"));
+    let memos = index(&[("a*", '2'), ("a*z", '1')]);
+    assert_eq!(lookup(&memos, "abz"), Some("2"));
+
+    // Anchored regexes count their literal prefix
+    let memos = index(&[("deploy*", '🚀'), (r"re:^deploy-p\w+", '🏭'), ("re:prod", '🅿')]);
+    assert_eq!(lookup(&memos, "deploy-prod"), Some("🏭"));
+    assert_eq!(lookup(&memos, "deploy-staging"), Some("🚀"));
+    assert_eq!(lookup(&memos, "in-prod"), Some("🅿"));
+}
+
+#[test]
+fn pattern_order_follows_the_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{"ci-*":"🤖","*-nightly":"🌙"}}"#).unwrap();
+    emo(&temp_dir).arg("x-nightly").assert().success().stdout("🌙\n");
+    // `ci-*` has the longer literal prefix
+    emo(&temp_dir).arg("ci-nightly").assert().success().stdout("🤖\n");
+
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{"*-nightly":"🌙","*ly":"🤖"}}"#).unwrap();
+    emo(&temp_dir).arg("ci-nightly").assert().success().stdout("🌙\n");
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{"*ly":"🤖","*-nightly":"🌙"}}"#).unwrap();
+    emo(&temp_dir).arg("ci-nightly").assert().success().stdout("🤖\n");
+}
+
+#[test]
+fn invalid_patterns_are_refused() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).args(["memo", "add", "🚀", "re:("]).assert().failure();
+    emo(&temp_dir).args(["memo", "add", "🧪", "test*"]).assert().success();
+    emo(&temp_dir).arg("test-e2e").assert().success().stdout("🧪\n");
+
+    // A broken pattern saved by hand never matches
+    let memos = MemoIndex::new([("re:(".to_string(), Memo::from('💥'))]);
+    assert_eq!(lookup(&memos, "("), None);
+}
+
+#[test]
+fn project_patterns_are_tried_first() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{"*-prod":"🏭"}}"#).unwrap();
+    fs::write(temp_dir.path().join(".emo.json"), r#"{"mappings":{"*prod":"🛳"}}"#).unwrap();
+    emo(&temp_dir).arg("deploy-prod").assert().success().stdout("🛳\n");
+}
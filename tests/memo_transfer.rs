@@ -0,0 +1,186 @@
+// Following ADD: Memo import and export in JSON, CSV, YAML and espanso
+
+use assert_cmd::Command;
+use emo::memos::{self, Memo, Merge, MemoFormat};
+use indexmap::IndexMap;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(
+            config_dir.join("config.json"),
+            r#"{"mappings":{"deploy":"🚀","ship it":"🚢"},"model":null}"#,
+        )
+        .unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path()).current_dir(temp_dir.path());
+    cmd
+}
+
+fn config(temp_dir: &TempDir) -> serde_json::Value {
+    let text = fs::read_to_string(temp_dir.path().join("emo").join("config.json")).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn every_format_round_trips() {
+    let memos = [("deploy", '🚀'), ("ship it", '🚢'), ("a, \"quoted\" term", '✨')];
+    let saved: Vec<(&str, Memo)> = memos.iter().map(|(t, e)| (*t, Memo::from(*e))).collect();
+    let saved: Vec<(&str, &Memo)> = saved.iter().map(|(t, m)| (*t, m)).collect();
+    for format in [MemoFormat::Json, MemoFormat::Csv, MemoFormat::Yaml, MemoFormat::Espanso] {
+        let text = memos::export(&saved, format).unwrap();
+        let mut parsed = memos::parse(&text, format).unwrap();
+        parsed.sort();
+        let mut expected: Vec<_> = memos.iter().map(|(t, e)| (t.to_string(), vec![e.to_string()])).collect();
+        expected.sort();
+        assert_eq!(parsed, expected, "{}", format);
+    }
+}
+
+#[test]
+fn espanso_triggers_start_with_a_colon() {
+    let text = memos::export(&[("deploy", &'🚀'.into())], MemoFormat::Espanso).unwrap();
+    assert!(text.contains("trigger: :deploy"), "{}", text);
+    assert!(text.contains("replace: 🚀"), "{}", text);
+
+    // YAML files with `matches` are read as espanso
+    assert_eq!(memos::parse(&text, MemoFormat::Yaml).unwrap(), vec![("deploy".to_string(), vec!["🚀".to_string()])]);
+}
+
+#[test]
+fn merge_strategies() {
+    let saved: IndexMap<String, Memo> = [("deploy".to_string(), '🚀'.into()), ("fire".to_string(), '🔥'.into())].into();
+    let imported = || {
+        vec![
+            ("deploy".to_string(), vec!["🛳️".to_string()]),
+            ("fire".to_string(), vec!["🔥".to_string()]),
+            ("new".to_string(), vec!["✨".to_string()]),
+            ("bad".to_string(), vec!["abc".to_string()]),
+            ("letter".to_string(), vec!["a".to_string()]),
+            ("digit".to_string(), vec!["🎉".to_string(), "1".to_string()]),
+        ]
+    };
+
+    let mut mappings = saved.clone();
+    let summary = memos::merge(&mut mappings, imported(), Merge::Keep);
+    assert_eq!(summary.added, vec![("new".to_string(), Memo::from('✨'))]);
+    assert_eq!(summary.conflicts.len(), 1);
+    assert_eq!(summary.unchanged, 1);
+    assert_eq!(summary.skipped.len(), 3);
+    assert!(summary.skipped[1].1.contains("'a' is not a single emoji"), "{:?}", summary.skipped);
+    assert!(summary.skipped[2].1.contains("'1' is not a single emoji"), "{:?}", summary.skipped);
+    assert_eq!(mappings["deploy"], '🚀');
+    assert_eq!(mappings["new"], '✨');
+
+    let mut mappings = saved.clone();
+    let summary = memos::merge(&mut mappings, imported(), Merge::Overwrite);
+    assert_eq!(summary.replaced[0].imported.first(), "🛳️");
+    assert_eq!(mappings["deploy"], '🛳');
+
+    let mut mappings = saved.clone();
+    let summary = memos::merge(&mut mappings, imported(), Merge::Report);
+    assert_eq!(summary.conflicts.len(), 1);
+    assert_eq!(mappings, saved);
+}
+
+#[test]
+fn import_reports_rows_that_are_not_emoji() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("team.csv");
+    fs::write(&file, "term,emoji\nletter,a\ndigit,1\nkeycap,1️⃣\n").unwrap();
+
+    emo(&temp_dir)
+        .args(["memo", "import"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("skipped 'letter'"))
+        .stderr(predicate::str::contains("skipped 'digit'"));
+    assert!(config(&temp_dir)["mappings"].get("letter").is_none());
+    assert!(config(&temp_dir)["mappings"].get("digit").is_none());
+    assert_eq!(config(&temp_dir)["mappings"]["keycap"], "1️⃣");
+}
+
+#[test]
+fn espanso_export_warns_about_lists() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).args(["memo", "export", "--format", "espanso"]).assert().success().stderr("");
+
+    emo(&temp_dir).args(["memo", "append", "🛳", "deploy"]).assert().success();
+    emo(&temp_dir)
+        .args(["memo", "export", "--format", "espanso"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("replace: 🚀"))
+        .stderr(predicate::str::contains("only gets the top emoji of the lists for: deploy"));
+}
+
+#[test]
+fn export_prints_memos() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["memo", "export", "--format", "csv"])
+        .assert()
+        .success()
+        .stdout("term,emoji\ndeploy,🚀\nship it,🚢\n");
+    emo(&temp_dir)
+        .args(["memo", "export", "--format", "toml"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn import_keeps_by_default_and_reports_conflicts() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("team.csv");
+    fs::write(&file, "term,emoji\ndeploy,🛳️\nrollback,⏪\n").unwrap();
+
+    emo(&temp_dir)
+        .args(["memo", "import", "--merge", "report"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("deploy: 🚀 saved, 🛳️ in the file"))
+        .stdout(predicate::str::contains("Nothing changed"));
+    assert!(config(&temp_dir)["mappings"].get("rollback").is_none());
+
+    emo(&temp_dir)
+        .args(["memo", "import"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 added, 0 replaced, 1 conflicting"));
+    assert_eq!(config(&temp_dir)["mappings"]["deploy"], "🚀");
+    assert_eq!(config(&temp_dir)["mappings"]["rollback"], "⏪");
+
+    emo(&temp_dir).args(["memo", "import", "--merge", "overwrite"]).arg(&file).assert().success();
+    assert_eq!(config(&temp_dir)["mappings"]["deploy"], "🛳️");
+}
+
+#[test]
+fn import_reads_configs_stdin_and_project_scope() {
+    let temp_dir = TempDir::new().unwrap();
+    let other = temp_dir.path().join("other.json");
+    fs::write(&other, r#"{"version":2,"mappings":{"party":"🎉"},"model":"qwen"}"#).unwrap();
+    emo(&temp_dir).args(["memo", "import"]).arg(&other).assert().success();
+    assert_eq!(config(&temp_dir)["mappings"]["party"], "🎉");
+    assert!(config(&temp_dir)["model"].is_null());
+
+    emo(&temp_dir)
+        .args(["memo", "import", "--scope", "project", "--format", "yaml", "-"])
+        .write_stdin("ship it: 🛳\n")
+        .assert()
+        .success();
+    let project = fs::read_to_string(temp_dir.path().join(".emo.json")).unwrap();
+    assert!(project.contains("🛳"));
+    emo(&temp_dir).args(["search", "ship", "it"]).assert().stdout("🛳\n");
+
+    // Without --format, stdin and unknown extensions can't be told apart
+    emo(&temp_dir).args(["memo", "import", "-"]).write_stdin("{}").assert().code(2);
+}
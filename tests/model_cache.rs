@@ -0,0 +1,26 @@
+// Following ADD: Offline use of downloaded models
+
+use emo::error::EmoError;
+use emo::models::{self, ModelRegistry};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn downloaded_models_resolve_offline() {
+    std::env::set_var("EMO_OFFLINE", "1");
+    let model_dir = TempDir::new().unwrap();
+    let file = model_dir.path().join("qwen2.5-0.5b-instruct-q4_k_m.gguf");
+    fs::write(&file, b"GGUF").unwrap();
+
+    let registry = ModelRegistry::new();
+    assert_eq!(registry.locate(Some("qwen2.5-0.5b-instruct"), model_dir.path()).unwrap(), file);
+    assert_eq!(registry.locate(None, model_dir.path()).unwrap(), file);
+    assert!(matches!(
+        registry.locate(Some("llama-3.2-1b"), model_dir.path()),
+        Err(EmoError::Offline(_))
+    ));
+
+    let cached = models::cached_models(model_dir.path());
+    assert_eq!(cached.len(), 1);
+    assert_eq!(cached[0].0.filename(), "qwen2.5-0.5b-instruct-q4_k_m.gguf");
+}
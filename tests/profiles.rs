@@ -0,0 +1,115 @@
+// Following ADD: Memo profiles with inheritance, picked by --profile or EMO_PROFILE
+
+use assert_cmd::Command;
+use emo::config::{self, Profile};
+use emo::{Emo, EmojiMappings};
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+const CONFIG: &str = r#"{
+  "mappings": {"ship": "🚢", "fire": "🔥"},
+  "profiles": {
+    "work": {"mappings": {"ship": "🚀"}},
+    "oncall": {"inherits": "work", "mappings": {"page": "📟"}}
+  }
+}"#;
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(config_dir.join("config.json"), CONFIG).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path())
+        .env_remove("EMO_PROFILE")
+        .current_dir(temp_dir.path());
+    cmd
+}
+
+fn config(temp_dir: &TempDir) -> serde_json::Value {
+    let text = fs::read_to_string(temp_dir.path().join("emo").join("config.json")).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn profiles_inherit_memos() {
+    let config = config::parse(CONFIG, "config.json".as_ref()).unwrap();
+    let oncall = config::profile_memos(&config, Some("oncall")).unwrap();
+    assert_eq!(oncall["ship"], '🚀');
+    assert_eq!(oncall["page"], '📟');
+    assert_eq!(oncall["fire"], '🔥');
+    assert_eq!(config::profile_chain(&config, Some("oncall")).unwrap(), vec!["oncall", "work"]);
+
+    let base = config::profile_memos(&config, Some("base")).unwrap();
+    assert_eq!(base["ship"], '🚢');
+    assert!(!base.contains_key("page"));
+}
+
+#[test]
+fn inheritance_loops_are_an_error() {
+    let mut config = EmojiMappings::default();
+    for (name, parent) in [("a", "b"), ("b", "a")] {
+        let profile = Profile { inherits: Some(parent.to_string()), ..Profile::default() };
+        config.profiles.insert(name.to_string(), profile);
+    }
+    let err = config::profile_memos(&config, Some("a")).unwrap_err();
+    assert_eq!(err.exit_code(), 4);
+    assert!(err.to_string().contains("a → b → a"));
+}
+
+#[test]
+fn client_memos_go_to_the_active_profile() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("config.json");
+    fs::write(&path, CONFIG).unwrap();
+
+    let mut emo = Emo::builder().config_path(&path).profile("personal").build().unwrap();
+    assert_eq!(emo.memo("ship"), Some("🚢"));
+    emo.set_memo("ship", "⛵").unwrap();
+    assert_eq!(emo.memo("ship"), Some("⛵"));
+
+    let saved = EmojiMappings::load_from(&path).unwrap();
+    assert_eq!(saved.mappings["ship"], '🚢');
+    assert_eq!(saved.profiles["personal"].mappings["ship"], '⛵');
+}
+
+#[test]
+fn search_uses_the_profile_from_flag_env_or_config() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).arg("ship").assert().stdout("🚢\n");
+    emo(&temp_dir).args(["--profile", "work", "ship"]).assert().stdout("🚀\n");
+    emo(&temp_dir).args(["search", "--profile", "work", "ship"]).assert().stdout("🚀\n");
+    emo(&temp_dir).env("EMO_PROFILE", "oncall").arg("page").assert().stdout("📟\n");
+    emo(&temp_dir).env("EMO_PROFILE", "work").args(["--profile", "base", "ship"]).assert().stdout("🚢\n");
+
+    emo(&temp_dir).args(["config", "set", "profile", "work"]).assert().success();
+    emo(&temp_dir).arg("ship").assert().stdout("🚀\n");
+}
+
+#[test]
+fn memo_commands_use_the_active_profile() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir).args(["--profile", "work", "-m", "🏢", "office"]).assert().success();
+    assert_eq!(config(&temp_dir)["profiles"]["work"]["mappings"]["office"], "🏢");
+    assert!(config(&temp_dir)["mappings"].get("office").is_none());
+
+    emo(&temp_dir)
+        .args(["memo", "ls", "--profile", "oncall"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Saved mappings (profile oncall):"))
+        .stdout(predicate::str::contains("office → 🏢  (user, work)"))
+        .stdout(predicate::str::contains("fire → 🔥  (user, base)"));
+
+    // Inherited memos are removed from the profile that has them
+    emo(&temp_dir)
+        .args(["--profile", "oncall", "-e", "office"])
+        .assert()
+        .stdout("No mapping found for 'office'\n")
+        .stderr(predicate::str::contains("use --profile work"));
+    emo(&temp_dir).args(["memo", "rm", "--profile", "work", "office"]).assert().success();
+    assert!(config(&temp_dir)["profiles"]["work"]["mappings"].get("office").is_none());
+}
@@ -0,0 +1,92 @@
+// Following ADD: Test prompt template rendering and per-model overrides
+
+use emo::prompts::{self, ChatPrompt, ModelPrompts, PromptKind, PromptTemplate, PromptVars};
+use std::collections::HashMap;
+
+fn vars<'a>(situation: &'a str, exclude: &'a [String]) -> PromptVars<'a> {
+    PromptVars {
+        situation,
+        count: 3,
+        length: 5,
+        exclude,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn render_substitutes_placeholders() {
+    let exclude = vec!["🐛".to_string(), "🐞".to_string()];
+    let rendered = prompts::render(
+        "{situation} x{count} in {length}, not {exclude}",
+        &vars("fixed a bug", &exclude),
+    );
+    assert_eq!(rendered, "fixed a bug x3 in 5, not 🐛, 🐞");
+}
+
+#[test]
+fn render_drops_lines_with_empty_placeholders() {
+    let rendered = prompts::render("Pick for {situation}.\nDo not use: {exclude}.", &vars("rain", &[]));
+    assert_eq!(rendered, "Pick for rain.");
+}
+
+#[test]
+fn placeholders_in_values_are_left_alone() {
+    let exclude = vec!["🐛".to_string()];
+    let rendered = prompts::render(
+        "Pick for {situation}.\nDo not use: {exclude}.",
+        &vars("ignore {exclude} and {examples} {", &exclude),
+    );
+    assert_eq!(rendered, "Pick for ignore {exclude} and {examples} {.\nDo not use: 🐛.");
+}
+
+#[test]
+fn default_emoji_prompt_mentions_exclusions() {
+    let exclude = vec!["🚀".to_string()];
+    let prompt = ChatPrompt::build(PromptKind::Emoji, None, &vars("deploy", &exclude));
+    assert!(prompt.user.contains("deploy"));
+    assert!(prompt.user.contains("Do not use: 🚀"));
+    assert!(prompt.to_completion().starts_with("Task: "));
+    assert!(prompt.to_completion().ends_with("\nEmoji:"));
+}
+
+#[test]
+fn model_override_replaces_only_given_fields() {
+    let mut table = HashMap::new();
+    table.insert(
+        "qwen".to_string(),
+        ModelPrompts {
+            emoji: Some(PromptTemplate {
+                system: None,
+                user: Some("Emoji for {situation}".to_string()),
+            }),
+            sentence: None,
+            explain: None,
+            gitmoji: None,
+        },
+    );
+
+    let overrides = prompts::lookup(&table, Some("qwen"));
+    let prompt = ChatPrompt::build(PromptKind::Emoji, overrides, &vars("coffee", &[]));
+    assert_eq!(prompt.user, "Emoji for coffee");
+
+    let default = ChatPrompt::build(PromptKind::Emoji, None, &vars("coffee", &[]));
+    assert_eq!(prompt.system, default.system);
+}
+
+#[test]
+fn lookup_falls_back_to_wildcard() {
+    let mut table = HashMap::new();
+    table.insert(prompts::ANY_MODEL.to_string(), ModelPrompts::default());
+
+    assert!(prompts::lookup(&table, Some("unknown-model")).is_some());
+    assert!(prompts::lookup(&HashMap::new(), Some("unknown-model")).is_none());
+}
+
+#[test]
+fn config_parses_prompt_overrides() {
+    let json = r#"{"mappings":{},"model":null,"prompts":{"*":{"sentence":{"user":"{length} emojis: {situation}"}}}}"#;
+    let config: emo::EmojiMappings = serde_json::from_str(json).unwrap();
+    let overrides = prompts::lookup(&config.prompts, None).unwrap();
+    let prompt = ChatPrompt::build(PromptKind::Sentence, Some(overrides), &vars("monday", &[]));
+    assert_eq!(prompt.user, "5 emojis: monday");
+}
@@ -0,0 +1,73 @@
+// Following ADD: Ranked candidates with scores and provenance
+
+use emo::error::Result;
+use emo::generators::{ChainGenerator, EmojiGenerator, MemoGenerator, Provenance, SearchGenerator};
+use emo::MatchKind;
+use std::collections::HashMap;
+
+#[test]
+fn search_candidates_are_ranked_and_explained() {
+    let candidates = SearchGenerator::new().candidates("fire", 3).unwrap();
+
+    assert_eq!(candidates.len(), 3);
+    assert_eq!(candidates[0].emoji, "🔥");
+    assert_eq!(candidates[0].score, 1.0);
+    assert!(candidates.iter().all(|c| c.provenance == Provenance::Search));
+    assert!(candidates.windows(2).all(|w| w[0].score >= w[1].score));
+    assert!(candidates[0].explanation.as_deref().unwrap().contains("fire"));
+}
+
+#[test]
+fn memo_candidate_has_memo_provenance() {
+    let mut mappings = HashMap::new();
+    mappings.insert("deploy".to_string(), '🚀');
+
+    let candidates = MemoGenerator::with_mappings(mappings).candidates("deploy", 5).unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].emoji, "🚀");
+    assert_eq!(candidates[0].provenance, Provenance::Memo);
+}
+
+#[test]
+fn chain_keeps_provenance_of_each_generator() {
+    let mut mappings = HashMap::new();
+    mappings.insert("fire".to_string(), '🧯');
+
+    let chain = ChainGenerator::new(vec![
+        Box::new(MemoGenerator::with_mappings(mappings)),
+        Box::new(SearchGenerator::new()),
+    ]);
+
+    let candidates = chain.candidates("fire", 2).unwrap();
+    assert_eq!(candidates[0].provenance, Provenance::Memo);
+    assert_eq!(candidates[1].provenance, Provenance::Search);
+}
+
+#[test]
+fn generate_only_generators_get_default_candidates() {
+    struct Constant;
+    impl EmojiGenerator for Constant {
+        fn generate(&self, _input: &str) -> Result<String> {
+            Ok("✨".to_string())
+        }
+    }
+
+    let candidates = Constant.candidates("anything", 3).unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].provenance, Provenance::Custom);
+    assert!(Constant.candidates("anything", 0).unwrap().is_empty());
+}
+
+#[test]
+fn match_kinds_score_in_order() {
+    assert_eq!(MatchKind::ExactName.score(), 1.0);
+    assert!(MatchKind::NameWord.score() > MatchKind::KeywordWord.score());
+    assert!(MatchKind::Definition.score() > 0.0);
+}
+
+#[test]
+fn candidates_serialize_with_lowercase_provenance() {
+    let candidate = SearchGenerator::new().candidates("fire", 1).unwrap().remove(0);
+    let json = serde_json::to_string(&candidate).unwrap();
+    assert!(json.contains("\"provenance\":\"search\""));
+}
@@ -0,0 +1,158 @@
+// Following ADD: Subcommands with the old flags kept as aliases
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn emo(temp_dir: &TempDir) -> Command {
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    if !config_dir.join("config.json").exists() {
+        fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+    }
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd
+}
+
+fn stdout(cmd: &mut Command) -> String {
+    String::from_utf8(cmd.assert().success().get_output().stdout.clone()).unwrap()
+}
+
+fn config(temp_dir: &TempDir) -> serde_json::Value {
+    let text = fs::read_to_string(temp_dir.path().join("emo").join("config.json")).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn subcommands_match_the_old_flags() {
+    let temp_dir = TempDir::new().unwrap();
+    let pairs: [(&[&str], &[&str]); 3] = [
+        (&["search", "-c", "2", "-n", "fire"], &["-c", "2", "-n", "fire"]),
+        (&["define", "🔥"], &["-d", "🔥"]),
+        (&["search", "--format", "json", "fire"], &["--format", "json", "fire"]),
+    ];
+    for (new, old) in pairs {
+        assert_eq!(stdout(emo(&temp_dir).args(new)), stdout(emo(&temp_dir).args(old)));
+    }
+}
+
+#[test]
+fn memo_add_ls_rm() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["memo", "add", "🚀", "ship", "it"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ship it ➡ 🚀 ✅"));
+    emo(&temp_dir).args(["search", "ship", "it"]).assert().stdout("🚀\n");
+    emo(&temp_dir)
+        .args(["memo", "ls"])
+        .assert()
+        .stdout(predicate::str::contains("ship it → 🚀"));
+
+    emo(&temp_dir)
+        .args(["memo", "rm", "ship", "it"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Mapping for 'ship it' erased ✅"));
+    emo(&temp_dir).args(["memo", "ls"]).assert().stdout("No saved mappings.\n");
+}
+
+#[test]
+fn memo_add_takes_a_result_index() {
+    let temp_dir = TempDir::new().unwrap();
+    let second = stdout(emo(&temp_dir).args(["search", "-c", "2", "fire"]))
+        .lines()
+        .nth(1)
+        .unwrap()
+        .to_string();
+
+    emo(&temp_dir).args(["memo", "add", "2", "fire"]).assert().success();
+    assert_eq!(config(&temp_dir)["mappings"]["fire"], second.as_str());
+}
+
+#[test]
+fn ai_model_is_for_this_run_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let script_path = temp_dir.path().join("script.json");
+    fs::write(&script_path, r#"{"responses":[{"tokens":["🚀"]}]}"#).unwrap();
+    let backend = format!("mock:{}", script_path.display());
+
+    emo(&temp_dir)
+        .env("EMO_AI_BACKEND", &backend)
+        .args(["ai", "--model", "qwen", "shipping"])
+        .assert()
+        .success()
+        .stdout("🚀\n");
+    assert!(config(&temp_dir)["model"].is_null());
+
+    // The old form still makes it the default
+    emo(&temp_dir)
+        .env("EMO_AI_BACKEND", &backend)
+        .args(["--model", "qwen", "shipping"])
+        .assert()
+        .success();
+    assert_eq!(config(&temp_dir)["model"], "qwen");
+}
+
+#[test]
+fn models_use_saves_the_default() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["models", "use", "qwen"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Default model: qwen"));
+    assert_eq!(config(&temp_dir)["model"], "qwen");
+
+    emo(&temp_dir).args(["models", "use", "qwen,phi"]).assert().success();
+    assert_eq!(config(&temp_dir)["ensemble"], serde_json::json!(["qwen", "phi"]));
+}
+
+#[test]
+fn config_path_and_show() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("emo").join("config.json");
+    emo(&temp_dir)
+        .args(["config", "path"])
+        .assert()
+        .success()
+        .stdout(format!("{}\n", path.display()));
+    emo(&temp_dir)
+        .args(["config", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"mappings\""));
+}
+
+#[test]
+fn random_subcommand() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .arg("random")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^.+ - .+\n$").unwrap());
+}
+
+#[test]
+fn each_subcommand_has_its_own_flags() {
+    let temp_dir = TempDir::new().unwrap();
+    emo(&temp_dir)
+        .args(["ai", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--sentence").and(predicate::str::contains("--explain")));
+    emo(&temp_dir)
+        .args(["search", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--sentence").not());
+
+    // Old flags don't mix with subcommands
+    emo(&temp_dir).args(["-d", "search", "fire"]).assert().failure();
+    emo(&temp_dir).args(["search", "-d", "fire"]).assert().failure();
+}
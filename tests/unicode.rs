@@ -0,0 +1,133 @@
+// Following ADD: Emoji classification from the Unicode data tables
+
+use assert_cmd::prelude::*;
+use emo::ai::AiEmojiSelector;
+use emo::backend::mock::{MockResponse, MockScript, MockToken};
+use emo::backend::MockBackend;
+use emo::unicode;
+use predicates::prelude::*;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn properties_follow_emoji_data() {
+    // Emoji, but shown as text unless followed by VS16
+    for ch in ['©', '™', '↔', '1', '#'] {
+        assert!(unicode::is_emoji(ch), "{:?}", ch);
+        assert!(!unicode::is_emoji_presentation(ch), "{:?}", ch);
+    }
+    // Emoji by default, outside the old hardcoded ranges
+    for ch in ['⭐', '⌛', '🫠'] {
+        assert!(unicode::is_emoji_presentation(ch), "{:?}", ch);
+        assert!(unicode::is_extended_pictographic(ch), "{:?}", ch);
+    }
+    // Inside the old ranges, but not emoji
+    assert!(!unicode::is_emoji('\u{2621}'));
+    assert!(!unicode::is_emoji('a'));
+
+    assert!(unicode::is_emoji_modifier('\u{1F3FD}'));
+    assert!(unicode::is_emoji_modifier_base('👋'));
+    assert!(unicode::is_emoji_component('\u{200D}'));
+}
+
+#[test]
+fn rgi_sequences_are_validated() {
+    for emoji in ["🚀", "❤️", "#️⃣", "🇺🇸", "👩🏽‍💻", "🏴󠁧󠁢󠁳󠁣󠁴󠁿"] {
+        assert!(unicode::is_rgi(emoji), "{}", emoji);
+    }
+    for not_rgi in ["❤", "#⃣", "🚀🚀", "a", ""] {
+        assert!(!unicode::is_rgi(not_rgi), "{}", not_rgi);
+    }
+}
+
+#[test]
+fn segmentation_keeps_sequences_whole() {
+    let text = "I ❤️ coding 👩🏽‍💻 in 🇺🇸, press 1️⃣ © 2024";
+    let emojis: Vec<_> = unicode::emojis(text).collect();
+    assert_eq!(emojis, vec!["❤️", "👩🏽‍💻", "🇺🇸", "1️⃣"]);
+
+    let offsets: Vec<_> = unicode::emoji_indices("a🚀b").collect();
+    assert_eq!(offsets, vec![(1, "🚀")]);
+}
+
+#[test]
+fn text_presentation_is_not_an_emoji() {
+    assert!(unicode::is_emoji_cluster("⭐"));
+    assert!(!unicode::is_emoji_cluster("⭐\u{FE0E}"));
+    assert!(unicode::is_emoji_cluster("©\u{FE0F}"));
+    assert!(!unicode::is_emoji_cluster("©"));
+    // Text-default pictographs in the emoji planes still count
+    assert!(unicode::is_emoji_cluster("🌧"));
+}
+
+#[test]
+fn display_width_counts_emoji_as_two_columns() {
+    assert_eq!(unicode::display_width("abc"), 3);
+    assert_eq!(unicode::display_width("🚀"), 2);
+    assert_eq!(unicode::display_width("👩🏽‍💻"), 2);
+    assert_eq!(unicode::display_width("❤️ ok"), 5);
+    assert_eq!(unicode::display_width("e\u{301}"), 1);
+}
+
+#[test]
+fn ai_picks_whole_sequences_split_across_tokens() {
+    // 👩🏽‍💻 streamed code point by code point, followed by more text
+    let mock = MockBackend::new(MockScript {
+        responses: vec![MockResponse {
+            tokens: ["👩", "🏽", "\u{200D}", "💻", " done"]
+                .into_iter()
+                .map(|t| MockToken::Text(t.to_string()))
+                .collect(),
+            error: None,
+        }],
+        record: None,
+    });
+
+    let selector = AiEmojiSelector::with_backend(Box::new(mock));
+    assert_eq!(selector.select_emoji_llm("coding").unwrap(), "👩🏽‍💻");
+}
+
+#[test]
+fn ai_sentence_counts_sequences_once() {
+    let mock = MockBackend::replies(["🇯🇵✈️👨‍👩‍👧🍣"]);
+    let selector = AiEmojiSelector::with_backend(Box::new(mock));
+    assert_eq!(selector.generate_emoji_sentence("family trip", 3).unwrap(), "🇯🇵✈️👨‍👩‍👧");
+}
+
+#[test]
+fn define_accepts_emoji_with_selector() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["-d", "⭐\u{FE0F}"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("⭐ - "));
+}
+
+#[test]
+fn memo_saves_multi_character_emoji() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().join("emo");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("config.json"), r#"{"mappings":{},"model":null}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["-m", "👍🏽 ❤️ 👩🏽‍💻", "approve"]);
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["-c", "3", "approve"]);
+    cmd.assert().success().stdout("👍🏽\n❤️\n👩🏽‍💻\n");
+
+    let mut cmd = Command::cargo_bin("emo").unwrap();
+    cmd.env("XDG_CONFIG_HOME", temp_dir.path());
+    cmd.args(["-m", "👍🏽👍🏽", "approve"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("not a single emoji"));
+}